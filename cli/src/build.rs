@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+
+use oca_bindings_common::build::build_set;
+use oca_bindings_common::resolve::{BundleResolver, DirResolver};
+use oca_sdk_rs::{oca, ToJSON};
+
+use crate::{overlay_registry, read};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// OCAfiles to build. A file without `--name=` is named after its stem.
+    #[arg(required = true)]
+    ocafiles: Vec<PathBuf>,
+    /// Overlay definition file; may be repeated
+    #[arg(long = "overlays", value_name = "FILE")]
+    overlay_files: Vec<PathBuf>,
+    /// Directory of `<name>.json` and `<said>.json` bundles to resolve names
+    /// outside the set
+    #[arg(long, value_name = "DIR")]
+    store: Option<PathBuf>,
+    /// Directory to write each bundle to as `<said>.json` and `<name>.json`,
+    /// so that it can serve as a `--store` for later builds
+    #[arg(long, value_name = "DIR")]
+    out: Option<PathBuf>,
+}
+
+/// Builds the OCAfiles and prints the name to SAID map as JSON.
+pub fn run(args: Args) -> Result<(), Vec<String>> {
+    let registry = overlay_registry(&args.overlay_files)?;
+    let store = args
+        .store
+        .map(DirResolver::new)
+        .transpose()
+        .map_err(|e| vec![e])?;
+
+    let mut files = vec![];
+    for path in &args.ocafiles {
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        files.push((name, read(path)?));
+    }
+
+    let built = build_set(files, store.as_ref().map(|s| s as &dyn BundleResolver), |text| {
+        let ast = oca::file::parse_from_string(text.to_string(), &registry)
+            .map_err(|e| format!("Failed to parse OCAfile: {e}"))?;
+        let build = oca::bundle::from_ast(None, &ast).map_err(|e| format!("Failed to build bundle: {e:?}"))?;
+        Ok(build.oca_bundle.get_json_bundle())
+    })
+    .map_err(|errors| errors.into_iter().map(|e| e.message).collect::<Vec<_>>())?;
+
+    if let Some(out) = args.out {
+        std::fs::create_dir_all(&out).map_err(|e| vec![format!("Failed to create '{}': {e}", out.display())])?;
+        for (name, bundle) in &built.bundles {
+            for key in [&built.saids[name], name] {
+                let path = out.join(format!("{key}.json"));
+                std::fs::write(&path, bundle).map_err(|e| vec![format!("Failed to write '{}': {e}", path.display())])?;
+            }
+        }
+    }
+
+    let saids = serde_json::to_string_pretty(&built.saids).map_err(|e| vec![e.to_string()])?;
+    println!("{saids}");
+    Ok(())
+}
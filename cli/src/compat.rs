@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+
+use oca_bindings_common::compat::{check, Verdict};
+
+use crate::{load_bundle, overlay_registry};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The published bundle JSON
+    old: PathBuf,
+    /// The bundle JSON about to replace it
+    new: PathBuf,
+    /// Overlay definition file; may be repeated
+    #[arg(long = "overlays", value_name = "FILE")]
+    overlay_files: Vec<PathBuf>,
+    /// Print the verdict and findings as JSON instead of a text report
+    #[arg(long)]
+    json: bool,
+    /// Exit with an error when the verdict is this severe or worse
+    #[arg(long, value_enum, default_value = "breaking")]
+    fail_on: FailOn,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum FailOn {
+    BackwardCompatible,
+    Breaking,
+    Never,
+}
+
+/// Prints the compatibility verdict of `new` against `old`, failing when it
+/// reaches `--fail-on` so that CI can gate publication on it.
+pub fn run(args: Args) -> Result<(), Vec<String>> {
+    let registry = overlay_registry(&args.overlay_files)?;
+    let compatibility = check(&load_bundle(&args.old, &registry)?, &load_bundle(&args.new, &registry)?);
+
+    if args.json {
+        let json = serde_json::to_string_pretty(&compatibility).map_err(|e| vec![e.to_string()])?;
+        println!("{json}");
+    } else {
+        print!("{compatibility}");
+    }
+
+    let threshold = match args.fail_on {
+        FailOn::BackwardCompatible => Some(Verdict::BackwardCompatible),
+        FailOn::Breaking => Some(Verdict::Breaking),
+        FailOn::Never => None,
+    };
+    match threshold {
+        Some(threshold) if compatibility.verdict >= threshold => {
+            Err(vec![format!("The new bundle is {}", compatibility.verdict)])
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use oca_bindings_common::diff::diff;
+
+use crate::{load_bundle, overlay_registry};
+
+#[derive(clap::Args)]
+pub struct Args {
+    /// The earlier bundle JSON
+    old: PathBuf,
+    /// The later bundle JSON
+    new: PathBuf,
+    /// Overlay definition file; may be repeated
+    #[arg(long = "overlays", value_name = "FILE")]
+    overlay_files: Vec<PathBuf>,
+    /// Print the differences as JSON instead of a text report
+    #[arg(long)]
+    json: bool,
+}
+
+pub fn run(args: Args) -> Result<(), Vec<String>> {
+    let registry = overlay_registry(&args.overlay_files)?;
+    let bundle_diff = diff(&load_bundle(&args.old, &registry)?, &load_bundle(&args.new, &registry)?);
+
+    if args.json {
+        let json = serde_json::to_string_pretty(&bundle_diff).map_err(|e| vec![e.to_string()])?;
+        println!("{json}");
+    } else {
+        print!("{bundle_diff}");
+    }
+    Ok(())
+}
@@ -0,0 +1,72 @@
+mod build;
+mod compat;
+mod diff;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use oca_bindings_common::version;
+use oca_sdk_rs::oca::overlay_file::OverlayLocalRegistry;
+use oca_sdk_rs::{oca, ToJSON};
+
+#[derive(Parser)]
+#[command(name = "oca", version, about = "Command line tools for OCA bundles")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Build OCAfiles that reference each other by `refn:` name
+    Build(build::Args),
+    /// Show what changed between two versions of a bundle
+    Diff(diff::Args),
+    /// Check whether data valid under one bundle version stays valid under the next
+    Compat(compat::Args),
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Build(args) => build::run(args),
+        Command::Diff(args) => diff::run(args),
+        Command::Compat(args) => compat::run(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(errors) => {
+            for error in errors {
+                eprintln!("error: {error}");
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Loads the overlay definitions of all given overlay files into one
+/// registry.
+fn overlay_registry(overlay_files: &[PathBuf]) -> Result<OverlayLocalRegistry, Vec<String>> {
+    let sources = overlay_files
+        .iter()
+        .map(read)
+        .collect::<Result<Vec<_>, _>>()?;
+    OverlayLocalRegistry::from_string(sources.join("\n"))
+        .map_err(|e| vec![format!("Failed to load overlay registry: {e}")])
+}
+
+fn read(path: &PathBuf) -> Result<String, Vec<String>> {
+    std::fs::read_to_string(path).map_err(|e| vec![format!("Failed to read '{}': {e}", path.display())])
+}
+
+/// Loads a bundle file through the SDK and returns it serialized again, so
+/// that bundles compare in the same serialization.
+fn load_bundle(path: &PathBuf, registry: &OverlayLocalRegistry) -> Result<serde_json::Value, Vec<String>> {
+    let text = read(path)?;
+    let invalid = |e: String| vec![format!("Failed to load '{}': {e}", path.display())];
+    let bundle: serde_json::Value = serde_json::from_str(&text).map_err(|e| invalid(e.to_string()))?;
+    version::require_supported(&bundle).map_err(invalid)?;
+
+    let model = oca::bundle::load(&mut text.as_bytes(), registry).map_err(|e| invalid(e.to_string()))?;
+    serde_json::from_str(&model.get_json_bundle()).map_err(|e| invalid(e.to_string()))
+}
@@ -0,0 +1,77 @@
+//! Runs the `oca` binary on the example assets.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use serde_json::Value;
+
+const ASSETS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../js/example/test/assets");
+
+fn oca(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_oca")).args(args).output().unwrap()
+}
+
+fn asset(name: &str) -> String {
+    format!("{ASSETS}/{name}")
+}
+
+/// An empty directory of its own for each test.
+fn scratch(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("oca-cli-{}-{test}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write(dir: &Path, name: &str, text: &str) -> String {
+    let path = dir.join(name);
+    std::fs::write(&path, text).unwrap();
+    path.to_string_lossy().into_owned()
+}
+
+fn stdout_json(output: &Output) -> Value {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[test]
+fn build_output_serves_as_store() {
+    let dir = scratch("store");
+    let overlays = asset("semantic.overlayfile");
+    let address = write(&dir, "address.ocafile", "ADD ATTRIBUTE street=Text\n");
+    let person = write(&dir, "person.ocafile", "ADD ATTRIBUTE name=Text home=refn:address\n");
+    let out = dir.join("out");
+    let out_arg = out.to_string_lossy().into_owned();
+
+    let saids = stdout_json(&oca(&["build", "--overlays", &overlays, "--out", &out_arg, &person, &address]));
+    for name in ["address", "person"] {
+        let said = saids[name].as_str().unwrap();
+        let by_name: Value = serde_json::from_str(&std::fs::read_to_string(out.join(format!("{name}.json"))).unwrap()).unwrap();
+        assert_eq!(by_name["digest"], said);
+        assert!(out.join(format!("{said}.json")).is_file());
+    }
+
+    let employee = write(&dir, "employee.ocafile", "ADD ATTRIBUTE who=refn:person office=refn:address\n");
+    let built = stdout_json(&oca(&["build", "--overlays", &overlays, "--store", &out_arg, &employee]));
+    assert!(built["employee"].is_string());
+}
+
+#[test]
+fn build_reports_missing_names() {
+    let dir = scratch("missing");
+    let ocafile = write(&dir, "a.ocafile", "ADD ATTRIBUTE x=refn:nowhere\n");
+    let output = oca(&["build", "--overlays", &asset("semantic.overlayfile"), &ocafile]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown name 'nowhere'"));
+}
+
+#[test]
+fn a_bundle_is_compatible_with_itself() {
+    let bundle = asset("oca_new.json");
+    let output = oca(&["compat", "--overlays", &asset("semantic.overlayfile"), &bundle, &bundle]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("Verdict: compatible\n"));
+
+    let diff = stdout_json(&oca(&["diff", "--json", "--overlays", &asset("semantic.overlayfile"), &bundle, &bundle]));
+    assert_eq!(diff["added_attributes"], serde_json::json!([]));
+}
@@ -0,0 +1,60 @@
+//! `said::compute` hashes fields in the order they were parsed in, which
+//! relies on the SDK turning on `serde_json`'s `preserve_order`. These tests
+//! check it against the SAIDs the SDK computed for the passport fixture.
+
+use oca_bindings_common::{edit, said};
+use oca_sdk_rs::oca::overlay_file::OverlayLocalRegistry;
+use oca_sdk_rs::{oca, ToJSON};
+use serde_json::Value;
+
+const ASSETS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../js/example/test/assets");
+
+fn asset(name: &str) -> String {
+    std::fs::read_to_string(format!("{ASSETS}/{name}")).unwrap()
+}
+
+fn rebuild_fixture() -> (Value, Value) {
+    let registry = OverlayLocalRegistry::from_string(asset("semantic.overlayfile")).unwrap();
+    let ast = oca::file::parse_from_string(asset("passport.ocafile"), &registry).unwrap();
+    let build = oca::bundle::from_ast(None, &ast).unwrap();
+    let built = serde_json::from_str(&build.oca_bundle.get_json_bundle()).unwrap();
+    let fixture = serde_json::from_str(&asset("oca_new.json")).unwrap();
+    (built, fixture)
+}
+
+fn saids(bundle: &Value) -> Vec<(String, Option<String>)> {
+    said::verify(bundle)
+        .objects
+        .into_iter()
+        .map(|object| (object.path, object.embedded))
+        .collect()
+}
+
+#[test]
+fn sdk_builds_the_fixture_saids() {
+    let (built, fixture) = rebuild_fixture();
+    assert_eq!(saids(&built), saids(&fixture));
+    assert_eq!(built, fixture);
+}
+
+#[test]
+fn fixture_saids_verify() {
+    let fixture = serde_json::from_str(&asset("oca_new.json")).unwrap();
+    let verification = said::verify(&fixture);
+    assert!(verification.valid, "{:#?}", verification.objects);
+}
+
+#[test]
+fn sealing_keeps_the_fixture_saids() {
+    let fixture: Value = serde_json::from_str(&asset("oca_new.json")).unwrap();
+    let mut sealed = fixture.clone();
+    edit::seal(&mut sealed).unwrap();
+    assert_eq!(sealed, fixture);
+}
+
+#[test]
+fn sdk_saids_verify() {
+    let (built, _) = rebuild_fixture();
+    let verification = said::verify(&built);
+    assert!(verification.valid, "{:#?}", verification.objects);
+}
@@ -0,0 +1,107 @@
+//! What an OCAfile built on top of a base bundle added to it.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::bundle;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Additions {
+    /// Attributes the base capture base does not have, in capture base order.
+    pub attributes: Vec<String>,
+    /// Overlays of a type and language the base has none of.
+    pub overlays: Vec<OverlayKey>,
+    /// Overlays the base has, but with other content, e.g. labels for the
+    /// new attributes.
+    pub changed_overlays: Vec<OverlayKey>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OverlayKey {
+    #[serde(rename = "type")]
+    pub overlay_type: String,
+    pub language: Option<String>,
+}
+
+impl OverlayKey {
+    pub(crate) fn of(overlay: &Value) -> OverlayKey {
+        OverlayKey {
+            overlay_type: overlay.get("type").and_then(Value::as_str).unwrap_or_default().to_string(),
+            language: bundle::overlay_language(overlay).map(str::to_string),
+        }
+    }
+}
+
+/// Compares `bundle` with the `base` it was built from. SAIDs are ignored:
+/// a new attribute changes the capture base digest, and with it the digest
+/// of every overlay, even those whose content stayed the same.
+pub fn additions(base: &Value, bundle: &Value) -> Additions {
+    let base_attributes = bundle::attributes(base);
+    let attributes = bundle::attributes(bundle)
+        .into_iter()
+        .filter(|(name, _)| !base_attributes.iter().any(|(base_name, _)| base_name == name))
+        .map(|(name, _)| name.to_string())
+        .collect();
+
+    let mut additions = Additions {
+        attributes,
+        ..Additions::default()
+    };
+    for overlay in bundle::overlays(bundle) {
+        let key = OverlayKey::of(overlay);
+        let same_key: Vec<&Value> = bundle::overlays(base)
+            .iter()
+            .filter(|base_overlay| OverlayKey::of(base_overlay) == key)
+            .collect();
+        if same_key.is_empty() {
+            additions.overlays.push(key);
+        } else if !same_key.iter().any(|base_overlay| content(base_overlay) == content(overlay)) {
+            additions.changed_overlays.push(key);
+        }
+    }
+    additions
+}
+
+/// The overlay without the fields derived from SAIDs.
+pub(crate) fn content(overlay: &Value) -> Map<String, Value> {
+    let mut fields = overlay.as_object().cloned().unwrap_or_default();
+    fields.retain(|field, _| !matches!(field.as_str(), "digest" | "d" | "capture_base"));
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn finds_what_was_built_on_top_of_the_base() {
+        let base = json!({
+            "capture_base": { "attributes": { "name": "Text" } },
+            "overlays": [
+                {
+                    "digest": "E1", "capture_base": "EBase", "type": "overlay/label/2.0.0", "language": "en",
+                    "attribute_labels": { "name": "Name" },
+                },
+                { "digest": "E2", "capture_base": "EBase", "type": "overlay/meta/2.0.0", "language": "en", "name": "Person" },
+            ],
+        });
+        let bundle = json!({
+            "capture_base": { "attributes": { "name": "Text", "email": "Text" } },
+            "overlays": [
+                {
+                    "digest": "E3", "capture_base": "ENew", "type": "overlay/label/2.0.0", "language": "en",
+                    "attribute_labels": { "name": "Name", "email": "E-mail" },
+                },
+                { "digest": "E4", "capture_base": "ENew", "type": "overlay/meta/2.0.0", "language": "en", "name": "Person" },
+                { "digest": "E5", "capture_base": "ENew", "type": "overlay/label/2.0.0", "language": "fr", "attribute_labels": { "name": "Nom" } },
+            ],
+        });
+        let additions = additions(&base, &bundle);
+        assert_eq!(additions.attributes, ["email"]);
+        let key = |language: &str| OverlayKey { overlay_type: "overlay/label/2.0.0".to_string(), language: Some(language.to_string()) };
+        assert_eq!(additions.overlays, [key("fr")]);
+        assert_eq!(additions.changed_overlays, [key("en")]);
+    }
+}
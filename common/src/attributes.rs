@@ -0,0 +1,226 @@
+//! Per-attribute view of a bundle that joins the capture base with the
+//! overlays describing each attribute, e.g. to render a form.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::bundle;
+use crate::resolve::{BundleResolver, Reference, Trail};
+use crate::types::AttrType;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AttributeDescriptor {
+    pub name: String,
+    /// Type tree; `None` when the capture base type cannot be read.
+    #[serde(rename = "type")]
+    pub attribute_type: Option<AttrType>,
+    /// Label per language.
+    pub labels: BTreeMap<String, String>,
+    /// Information text per language.
+    pub information: BTreeMap<String, String>,
+    pub conformance: Option<String>,
+    pub cardinality: Option<String>,
+    pub format: Option<String>,
+    pub unit: Option<String>,
+    pub character_encoding: Option<String>,
+    /// Either a list of codes or the SAID of an external code list.
+    pub entry_codes: Option<Value>,
+    /// Code to label map per language.
+    pub entries: BTreeMap<String, BTreeMap<String, String>>,
+    pub sensitive: bool,
+    pub standard: Option<String>,
+    /// Attributes of the referenced bundle for `refs:` and `refn:` types,
+    /// when a resolver was given and found it.
+    pub nested: Option<Vec<AttributeDescriptor>>,
+}
+
+/// Describes every attribute of the capture base, in capture base order.
+pub fn describe_all(bundle: &Value) -> Vec<AttributeDescriptor> {
+    describe_all_with(bundle, None)
+}
+
+/// Like [`describe_all`], also describing the bundles that attributes
+/// reference when `resolver` can find them.
+pub fn describe_all_with(bundle: &Value, resolver: Option<&dyn BundleResolver>) -> Vec<AttributeDescriptor> {
+    describe_all_in(bundle, resolver, &mut Trail::for_schema(bundle))
+}
+
+/// Describes a single attribute. Overlays that say nothing about it leave the
+/// corresponding field empty.
+pub fn describe(bundle: &Value, name: &str) -> AttributeDescriptor {
+    describe_with(bundle, name, None)
+}
+
+pub fn describe_with(bundle: &Value, name: &str, resolver: Option<&dyn BundleResolver>) -> AttributeDescriptor {
+    let attribute_type = bundle::attributes(bundle)
+        .into_iter()
+        .find(|(attribute, _)| *attribute == name)
+        .and_then(|(_, value)| AttrType::from_json(value));
+    describe_in(bundle, name, attribute_type, resolver, &mut Trail::for_schema(bundle))
+}
+
+/// Describes the attributes of an SDK model after `fill_attributes`, given as
+/// name and type in model order. The overlays of `bundle`, the bundle the
+/// model was loaded from, supply everything else.
+pub fn describe_filled<'a>(
+    bundle: &Value,
+    attributes: impl IntoIterator<Item = (&'a str, Option<AttrType>)>,
+    resolver: Option<&dyn BundleResolver>,
+) -> Vec<AttributeDescriptor> {
+    let mut trail = Trail::for_schema(bundle);
+    attributes
+        .into_iter()
+        .map(|(name, attribute_type)| describe_in(bundle, name, attribute_type, resolver, &mut trail))
+        .collect()
+}
+
+fn describe_all_in(bundle: &Value, resolver: Option<&dyn BundleResolver>, trail: &mut Trail) -> Vec<AttributeDescriptor> {
+    bundle::attributes(bundle)
+        .into_iter()
+        .map(|(name, value)| describe_in(bundle, name, AttrType::from_json(value), resolver, trail))
+        .collect()
+}
+
+fn describe_in(
+    bundle: &Value,
+    name: &str,
+    attribute_type: Option<AttrType>,
+    resolver: Option<&dyn BundleResolver>,
+    trail: &mut Trail,
+) -> AttributeDescriptor {
+    let text = |overlay: &str, field: &str| {
+        bundle::attribute_value(bundle, overlay, field, name)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+
+    let nested = match (resolver, attribute_type.as_ref().and_then(Reference::of)) {
+        (Some(resolver), Some(reference)) => trail.follow(resolver, &reference).ok().map(|referenced| {
+            trail.push(reference);
+            let nested = describe_all_in(&referenced, Some(resolver), trail);
+            trail.pop();
+            nested
+        }),
+        _ => None,
+    };
+
+    AttributeDescriptor {
+        name: name.to_string(),
+        attribute_type,
+        labels: per_language(bundle, "label", "attribute_labels", name)
+            .filter_map(|(language, value)| Some((language, value.as_str()?.to_string())))
+            .collect(),
+        information: per_language(bundle, "information", "attribute_information", name)
+            .filter_map(|(language, value)| Some((language, value.as_str()?.to_string())))
+            .collect(),
+        conformance: text("conformance", "attribute_conformances"),
+        cardinality: text("cardinality", "attribute_cardinalities"),
+        format: text("format", "attribute_formats"),
+        unit: text("unit", "attribute_units"),
+        character_encoding: text("character_encoding", "attribute_character_encodings"),
+        entry_codes: bundle::attribute_value(bundle, "entry_code", "attribute_entry_codes", name).cloned(),
+        entries: per_language(bundle, "entry", "attribute_entries", name)
+            .filter_map(|(language, value)| {
+                let entries = value
+                    .as_object()?
+                    .iter()
+                    .filter_map(|(code, label)| Some((code.clone(), label.as_str()?.to_string())))
+                    .collect();
+                Some((language, entries))
+            })
+            .collect(),
+        sensitive: is_sensitive(bundle, name),
+        standard: text("standard", "attribute_standards"),
+        nested,
+    }
+}
+
+/// Values of `field` for `attribute` in every overlay called `overlay`,
+/// keyed by the overlay's language.
+fn per_language<'a>(
+    bundle: &'a Value,
+    overlay: &'a str,
+    field: &'a str,
+    attribute: &'a str,
+) -> impl Iterator<Item = (String, &'a Value)> {
+    bundle::overlays(bundle)
+        .iter()
+        .filter(move |o| bundle::overlay_name(o) == Some(overlay))
+        .filter_map(move |o| {
+            let language = bundle::overlay_language(o)?.to_string();
+            let value = bundle::overlay_fields(o)?.get(field)?.get(attribute)?;
+            Some((language, value))
+        })
+}
+
+/// Sensitive attributes are listed by the `sensitive` overlay, or flagged in
+/// the capture base by bundles of the 1.x format.
+fn is_sensitive(bundle: &Value, name: &str) -> bool {
+    let listed = |items: Option<&Value>| {
+        items
+            .and_then(Value::as_array)
+            .is_some_and(|items| items.iter().any(|item| item.as_str() == Some(name)))
+    };
+    bundle::overlays(bundle)
+        .iter()
+        .filter(|o| bundle::overlay_name(o) == Some("sensitive"))
+        .any(|o| listed(bundle::overlay_fields(o).and_then(|fields| fields.get("attributes"))))
+        || listed(bundle::capture_base(bundle).and_then(|cb| cb.get("flagged_attributes")))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    struct Address;
+
+    impl BundleResolver for Address {
+        fn resolve(&self, reference: &Reference) -> Result<Option<Value>, String> {
+            Ok((*reference == Reference::Name("address".to_string()))
+                .then(|| json!({ "digest": "EAddress", "capture_base": { "attributes": { "street": "Text" } } })))
+        }
+    }
+
+    #[test]
+    fn joins_the_overlays_of_each_attribute() {
+        let bundle = fixtures::passport();
+        let descriptors = describe_all(&bundle);
+        assert_eq!(descriptors.len(), 21);
+
+        let sex = describe(&bundle, "sex");
+        assert_eq!(sex.attribute_type, Some(AttrType::Text));
+        assert_eq!(sex.entry_codes, Some(json!(["F", "M", "X"])));
+        assert_eq!(sex.entries["fr"]["F"], "Femelle");
+        assert!(sex.labels.contains_key("fr"));
+        assert_eq!(describe(&bundle, "dateOfBirth").format.as_deref(), Some("YYnMMnDD"));
+    }
+
+    #[test]
+    fn describes_referenced_bundles_with_a_resolver() {
+        let bundle = json!({
+            "capture_base": { "attributes": { "home": "refn:address", "work": ["refn:nowhere"] }, "flagged_attributes": ["home"] },
+            "overlays": [],
+        });
+        let home = describe_with(&bundle, "home", Some(&Address));
+        assert!(home.sensitive);
+        assert_eq!(home.nested.unwrap()[0].name, "street");
+        assert_eq!(describe_with(&bundle, "work", Some(&Address)).nested, None);
+        assert_eq!(describe(&bundle, "home").nested, None);
+    }
+
+    #[test]
+    fn describes_filled_attributes_in_model_order() {
+        let bundle = fixtures::passport();
+        let filled = [("sex", Some(AttrType::Text)), ("home", Some(AttrType::RefName("address".to_string())))];
+        let descriptors = describe_filled(&bundle, filled.iter().cloned(), Some(&Address));
+        assert_eq!(descriptors.len(), 2);
+        assert_eq!(descriptors[0], describe(&bundle, "sex"));
+        assert_eq!(descriptors[1].name, "home");
+        assert_eq!(descriptors[1].nested.as_ref().unwrap()[0].name, "street");
+    }
+}
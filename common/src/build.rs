@@ -0,0 +1,322 @@
+//! Building a set of OCAfiles that reference each other by name: ordering
+//! them by their `refn:` dependencies and substituting each name with the
+//! SAID of the bundle built for it.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::bundle;
+use crate::resolve::{self, BundleResolver, Reference};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BuildSetErrorKind {
+    /// Two OCAfiles have the same name.
+    DuplicateName,
+    /// A `refn:` name is neither in the set nor known to the resolver.
+    MissingName,
+    /// OCAfiles reference each other in a loop.
+    Cycle,
+    /// An OCAfile failed to parse or build.
+    Build,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BuildSetError {
+    pub kind: BuildSetErrorKind,
+    /// The OCAfile the error was found in.
+    pub file: Option<String>,
+    /// The names involved: the missing or duplicate name, or the cycle.
+    pub names: Vec<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct BuildSet {
+    /// Name to SAID of every bundle built.
+    pub saids: BTreeMap<String, String>,
+    /// Name to bundle JSON.
+    pub bundles: BTreeMap<String, String>,
+    /// Names in the order they were built.
+    pub order: Vec<String>,
+}
+
+/// The `--name=` given in the OCAfile's meta lines, if any.
+pub fn ocafile_name(ocafile: &str) -> Option<&str> {
+    ocafile
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("--name="))
+        .map(|name| name.trim().trim_matches('"'))
+        .find(|name| !name.is_empty())
+}
+
+/// Builds `files`, given as `(name, OCAfile)` pairs, in dependency order.
+/// An OCAfile's own `--name=` takes precedence over the name it is given.
+/// `refn:` names outside the set are looked up with `external`. `build`
+/// turns OCAfile text, with names already replaced by SAIDs, into bundle
+/// JSON.
+pub fn build_set(
+    files: Vec<(String, String)>,
+    external: Option<&dyn BundleResolver>,
+    mut build: impl FnMut(&str) -> Result<String, String>,
+) -> Result<BuildSet, Vec<BuildSetError>> {
+    let mut errors = vec![];
+    let mut sources: BTreeMap<String, String> = BTreeMap::new();
+    let mut input_order = vec![];
+    for (name, text) in files {
+        let name = ocafile_name(&text).map(str::to_string).unwrap_or(name);
+        if sources.contains_key(&name) {
+            errors.push(BuildSetError {
+                kind: BuildSetErrorKind::DuplicateName,
+                file: Some(name.clone()),
+                names: vec![name.clone()],
+                message: format!("More than one OCAfile is named '{name}'"),
+            });
+            continue;
+        }
+        input_order.push(name.clone());
+        sources.insert(name, text);
+    }
+
+    // Bundles resolved for names outside the set, so that each is looked up
+    // only once.
+    let mut values: BTreeMap<String, Value> = BTreeMap::new();
+    let mut dependencies: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for name in &input_order {
+        let mut local = vec![];
+        for dependency in resolve::referenced_names(&sources[name]) {
+            if sources.contains_key(&dependency) {
+                local.push(dependency);
+                continue;
+            }
+            if values.contains_key(&dependency) {
+                continue;
+            }
+            match resolve_external(external, &dependency) {
+                Ok(bundle) => {
+                    values.insert(dependency, bundle);
+                }
+                Err(reason) => errors.push(BuildSetError {
+                    kind: BuildSetErrorKind::MissingName,
+                    file: Some(name.clone()),
+                    names: vec![dependency.clone()],
+                    message: format!("OCAfile '{name}' references unknown name '{dependency}'{reason}"),
+                }),
+            }
+        }
+        dependencies.insert(name, local);
+    }
+
+    let order = match topological_order(&input_order, &dependencies) {
+        Ok(order) => order,
+        Err(cycles) => {
+            errors.extend(cycles.into_iter().map(|cycle| BuildSetError {
+                kind: BuildSetErrorKind::Cycle,
+                file: cycle.first().cloned(),
+                message: format!("OCAfiles reference each other in a cycle: {}", cycle.join(" -> ")),
+                names: cycle,
+            }));
+            vec![]
+        }
+    };
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut built = BuildSet::default();
+    for name in order {
+        let resolver = SetResolver {
+            built: &values,
+            external,
+        };
+        let build_error = |message: String| {
+            vec![BuildSetError {
+                kind: BuildSetErrorKind::Build,
+                file: Some(name.clone()),
+                names: vec![],
+                message: format!("Failed to build '{name}': {message}"),
+            }]
+        };
+        let text = resolve::substitute_names(&sources[&name], &resolver).map_err(|e| build_error(e.join("; ")))?;
+        let bundle_json = build(&text).map_err(build_error)?;
+        let value: Value = serde_json::from_str(&bundle_json).map_err(|e| build_error(e.to_string()))?;
+        let said = bundle::digest(&value)
+            .ok_or_else(|| build_error("the bundle has no digest".to_string()))?
+            .to_string();
+
+        built.saids.insert(name.clone(), said);
+        built.bundles.insert(name.clone(), bundle_json);
+        built.order.push(name.clone());
+        values.insert(name, value);
+    }
+    Ok(built)
+}
+
+/// The bundle `external` knows by `name`, or why there is none, as a suffix
+/// for the error message.
+fn resolve_external(external: Option<&dyn BundleResolver>, name: &str) -> Result<Value, String> {
+    match external.map(|r| r.resolve(&Reference::Name(name.to_string()))) {
+        Some(Ok(Some(bundle))) => Ok(bundle),
+        Some(Err(e)) => Err(format!(": {e}")),
+        _ => Err(String::new()),
+    }
+}
+
+/// Orders names so that each comes after the names it depends on, keeping
+/// the input order where there is a choice. Fails with the cycles found.
+fn topological_order(names: &[String], dependencies: &BTreeMap<&str, Vec<String>>) -> Result<Vec<String>, Vec<Vec<String>>> {
+    // Kahn's algorithm, always taking the ready name that comes first in the
+    // input.
+    let index: HashMap<&str, usize> = names.iter().enumerate().map(|(i, name)| (name.as_str(), i)).collect();
+    let mut in_degree: Vec<usize> = names.iter().map(|name| dependencies[name.as_str()].len()).collect();
+    let mut dependents: Vec<Vec<usize>> = vec![vec![]; names.len()];
+    for (i, name) in names.iter().enumerate() {
+        for dependency in &dependencies[name.as_str()] {
+            dependents[index[dependency.as_str()]].push(i);
+        }
+    }
+    let mut ready: BTreeSet<usize> = (0..names.len()).filter(|&i| in_degree[i] == 0).collect();
+    let mut order: Vec<String> = vec![];
+    while let Some(i) = ready.pop_first() {
+        order.push(names[i].clone());
+        for &dependent in &dependents[i] {
+            in_degree[dependent] -= 1;
+            if in_degree[dependent] == 0 {
+                ready.insert(dependent);
+            }
+        }
+    }
+    if order.len() == names.len() {
+        return Ok(order);
+    }
+    let built: HashSet<&str> = order.iter().map(String::as_str).collect();
+
+    // Every name left depends on another one left, so walking dependencies
+    // from any of them runs into a cycle.
+    let mut cycles: Vec<Vec<String>> = vec![];
+    for start in names.iter().filter(|name| !built.contains(name.as_str())) {
+        if cycles.iter().any(|cycle| cycle.contains(start)) {
+            continue;
+        }
+        let mut path = vec![start.clone()];
+        loop {
+            let last = path.last().unwrap();
+            let next = dependencies[last.as_str()]
+                .iter()
+                .find(|dependency| !built.contains(dependency.as_str()))
+                .unwrap()
+                .clone();
+            if let Some(i) = path.iter().position(|name| *name == next) {
+                let mut cycle = path.split_off(i);
+                cycle.push(next);
+                if !cycles.iter().any(|known| known.contains(&cycle[0])) {
+                    cycles.push(cycle);
+                }
+                break;
+            }
+            path.push(next);
+        }
+    }
+    Err(cycles)
+}
+
+/// Resolves names to the bundles built or resolved so far, then to
+/// `external`.
+struct SetResolver<'a> {
+    built: &'a BTreeMap<String, Value>,
+    external: Option<&'a dyn BundleResolver>,
+}
+
+impl BundleResolver for SetResolver<'_> {
+    fn resolve(&self, reference: &Reference) -> Result<Option<Value>, String> {
+        if let Reference::Name(name) = reference {
+            if let Some(bundle) = self.built.get(name) {
+                return Ok(Some(bundle.clone()));
+            }
+        }
+        match self.external {
+            Some(external) => external.resolve(reference),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use serde_json::json;
+
+    use super::*;
+
+    /// Knows `address` and records every lookup.
+    #[derive(Default)]
+    struct Store {
+        lookups: RefCell<Vec<Reference>>,
+    }
+
+    impl BundleResolver for Store {
+        fn resolve(&self, reference: &Reference) -> Result<Option<Value>, String> {
+            self.lookups.borrow_mut().push(reference.clone());
+            Ok((*reference == Reference::Name("address".to_string())).then(|| json!({ "digest": "EAddress" })))
+        }
+    }
+
+    /// Stands in for the SDK: the bundle's digest is its attribute list.
+    fn build(text: &str) -> Result<String, String> {
+        Ok(json!({ "digest": text.trim().trim_start_matches("ADD ATTRIBUTE ") }).to_string())
+    }
+
+    fn files(files: &[(&str, &str)]) -> Vec<(String, String)> {
+        files.iter().map(|(name, text)| (name.to_string(), text.to_string())).collect()
+    }
+
+    #[test]
+    fn substitutes_names_in_dependency_order() {
+        let built = build_set(files(&[("person", "ADD ATTRIBUTE home=refn:home\n"), ("home", "ADD ATTRIBUTE x=Text\n")]), None, build).unwrap();
+        assert_eq!(built.order, ["home", "person"]);
+        assert_eq!(built.saids["person"], "home=refs:x=Text");
+    }
+
+    #[test]
+    fn resolves_each_external_name_once() {
+        let store = Store::default();
+        let set = files(&[("a", "ADD ATTRIBUTE x=refn:address\n"), ("b", "ADD ATTRIBUTE y=refn:address z=Array[refn:address]\n")]);
+        let built = build_set(set, Some(&store), build).unwrap();
+        assert_eq!(*store.lookups.borrow(), [Reference::Name("address".to_string())]);
+        assert_eq!(built.saids["b"], "y=refs:EAddress z=Array[refs:EAddress]");
+    }
+
+    fn dependencies<'a>(edges: &[(&'a str, &[&str])]) -> BTreeMap<&'a str, Vec<String>> {
+        edges.iter().map(|(name, on)| (*name, on.iter().map(|d| d.to_string()).collect())).collect()
+    }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn orders_dependencies_first_keeping_input_order() {
+        let deps = dependencies(&[("a", &["c"]), ("b", &[]), ("c", &["b"]), ("d", &[])]);
+        assert_eq!(topological_order(&names(&["a", "b", "c", "d"]), &deps).unwrap(), ["b", "c", "a", "d"]);
+    }
+
+    #[test]
+    fn reports_the_path_of_each_cycle_once() {
+        // `x` only leads into the a -> b -> c loop; `s` loops on itself.
+        let deps = dependencies(&[("x", &["a"]), ("a", &["b"]), ("b", &["c"]), ("c", &["a"]), ("s", &["s"]), ("ok", &[])]);
+        let cycles = topological_order(&names(&["x", "a", "b", "c", "s", "ok"]), &deps).unwrap_err();
+        assert_eq!(cycles, [names(&["a", "b", "c", "a"]), names(&["s", "s"])]);
+    }
+
+    #[test]
+    fn reports_missing_names_before_building() {
+        let store = Store::default();
+        let errors = build_set(files(&[("a", "ADD ATTRIBUTE x=refn:nowhere\n")]), Some(&store), |_| unreachable!()).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].kind, BuildSetErrorKind::MissingName);
+        assert_eq!(errors[0].names, ["nowhere"]);
+    }
+}
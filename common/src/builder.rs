@@ -0,0 +1,424 @@
+//! Assembling an OCAfile from structured input, for the fluent bundle
+//! builders of the bindings.
+//!
+//! Text that OCAfile syntax cannot carry verbatim (quotes, backslashes, line
+//! breaks) is written as a placeholder and put back into the parsed AST by
+//! [`Rendered::read_back`], so any label survives and the bundle is built from
+//! the same AST as the equivalent OCAfile.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::resolve::{self, BundleResolver};
+use crate::types::AttrType;
+
+const PLACEHOLDER_PREFIX: &str = "oca-builder-text-";
+
+#[derive(Debug, Clone, Default)]
+pub struct BundleBuilder {
+    name: Option<String>,
+    attributes: Vec<(String, AttrType)>,
+    overlays: Vec<Overlay>,
+}
+
+#[derive(Debug, Clone)]
+struct Overlay {
+    /// Lower case overlay name, e.g. `label`.
+    name: String,
+    language: Option<String>,
+    properties: Map<String, Value>,
+}
+
+/// An OCAfile with placeholders for the text it could not carry verbatim.
+#[derive(Debug, Clone)]
+pub struct Rendered {
+    pub ocafile: String,
+    placeholders: BTreeMap<String, String>,
+}
+
+/// Why a rendered OCAfile could not be read back into an AST.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReadBackError<E> {
+    /// `refn:` names the resolver could not resolve.
+    References(Vec<String>),
+    /// The parser rejected the OCAfile.
+    Parse(E),
+    /// The AST did not survive its round trip through JSON.
+    Ast(String),
+}
+
+impl BundleBuilder {
+    pub fn new() -> BundleBuilder {
+        BundleBuilder::default()
+    }
+
+    /// The `--name=` of the OCAfile, by which other OCAfiles can reference
+    /// the bundle with `refn:`.
+    pub fn name(&mut self, name: &str) -> Result<(), String> {
+        check_identifier("bundle name", name)?;
+        self.name = Some(name.to_string());
+        Ok(())
+    }
+
+    /// Adds an attribute, or changes the type of one added before.
+    pub fn add_attribute(&mut self, name: &str, attribute_type: AttrType) -> Result<(), String> {
+        check_identifier("attribute name", name)?;
+        match self.attributes.iter_mut().find(|(known, _)| known == name) {
+            Some((_, known_type)) => *known_type = attribute_type,
+            None => self.attributes.push((name.to_string(), attribute_type)),
+        }
+        Ok(())
+    }
+
+    pub fn label(&mut self, language: &str, attribute: &str, label: &str) -> Result<(), String> {
+        self.attribute_property("label", Some(language), "attribute_labels", attribute, Value::from(label))
+    }
+
+    pub fn information(&mut self, language: &str, attribute: &str, information: &str) -> Result<(), String> {
+        self.attribute_property("information", Some(language), "attribute_information", attribute, Value::from(information))
+    }
+
+    /// Labels of entry codes, in the given order.
+    pub fn entries(&mut self, language: &str, attribute: &str, entries: Vec<(String, String)>) -> Result<(), String> {
+        let entries: Map<String, Value> = entries.into_iter().map(|(code, label)| (code, Value::from(label))).collect();
+        self.attribute_property("entry", Some(language), "attribute_entries", attribute, Value::Object(entries))
+    }
+
+    pub fn entry_codes(&mut self, attribute: &str, codes: Vec<String>) -> Result<(), String> {
+        self.attribute_property("entry_code", None, "attribute_entry_codes", attribute, Value::from(codes))
+    }
+
+    /// `M` (mandatory) or `O` (optional).
+    pub fn conformance(&mut self, attribute: &str, conformance: &str) -> Result<(), String> {
+        self.attribute_property("conformance", None, "attribute_conformances", attribute, Value::from(conformance))
+    }
+
+    pub fn format(&mut self, attribute: &str, format: &str) -> Result<(), String> {
+        self.attribute_property("format", None, "attribute_formats", attribute, Value::from(format))
+    }
+
+    pub fn cardinality(&mut self, attribute: &str, cardinality: &str) -> Result<(), String> {
+        self.attribute_property("cardinality", None, "attribute_cardinalities", attribute, Value::from(cardinality))
+    }
+
+    pub fn unit(&mut self, attribute: &str, unit: &str) -> Result<(), String> {
+        self.attribute_property("unit", None, "attribute_units", attribute, Value::from(unit))
+    }
+
+    pub fn character_encoding(&mut self, attribute: &str, encoding: &str) -> Result<(), String> {
+        self.attribute_property("character_encoding", None, "attribute_character_encodings", attribute, Value::from(encoding))
+    }
+
+    pub fn standard(&mut self, attribute: &str, standard: &str) -> Result<(), String> {
+        self.attribute_property("standard", None, "attribute_standards", attribute, Value::from(standard))
+    }
+
+    /// Sets the value an attribute has in an overlay that maps attributes to
+    /// values, e.g. `conformance` with field `attribute_conformances`.
+    pub fn attribute_property(
+        &mut self,
+        overlay: &str,
+        language: Option<&str>,
+        field: &str,
+        attribute: &str,
+        value: Value,
+    ) -> Result<(), String> {
+        self.check_attribute(attribute)?;
+        let properties = self.overlay_properties(overlay, language)?;
+        let values = properties
+            .entry(field.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+        match values.as_object_mut() {
+            Some(values) => {
+                values.insert(attribute.to_string(), value);
+                Ok(())
+            }
+            None => Err(format!("Field '{field}' of overlay '{overlay}' is not a map of attributes")),
+        }
+    }
+
+    /// Lists the attribute in the `sensitive` overlay.
+    pub fn sensitive(&mut self, attribute: &str) -> Result<(), String> {
+        self.check_attribute(attribute)?;
+        let properties = self.overlay_properties("sensitive", None)?;
+        let listed = properties
+            .entry("attributes".to_string())
+            .or_insert_with(|| Value::Array(vec![]));
+        match listed.as_array_mut() {
+            Some(listed) if listed.iter().any(|item| item.as_str() == Some(attribute)) => Ok(()),
+            Some(listed) => {
+                listed.push(Value::from(attribute));
+                Ok(())
+            }
+            None => Err("Field 'attributes' of overlay 'sensitive' is not a list".to_string()),
+        }
+    }
+
+    /// Adds fields such as `name` and `description` to the meta overlay of
+    /// `language`.
+    pub fn meta(&mut self, language: &str, fields: Map<String, Value>) -> Result<(), String> {
+        self.overlay("meta", Some(language), fields)
+    }
+
+    /// Adds fields to any overlay the registry defines. Maps are merged into
+    /// what earlier calls added to the same overlay and language.
+    pub fn overlay(&mut self, name: &str, language: Option<&str>, fields: Map<String, Value>) -> Result<(), String> {
+        let properties = self.overlay_properties(name, language)?;
+        for (field, value) in fields {
+            check_identifier("overlay field", &field)?;
+            merge(properties.entry(field).or_insert(Value::Null), value);
+        }
+        Ok(())
+    }
+
+    pub fn render(&self) -> Result<Rendered, String> {
+        if self.attributes.is_empty() {
+            return Err("The bundle has no attributes".to_string());
+        }
+        let mut rendered = Rendered {
+            ocafile: String::new(),
+            placeholders: BTreeMap::new(),
+        };
+        if let Some(name) = &self.name {
+            let _ = writeln!(rendered.ocafile, "--name={name}");
+        }
+        let attributes: Vec<String> = self
+            .attributes
+            .iter()
+            .map(|(name, attribute_type)| format!("{name}={attribute_type}"))
+            .collect();
+        let _ = writeln!(rendered.ocafile, "ADD ATTRIBUTE {}", attributes.join(" "));
+
+        for overlay in &self.overlays {
+            let _ = write!(rendered.ocafile, "\nADD OVERLAY {}\n", overlay.name.to_uppercase());
+            if let Some(language) = &overlay.language {
+                let _ = writeln!(rendered.ocafile, "  language=\"{language}\"");
+            }
+            for (field, value) in &overlay.properties {
+                rendered.property(field, value, 0)?;
+            }
+        }
+        Ok(rendered)
+    }
+
+    fn check_attribute(&self, attribute: &str) -> Result<(), String> {
+        if self.attributes.iter().any(|(name, _)| name == attribute) {
+            Ok(())
+        } else {
+            Err(format!("Unknown attribute '{attribute}'; add it first"))
+        }
+    }
+
+    fn overlay_properties(&mut self, name: &str, language: Option<&str>) -> Result<&mut Map<String, Value>, String> {
+        check_identifier("overlay name", name)?;
+        if let Some(language) = language {
+            if language.is_empty() || !language.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+                return Err(format!("Invalid language '{language}'"));
+            }
+        }
+        let name = name.to_lowercase();
+        let index = match self
+            .overlays
+            .iter()
+            .position(|overlay| overlay.name == name && overlay.language.as_deref() == language)
+        {
+            Some(index) => index,
+            None => {
+                self.overlays.push(Overlay {
+                    name,
+                    language: language.map(str::to_string),
+                    properties: Map::new(),
+                });
+                self.overlays.len() - 1
+            }
+        };
+        Ok(&mut self.overlays[index].properties)
+    }
+}
+
+impl Rendered {
+    /// Substitutes `refn:` names through `resolver`, parses the OCAfile with
+    /// the binding's parser and puts the text behind the placeholders back
+    /// into the AST.
+    pub fn read_back<Ast, E>(
+        &self,
+        resolver: Option<&dyn BundleResolver>,
+        parse: impl FnOnce(String) -> Result<Ast, E>,
+    ) -> Result<Ast, ReadBackError<E>>
+    where
+        Ast: Serialize + DeserializeOwned,
+    {
+        let ocafile = match resolver {
+            Some(resolver) => resolve::substitute_names(&self.ocafile, resolver).map_err(ReadBackError::References)?,
+            None => self.ocafile.clone(),
+        };
+        let ast = parse(ocafile).map_err(ReadBackError::Parse)?;
+        if self.placeholders.is_empty() {
+            return Ok(ast);
+        }
+        let mut ast_value = serde_json::to_value(&ast).map_err(|e| ReadBackError::Ast(format!("Failed to serialize OCAfile AST: {e}")))?;
+        self.restore(&mut ast_value);
+        serde_json::from_value(ast_value).map_err(|e| ReadBackError::Ast(format!("Invalid OCAfile AST: {e}")))
+    }
+
+    /// Puts the text behind each placeholder back into the AST of the
+    /// rendered OCAfile, in its JSON form.
+    fn restore(&self, ast: &mut Value) {
+        if self.placeholders.is_empty() {
+            return;
+        }
+        match ast {
+            Value::String(text) => *text = self.restore_text(text),
+            Value::Array(items) => items.iter_mut().for_each(|item| self.restore(item)),
+            Value::Object(fields) => {
+                let restored: Map<String, Value> = std::mem::take(fields)
+                    .into_iter()
+                    .map(|(key, mut value)| {
+                        self.restore(&mut value);
+                        (self.restore_text(&key), value)
+                    })
+                    .collect();
+                *fields = restored;
+            }
+            _ => {}
+        }
+    }
+
+    /// A placeholder always stands for a whole quoted string, so only exact
+    /// matches are restored and restored text is never searched again.
+    fn restore_text(&self, text: &str) -> String {
+        self.placeholders.get(text).cloned().unwrap_or_else(|| text.to_string())
+    }
+
+    /// `key="value"`, `key=["a", "b"]` or `key` followed by nested lines.
+    /// Attribute names (depth 1) are written bare, other keys quoted.
+    fn property(&mut self, key: &str, value: &Value, depth: usize) -> Result<(), String> {
+        let indent = "  ".repeat(depth + 1);
+        let key = if depth >= 2 { self.quoted(key) } else { key.to_string() };
+        match value {
+            Value::Object(fields) => {
+                let _ = writeln!(self.ocafile, "{indent}{key}");
+                for (nested_key, nested) in fields {
+                    self.property(nested_key, nested, depth + 1)?;
+                }
+            }
+            value => {
+                let value = self.scalar(value)?;
+                let _ = writeln!(self.ocafile, "{indent}{key}={value}");
+            }
+        }
+        Ok(())
+    }
+
+    fn scalar(&mut self, value: &Value) -> Result<String, String> {
+        match value {
+            Value::String(text) => Ok(self.quoted(text)),
+            Value::Number(number) => Ok(number.to_string()),
+            Value::Bool(flag) => Ok(flag.to_string()),
+            Value::Array(items) => {
+                let items = items.iter().map(|item| self.scalar(item)).collect::<Result<Vec<_>, _>>()?;
+                Ok(format!("[{}]", items.join(", ")))
+            }
+            Value::Null | Value::Object(_) => Err(format!("Cannot write {value} as an overlay value")),
+        }
+    }
+
+    fn quoted(&mut self, text: &str) -> String {
+        let verbatim = !text.contains(PLACEHOLDER_PREFIX) && !text.contains(['"', '\\', '\n', '\r']);
+        if verbatim {
+            return format!("\"{text}\"");
+        }
+        let placeholder = format!("{PLACEHOLDER_PREFIX}{:06}", self.placeholders.len());
+        self.placeholders.insert(placeholder.clone(), text.to_string());
+        format!("\"{placeholder}\"")
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ReadBackError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadBackError::References(errors) => write!(f, "Failed to resolve references: {}", errors.join("; ")),
+            ReadBackError::Parse(e) => write!(f, "Failed to parse OCAfile: {e}"),
+            ReadBackError::Ast(message) => f.write_str(message),
+        }
+    }
+}
+
+fn check_identifier(what: &str, name: &str) -> Result<(), String> {
+    if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '-')) {
+        Ok(())
+    } else {
+        Err(format!("Invalid {what} '{name}'"))
+    }
+}
+
+/// Merges maps key by key; anything else replaces what was there.
+fn merge(target: &mut Value, value: Value) {
+    match (target, value) {
+        (Value::Object(target), Value::Object(fields)) => {
+            for (key, value) in fields {
+                merge(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (target, value) => *target = value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn builder() -> BundleBuilder {
+        let mut builder = BundleBuilder::new();
+        builder.add_attribute("name", AttrType::Text).unwrap();
+        builder
+    }
+
+    #[test]
+    fn writes_plain_text_verbatim() {
+        let mut builder = builder();
+        builder.label("en", "name", "Name").unwrap();
+        let rendered = builder.render().unwrap();
+        assert_eq!(rendered.ocafile, "ADD ATTRIBUTE name=Text\n\nADD OVERLAY LABEL\n  language=\"en\"\n  attribute_labels\n    name=\"Name\"\n");
+        assert!(rendered.placeholders.is_empty());
+    }
+
+    #[test]
+    fn restores_text_that_looks_like_a_placeholder() {
+        let mut builder = builder();
+        builder.add_attribute("other", AttrType::Text).unwrap();
+        builder.label("en", "name", "Say \"oca-builder-text-000001\"").unwrap();
+        builder.label("en", "other", "oca-builder-text-000000").unwrap();
+        let rendered = builder.render().unwrap();
+        assert_eq!(rendered.placeholders.len(), 2);
+
+        let mut ast = json!({ "labels": ["oca-builder-text-000000", "oca-builder-text-000001", "oca-builder-text-000002"] });
+        rendered.restore(&mut ast);
+        assert_eq!(
+            ast,
+            json!({ "labels": ["Say \"oca-builder-text-000001\"", "oca-builder-text-000000", "oca-builder-text-000002"] })
+        );
+    }
+
+    /// Stands in for the SDK parser: the quoted strings of the OCAfile.
+    fn quoted_strings(ocafile: String) -> Result<Vec<String>, String> {
+        Ok(ocafile.split('"').skip(1).step_by(2).map(str::to_string).collect())
+    }
+
+    #[test]
+    fn reads_the_rendered_ocafile_back() {
+        let mut builder = builder();
+        builder.label("en", "name", "Line\nbreak").unwrap();
+        let rendered = builder.render().unwrap();
+        assert_eq!(rendered.read_back(None, quoted_strings).unwrap(), ["en", "Line\nbreak"]);
+
+        let failing = rendered.read_back(None, |_| Err::<Vec<String>, _>("bad".to_string())).unwrap_err();
+        assert_eq!(failing.to_string(), "Failed to parse OCAfile: bad");
+    }
+}
@@ -0,0 +1,102 @@
+//! Read-only accessors over the JSON form of an OCA bundle.
+//!
+//! Bundles reach the bindings either as serialized `OCABundle` (overlay
+//! fields at the top level of each overlay) or as a serialized
+//! `OCABundleModel` (overlay fields nested under `properties`). The helpers
+//! here accept both.
+
+use serde_json::{Map, Value};
+
+pub fn capture_base(bundle: &Value) -> Option<&Map<String, Value>> {
+    bundle.get("capture_base").and_then(Value::as_object)
+}
+
+/// Attribute names and their raw type values, as declared in the capture base.
+pub fn attributes(bundle: &Value) -> Vec<(&str, &Value)> {
+    capture_base(bundle)
+        .and_then(|cb| cb.get("attributes"))
+        .and_then(Value::as_object)
+        .map(|attrs| attrs.iter().map(|(k, v)| (k.as_str(), v)).collect())
+        .unwrap_or_default()
+}
+
+/// The SAID of a bundle or of one of its parts.
+pub fn digest(object: &Value) -> Option<&str> {
+    object.get("digest").or_else(|| object.get("d"))?.as_str()
+}
+
+pub fn overlays(bundle: &Value) -> &[Value] {
+    bundle
+        .get("overlays")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// The overlay's own fields, wherever the serialization put them.
+pub fn overlay_fields(overlay: &Value) -> Option<&Map<String, Value>> {
+    match overlay.get("properties").and_then(Value::as_object) {
+        Some(properties) => Some(properties),
+        None => overlay.as_object(),
+    }
+}
+
+/// Short overlay name, e.g. `label` for `overlay/label/2.0.0`.
+pub fn overlay_name(overlay: &Value) -> Option<&str> {
+    let overlay_type = overlay.get("type").and_then(Value::as_str)?;
+    let mut parts = overlay_type.rsplit('/');
+    let last = parts.next()?;
+    match parts.next() {
+        Some(name) if last.starts_with(|c: char| c.is_ascii_digit()) => Some(name),
+        _ => Some(last),
+    }
+}
+
+pub fn overlay_language(overlay: &Value) -> Option<&str> {
+    overlay_fields(overlay)?.get("language")?.as_str()
+}
+
+/// Looks up `field` (e.g. `attribute_formats`) of every overlay called
+/// `name` and returns the value stored for `attribute`, first match wins.
+pub fn attribute_value<'a>(bundle: &'a Value, name: &str, field: &str, attribute: &str) -> Option<&'a Value> {
+    overlays(bundle)
+        .iter()
+        .filter(|overlay| overlay_name(overlay) == Some(name))
+        .filter_map(|overlay| overlay_fields(overlay)?.get(field)?.as_object())
+        .find_map(|values| values.get(attribute))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn reads_both_overlay_serializations() {
+        let flat = json!({ "type": "overlay/label/2.0.0", "language": "en" });
+        let nested = json!({ "type": "overlay/label/2.0.0", "properties": { "language": "en" } });
+        for overlay in [&flat, &nested] {
+            assert_eq!(overlay_name(overlay), Some("label"));
+            assert_eq!(overlay_language(overlay), Some("en"));
+        }
+        assert_eq!(overlay_name(&json!({ "type": "spec/overlays/label" })), Some("label"));
+        assert_eq!(overlay_name(&json!({})), None);
+    }
+
+    #[test]
+    fn takes_the_first_value_for_an_attribute() {
+        let bundle = json!({
+            "d": "EOld",
+            "capture_base": { "attributes": { "name": "Text" } },
+            "overlays": [
+                { "type": "overlay/format/2.0.0", "attribute_formats": { "other": "x" } },
+                { "type": "overlay/format/2.0.0", "attribute_formats": { "name": "^a$" } },
+                { "type": "overlay/format/2.0.0", "attribute_formats": { "name": "^b$" } },
+            ],
+        });
+        assert_eq!(attribute_value(&bundle, "format", "attribute_formats", "name"), Some(&json!("^a$")));
+        assert_eq!(digest(&bundle), Some("EOld"));
+        assert_eq!(attributes(&bundle), [("name", &json!("Text"))]);
+    }
+}
@@ -0,0 +1,415 @@
+//! Whether data that is valid under one version of a bundle stays valid
+//! under the next, for gating schema publication.
+
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::additions::OverlayKey;
+use crate::attributes::{self, AttributeDescriptor};
+use crate::bundle;
+use crate::data::parse_cardinality;
+use crate::diff;
+use crate::types::AttrType;
+
+/// Overlays that constrain values. Changes to any other overlay only
+/// change how data is presented.
+const CONSTRAINING_OVERLAYS: &[&str] = &[
+    "conformance",
+    "entry_code",
+    "format",
+    "cardinality",
+    "unit",
+    "character_encoding",
+];
+
+/// Ordered from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    /// Both versions accept exactly the same data.
+    Compatible,
+    /// Data valid under the old version stays valid; the new one accepts more.
+    BackwardCompatible,
+    /// Some data valid under the old version is rejected or reinterpreted.
+    Breaking,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingKind {
+    AttributeAdded,
+    AttributeRemoved,
+    TypeChanged,
+    ConformanceChanged,
+    EntryCodesChanged,
+    FormatChanged,
+    CardinalityChanged,
+    UnitChanged,
+    CharacterEncodingChanged,
+    PresentationChanged,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Finding {
+    pub verdict: Verdict,
+    pub kind: FindingKind,
+    pub attribute: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Compatibility {
+    /// The most severe verdict of any finding.
+    pub verdict: Verdict,
+    pub findings: Vec<Finding>,
+}
+
+pub fn check(old: &Value, new: &Value) -> Compatibility {
+    let bundle_diff = diff::diff(old, new);
+    let mut findings = Findings(vec![]);
+
+    for attribute in &bundle_diff.added_attributes {
+        let name = &attribute.name;
+        if attributes::describe(new, name).conformance.as_deref() == Some("M") {
+            findings.push(Verdict::Breaking, FindingKind::AttributeAdded, name, format!(
+                "Mandatory attribute '{name}' was added; records without it are rejected"
+            ));
+        } else {
+            findings.push(Verdict::BackwardCompatible, FindingKind::AttributeAdded, name, format!(
+                "Optional attribute '{name}' was added"
+            ));
+        }
+    }
+    for attribute in &bundle_diff.removed_attributes {
+        let name = &attribute.name;
+        findings.push(Verdict::Breaking, FindingKind::AttributeRemoved, name, format!(
+            "Attribute '{name}' was removed; records that carry it no longer match the schema"
+        ));
+    }
+    for retyped in &bundle_diff.retyped_attributes {
+        let name = &retyped.name;
+        let type_name = |t: &Option<AttrType>| t.as_ref().map_or("(unknown)".to_string(), ToString::to_string);
+        let (old_type, new_type) = (type_name(&retyped.old_type), type_name(&retyped.new_type));
+        match (&retyped.old_type, &retyped.new_type) {
+            (Some(before), Some(after)) if widens(before, after) => {
+                findings.push(Verdict::BackwardCompatible, FindingKind::TypeChanged, name, format!(
+                    "The type of '{name}' was widened from {old_type} to {new_type}"
+                ))
+            }
+            _ => findings.push(Verdict::Breaking, FindingKind::TypeChanged, name, format!(
+                "The type of '{name}' changed from {old_type} to {new_type}; values of the old type are rejected"
+            )),
+        }
+    }
+
+    for (name, _) in bundle::attributes(old) {
+        if bundle_diff.removed_attributes.iter().any(|attribute| attribute.name == name) {
+            continue;
+        }
+        let before = attributes::describe(old, name);
+        let after = attributes::describe(new, name);
+        check_conformance(&mut findings, name, &before, &after);
+        check_entry_codes(&mut findings, name, &before, &after);
+        let never = |_: &str, _: &str| false;
+        let constraints = [
+            (FindingKind::FormatChanged, "format", &before.format, &after.format, format_loosens as Loosens),
+            (FindingKind::CardinalityChanged, "cardinality", &before.cardinality, &after.cardinality, cardinality_loosens),
+            (FindingKind::UnitChanged, "unit", &before.unit, &after.unit, never),
+            (FindingKind::CharacterEncodingChanged, "character encoding", &before.character_encoding, &after.character_encoding, never),
+        ];
+        for (kind, what, before, after, loosens) in constraints {
+            check_constraint(&mut findings, name, kind, what, (before, after), loosens);
+        }
+    }
+
+    let overlay_changes = [
+        ("added", &bundle_diff.added_overlays),
+        ("removed", &bundle_diff.removed_overlays),
+        ("changed", &bundle_diff.changed_overlays),
+    ];
+    for (change, keys) in overlay_changes {
+        for key in keys.iter().filter(|key| !is_constraining(key)) {
+            let language = key.language.as_ref().map(|l| format!(" ({l})")).unwrap_or_default();
+            findings.0.push(Finding {
+                verdict: Verdict::Compatible,
+                kind: FindingKind::PresentationChanged,
+                attribute: None,
+                message: format!("Overlay {}{language} was {change}; it does not affect which data is valid", key.overlay_type),
+            });
+        }
+    }
+
+    let findings = findings.0;
+    Compatibility {
+        verdict: findings.iter().map(|finding| finding.verdict).max().unwrap_or(Verdict::Compatible),
+        findings,
+    }
+}
+
+struct Findings(Vec<Finding>);
+
+impl Findings {
+    fn push(&mut self, verdict: Verdict, kind: FindingKind, attribute: &str, message: String) {
+        self.0.push(Finding {
+            verdict,
+            kind,
+            attribute: Some(attribute.to_string()),
+            message,
+        });
+    }
+}
+
+fn is_constraining(key: &OverlayKey) -> bool {
+    let name = key.overlay_type.split('/').nth(1).unwrap_or(&key.overlay_type);
+    CONSTRAINING_OVERLAYS.contains(&name)
+}
+
+fn check_conformance(findings: &mut Findings, name: &str, before: &AttributeDescriptor, after: &AttributeDescriptor) {
+    let mandatory = |descriptor: &AttributeDescriptor| descriptor.conformance.as_deref() == Some("M");
+    match (mandatory(before), mandatory(after)) {
+        (false, true) => findings.push(Verdict::Breaking, FindingKind::ConformanceChanged, name, format!(
+            "'{name}' became mandatory; records without it are rejected"
+        )),
+        (true, false) => findings.push(Verdict::BackwardCompatible, FindingKind::ConformanceChanged, name, format!(
+            "'{name}' became optional"
+        )),
+        _ => {}
+    }
+}
+
+fn check_entry_codes(findings: &mut Findings, name: &str, before: &AttributeDescriptor, after: &AttributeDescriptor) {
+    let codes = |value: &Value| -> Option<Vec<String>> {
+        value.as_array()?.iter().map(|code| code.as_str().map(str::to_string)).collect()
+    };
+    let kind = FindingKind::EntryCodesChanged;
+    match (&before.entry_codes, &after.entry_codes) {
+        (None, None) => {}
+        (Some(_), None) => findings.push(Verdict::BackwardCompatible, kind, name, format!(
+            "Values of '{name}' are no longer restricted to entry codes"
+        )),
+        (None, Some(_)) => findings.push(Verdict::Breaking, kind, name, format!(
+            "Values of '{name}' are now restricted to entry codes"
+        )),
+        (Some(before), Some(after)) => match (codes(before), codes(after)) {
+            (Some(before), Some(after)) => {
+                let removed: Vec<&str> = before.iter().filter(|c| !after.contains(c)).map(String::as_str).collect();
+                let added: Vec<&str> = after.iter().filter(|c| !before.contains(c)).map(String::as_str).collect();
+                if !removed.is_empty() {
+                    findings.push(Verdict::Breaking, kind, name, format!(
+                        "Entry codes {} of '{name}' were removed; records using them are rejected",
+                        removed.join(", ")
+                    ));
+                }
+                if !added.is_empty() {
+                    findings.push(Verdict::BackwardCompatible, kind, name, format!(
+                        "Entry codes {} of '{name}' were added",
+                        added.join(", ")
+                    ));
+                }
+            }
+            _ if before != after => findings.push(Verdict::Breaking, kind, name, format!(
+                "The entry code list of '{name}' changed from {before} to {after}"
+            )),
+            _ => {}
+        },
+    }
+}
+
+/// Whether every value the first constraint accepts is accepted by the
+/// second.
+type Loosens = fn(&str, &str) -> bool;
+
+fn check_constraint(
+    findings: &mut Findings,
+    name: &str,
+    kind: FindingKind,
+    what: &str,
+    (before, after): (&Option<String>, &Option<String>),
+    loosens: Loosens,
+) {
+    match (before, after) {
+        (Some(before), None) => findings.push(Verdict::BackwardCompatible, kind, name, format!(
+            "The {what} {before:?} of '{name}' was dropped"
+        )),
+        (None, Some(after)) => findings.push(Verdict::Breaking, kind, name, format!(
+            "'{name}' now has the {what} {after:?}; values valid before may not match it"
+        )),
+        (Some(before), Some(after)) if before != after && loosens(before, after) => {
+            findings.push(Verdict::BackwardCompatible, kind, name, format!(
+                "The {what} of '{name}' was loosened from {before:?} to {after:?}"
+            ))
+        }
+        (Some(before), Some(after)) if before != after => findings.push(Verdict::Breaking, kind, name, format!(
+            "The {what} of '{name}' changed from {before:?} to {after:?}; values valid before may not match it"
+        )),
+        _ => {}
+    }
+}
+
+/// Strings of any kind are text, so dates and binaries widen to `Text`;
+/// arrays widen with their items.
+fn widens(before: &AttrType, after: &AttrType) -> bool {
+    match (before, after) {
+        (AttrType::DateTime | AttrType::Binary, AttrType::Text) => true,
+        (AttrType::Array(before), AttrType::Array(after)) => before == after || widens(before, after),
+        _ => false,
+    }
+}
+
+/// The new range contains the old one, e.g. `1..2` to `0..5`.
+fn cardinality_loosens(before: &str, after: &str) -> bool {
+    let (Some((old_min, old_max)), Some((new_min, new_max))) = (parse_cardinality(before), parse_cardinality(after)) else {
+        return false;
+    };
+    let max_contains = match (old_max, new_max) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(old_max), Some(new_max)) => new_max >= old_max,
+    };
+    new_min <= old_min && max_contains
+}
+
+/// Only recognises a regex gaining alternatives, e.g. `^(a|b)$` to
+/// `^(a|b|c)$`; other format changes cannot be compared.
+fn format_loosens(before: &str, after: &str) -> bool {
+    match (alternatives(before), alternatives(after)) {
+        (Some(before), Some(after)) => before.iter().all(|branch| after.contains(branch)),
+        _ => false,
+    }
+}
+
+/// The top-level alternatives of an anchored regex, with an enclosing group
+/// taken off.
+fn alternatives(format: &str) -> Option<Vec<&str>> {
+    let mut body = format.strip_prefix('^')?.strip_suffix('$')?;
+    if let Some(inner) = body.strip_prefix("(?:").or_else(|| body.strip_prefix('(')).and_then(|b| b.strip_suffix(')')) {
+        if split_top_level(inner).is_some() {
+            body = inner;
+        }
+    }
+    split_top_level(body)
+}
+
+/// Splits at `|` outside groups and classes; `None` if the brackets do not
+/// balance.
+fn split_top_level(regex: &str) -> Option<Vec<&str>> {
+    let (mut depth, mut in_class, mut escaped) = (0usize, false, false);
+    let (mut branches, mut start) = (vec![], 0);
+    for (i, c) in regex.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            ']' if in_class => in_class = false,
+            _ if in_class => {}
+            '[' => in_class = true,
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1)?,
+            '|' if depth == 0 => {
+                branches.push(&regex[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    (depth == 0 && !in_class && !escaped).then(|| {
+        branches.push(&regex[start..]);
+        branches
+    })
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Compatible => "compatible",
+            Verdict::BackwardCompatible => "backward-compatible",
+            Verdict::Breaking => "breaking",
+        })
+    }
+}
+
+/// The verdict followed by one finding per line, most severe first.
+impl fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Verdict: {}", self.verdict)?;
+        let mut findings: Vec<&Finding> = self.findings.iter().collect();
+        findings.sort_by_key(|finding| std::cmp::Reverse(finding.verdict));
+        for finding in findings {
+            writeln!(f, "  [{}] {}", finding.verdict, finding.message)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn bundle(attr_type: &str, cardinality: Option<&str>, format: Option<&str>) -> Value {
+        let mut overlays = vec![];
+        if let Some(cardinality) = cardinality {
+            overlays.push(json!({ "type": "overlay/cardinality/2.0.0", "attribute_cardinalities": { "a": cardinality } }));
+        }
+        if let Some(format) = format {
+            overlays.push(json!({ "type": "overlay/format/2.0.0", "attribute_formats": { "a": format } }));
+        }
+        json!({ "capture_base": { "attributes": { "a": attr_type } }, "overlays": overlays })
+    }
+
+    fn verdict(old: &Value, new: &Value, kind: FindingKind) -> Verdict {
+        let compatibility = check(old, new);
+        let finding = compatibility.findings.iter().find(|finding| finding.kind == kind).unwrap();
+        finding.verdict
+    }
+
+    #[test]
+    fn loosened_cardinality_is_backward_compatible() {
+        for (old, new) in [("1..2", "0..5"), ("1..2", "1.."), ("2", "1..3"), ("1..*", "0..n")] {
+            let (old, new) = (bundle("Text", Some(old), None), bundle("Text", Some(new), None));
+            assert_eq!(verdict(&old, &new, FindingKind::CardinalityChanged), Verdict::BackwardCompatible);
+        }
+    }
+
+    #[test]
+    fn narrowed_cardinality_is_breaking() {
+        for (old, new) in [("0..5", "1..2"), ("1..", "1..9"), ("1..3", "2"), ("1..2", "many")] {
+            let (old, new) = (bundle("Text", Some(old), None), bundle("Text", Some(new), None));
+            assert_eq!(verdict(&old, &new, FindingKind::CardinalityChanged), Verdict::Breaking);
+        }
+    }
+
+    #[test]
+    fn widened_types_are_backward_compatible() {
+        for (old, new) in [("DateTime", "Text"), ("Binary", "Text"), ("Array[DateTime]", "Array[Text]")] {
+            assert_eq!(verdict(&bundle(old, None, None), &bundle(new, None, None), FindingKind::TypeChanged), Verdict::BackwardCompatible);
+        }
+    }
+
+    #[test]
+    fn narrowed_or_unrelated_types_are_breaking() {
+        for (old, new) in [("Text", "DateTime"), ("Numeric", "Text"), ("Text", "Array[Text]"), ("Array[Text]", "Array[Binary]")] {
+            assert_eq!(verdict(&bundle(old, None, None), &bundle(new, None, None), FindingKind::TypeChanged), Verdict::Breaking);
+        }
+    }
+
+    #[test]
+    fn formats_gaining_alternatives_are_backward_compatible() {
+        let (old, new) = (bundle("Text", None, Some("^(a|b)$")), bundle("Text", None, Some("^(?:a|b|[c|d])$")));
+        assert_eq!(verdict(&old, &new, FindingKind::FormatChanged), Verdict::BackwardCompatible);
+        assert_eq!(verdict(&new, &old, FindingKind::FormatChanged), Verdict::Breaking);
+        let (old, new) = (bundle("DateTime", None, Some("YYYY-MM-DD")), bundle("DateTime", None, Some("DD.MM.YYYY")));
+        assert_eq!(verdict(&old, &new, FindingKind::FormatChanged), Verdict::Breaking);
+    }
+
+    #[test]
+    fn splits_only_top_level_alternatives() {
+        assert_eq!(alternatives("^(a|b)c|d$"), Some(vec!["(a|b)c", "d"]));
+        assert_eq!(alternatives(r"^a\|b|[|]$"), Some(vec![r"a\|b", "[|]"]));
+        assert_eq!(alternatives("^(a|b)$"), Some(vec!["a", "b"]));
+        assert_eq!(alternatives("^(a$"), None);
+        assert_eq!(alternatives("a|b"), None);
+    }
+}
@@ -0,0 +1,467 @@
+//! Data record validation with structured, per-attribute errors.
+
+use regex::Regex;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::bundle;
+use crate::resolve::{BundleResolver, Reference, Trail};
+use crate::types::AttrType;
+
+/// The constraint a data error refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Rule {
+    Type,
+    Conformance,
+    EntryCode,
+    Format,
+    Cardinality,
+    /// A referenced bundle could not be resolved.
+    Reference,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DataError {
+    pub attribute: Option<String>,
+    /// JSON pointer to the offending value within the record.
+    pub path: String,
+    pub rule: Rule,
+    pub expected: Option<Value>,
+    pub actual: Option<Value>,
+    pub message: String,
+}
+
+/// Validates `record` against the attributes and constraint overlays of
+/// `bundle` (the bundle's JSON form). `sdk` runs the SDK validator on a
+/// record. It only checks scalar types, so it is only asked about scalar
+/// values the structured checks found nothing wrong with, and its messages
+/// are added as `Type` errors. Like the structured checks, it treats `null`
+/// as absent.
+pub fn validate_record<E>(
+    bundle: &Value,
+    record: &Value,
+    sdk: impl FnMut(&Value) -> Result<Vec<String>, E>,
+) -> Result<Vec<DataError>, E> {
+    validate_record_with(bundle, record, sdk, None)
+}
+
+/// Like [`validate_record`], but with a resolver the values of `refs:` and
+/// `refn:` attributes are validated against the bundles they reference.
+pub fn validate_record_with<E>(
+    bundle: &Value,
+    record: &Value,
+    mut sdk: impl FnMut(&Value) -> Result<Vec<String>, E>,
+    resolver: Option<&dyn BundleResolver>,
+) -> Result<Vec<DataError>, E> {
+    let mut validator = Validator {
+        errors: vec![],
+        resolver,
+        trail: Trail::for_data(),
+    };
+    validator.check_record(bundle, record, "");
+    let mut errors = validator.errors;
+
+    let Some(fields) = record.as_object() else {
+        return Ok(errors);
+    };
+    for (name, raw_type) in bundle::attributes(bundle) {
+        let Some(value) = fields.get(name).filter(|v| !(v.is_null() || v.is_array() || v.is_object())) else {
+            continue;
+        };
+        let path = pointer("", name);
+        let covered = |error: &DataError| error.path == path || error.path.starts_with(&format!("{path}/"));
+        if errors.iter().any(covered) {
+            continue;
+        }
+        // The SDK is asked about one attribute at a time, so whatever it
+        // reports belongs to that attribute.
+        let single = Value::Object([(name.to_string(), value.clone())].into_iter().collect());
+        for message in sdk(&single)? {
+            errors.push(DataError {
+                attribute: Some(name.to_string()),
+                path: path.clone(),
+                rule: Rule::Type,
+                expected: AttrType::from_json(raw_type).map(|t| json!(t.to_string())),
+                actual: Some(value.clone()),
+                message,
+            });
+        }
+    }
+    Ok(errors)
+}
+
+struct Constraints<'a> {
+    mandatory: bool,
+    entry_codes: Option<Vec<&'a str>>,
+    format: Option<&'a str>,
+    cardinality: Option<(&'a str, usize, Option<usize>)>,
+}
+
+impl<'a> Constraints<'a> {
+    fn of(bundle: &'a Value, attribute: &str) -> Constraints<'a> {
+        let lookup = |name, field| bundle::attribute_value(bundle, name, field, attribute);
+        Constraints {
+            mandatory: lookup("conformance", "attribute_conformances").and_then(Value::as_str) == Some("M"),
+            entry_codes: lookup("entry_code", "attribute_entry_codes")
+                .and_then(Value::as_array)
+                .map(|codes| codes.iter().filter_map(Value::as_str).collect()),
+            format: lookup("format", "attribute_formats").and_then(Value::as_str),
+            cardinality: lookup("cardinality", "attribute_cardinalities")
+                .and_then(Value::as_str)
+                .and_then(|c| parse_cardinality(c).map(|(min, max)| (c, min, max))),
+        }
+    }
+}
+
+/// Parses `n`, `n..m` and open-ended `n..`, `n..*` or `n..n` cardinalities.
+pub(crate) fn parse_cardinality(cardinality: &str) -> Option<(usize, Option<usize>)> {
+    match cardinality.split_once("..") {
+        None => {
+            let n = cardinality.trim().parse().ok()?;
+            Some((n, Some(n)))
+        }
+        Some((min, max)) => {
+            let min = min.trim().parse().ok()?;
+            let max = match max.trim() {
+                "" | "*" | "n" => None,
+                max => Some(max.parse().ok()?),
+            };
+            Some((min, max))
+        }
+    }
+}
+
+/// Turns a format into a regex. Formats that already look like a regex are
+/// used as is, date patterns such as `YYYY-MM-DD` are translated, anything
+/// else (e.g. media types for binaries) is not checkable and yields `None`.
+/// `MM` is the month, unless it directly follows an hour, as in `hh:MM`.
+fn format_regex(format: &str) -> Option<Regex> {
+    if format.contains(['^', '$', '\\', '[', '(']) {
+        return Regex::new(format).ok();
+    }
+    if !format.contains("YY") && !format.contains("DD") && !format.contains("hh") && !format.contains("HH") {
+        return None;
+    }
+    const MONTH: &str = r"(0[1-9]|1[0-2])";
+    const MINUTE: &str = r"[0-5]\d";
+    const TOKENS: [(&str, &str); 8] = [
+        ("YYYY", r"\d{4}"),
+        ("YY", r"\d{2}"),
+        ("MM", MONTH),
+        ("DD", r"(0[1-9]|[12]\d|3[01])"),
+        ("hh", r"([01]\d|2[0-3])"),
+        ("HH", r"([01]\d|2[0-3])"),
+        ("mm", MINUTE),
+        ("ss", r"[0-5]\d"),
+    ];
+    let mut pattern = String::from("^");
+    let mut rest = format;
+    let mut after_hour = false;
+    'outer: while let Some(c) = rest.chars().next() {
+        for (token, regex) in TOKENS {
+            if let Some(tail) = rest.strip_prefix(token) {
+                let regex = if token == "MM" && after_hour { MINUTE } else { regex };
+                after_hour = matches!(token, "hh" | "HH");
+                pattern.push_str(regex);
+                rest = tail;
+                continue 'outer;
+            }
+        }
+        after_hour = after_hour && c == ':';
+        pattern.push_str(&regex::escape(&c.to_string()));
+        rest = &rest[c.len_utf8()..];
+    }
+    pattern.push('$');
+    Regex::new(&pattern).ok()
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn pointer(parent: &str, segment: &str) -> String {
+    format!("{parent}/{}", segment.replace('~', "~0").replace('/', "~1"))
+}
+
+/// The value itself, or each element of an array value, with its path.
+fn elements<'v>(path: &str, value: &'v Value) -> Vec<(String, &'v Value)> {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (pointer(path, &i.to_string()), v))
+            .collect(),
+        _ => vec![(path.to_string(), value)],
+    }
+}
+
+struct Validator<'r> {
+    errors: Vec<DataError>,
+    resolver: Option<&'r dyn BundleResolver>,
+    trail: Trail,
+}
+
+impl Validator<'_> {
+    fn error(&mut self, attribute: &str, path: &str, rule: Rule, expected: Value, actual: Option<&Value>, message: String) {
+        self.errors.push(DataError {
+            attribute: Some(attribute.to_string()),
+            path: path.to_string(),
+            rule,
+            expected: Some(expected),
+            actual: actual.cloned(),
+            message,
+        });
+    }
+
+    fn check_record(&mut self, bundle: &Value, record: &Value, path: &str) {
+        let Some(fields) = record.as_object() else {
+            self.errors.push(DataError {
+                attribute: None,
+                path: path.to_string(),
+                rule: Rule::Type,
+                expected: Some(json!("object")),
+                actual: Some(record.clone()),
+                message: format!("Record must be a JSON object, got {}", json_type(record)),
+            });
+            return;
+        };
+
+        for (name, raw_type) in bundle::attributes(bundle) {
+            let path = pointer(path, name);
+            let constraints = Constraints::of(bundle, name);
+            let value = fields.get(name).filter(|v| !v.is_null());
+
+            let Some(value) = value else {
+                if constraints.mandatory {
+                    self.error(name, &path, Rule::Conformance, json!("M"), None, format!("Attribute '{name}' is mandatory"));
+                } else if let Some((cardinality, min, _)) = constraints.cardinality.filter(|(_, min, _)| *min > 0) {
+                    self.error(
+                        name,
+                        &path,
+                        Rule::Cardinality,
+                        json!(cardinality),
+                        None,
+                        format!("Attribute '{name}' requires at least {min} value(s)"),
+                    );
+                }
+                continue;
+            };
+
+            if let Some(attr_type) = AttrType::from_json(raw_type) {
+                self.check_type(name, &path, &attr_type, value);
+            }
+            self.check_cardinality(name, &path, &constraints, value);
+            self.check_entry_codes(name, &path, &constraints, value);
+            self.check_format(name, &path, &constraints, value);
+        }
+    }
+
+    fn check_type(&mut self, name: &str, path: &str, attr_type: &AttrType, value: &Value) {
+        let matches = match (attr_type, value) {
+            (AttrType::Text | AttrType::DateTime | AttrType::Binary, Value::String(_)) => true,
+            (AttrType::Numeric, Value::Number(_)) => true,
+            (AttrType::Boolean, Value::Bool(_)) => true,
+            (AttrType::RefSaid(_) | AttrType::RefName(_), Value::Object(_)) => {
+                self.check_reference(name, path, attr_type, value);
+                true
+            }
+            (AttrType::Array(items), Value::Array(values)) => {
+                for (i, item) in values.iter().enumerate() {
+                    self.check_type(name, &pointer(path, &i.to_string()), items, item);
+                }
+                true
+            }
+            _ => false,
+        };
+        if !matches {
+            self.error(
+                name,
+                path,
+                Rule::Type,
+                json!(attr_type.to_string()),
+                Some(value),
+                format!("Attribute '{name}' expects {attr_type}, got {}", json_type(value)),
+            );
+        }
+    }
+
+    fn check_reference(&mut self, name: &str, path: &str, attr_type: &AttrType, value: &Value) {
+        let (Some(resolver), Some(reference)) = (self.resolver, Reference::of(attr_type)) else {
+            return;
+        };
+        match self.trail.follow(resolver, &reference) {
+            Ok(referenced) => {
+                self.trail.push(reference);
+                self.check_record(&referenced, value, path);
+                self.trail.pop();
+            }
+            Err(message) => self.error(name, path, Rule::Reference, json!(reference.to_string()), None, message),
+        }
+    }
+
+    fn check_cardinality(&mut self, name: &str, path: &str, constraints: &Constraints, value: &Value) {
+        let Some((cardinality, min, max)) = constraints.cardinality else {
+            return;
+        };
+        let count = value.as_array().map_or(1, Vec::len);
+        if count < min || max.is_some_and(|max| count > max) {
+            self.error(
+                name,
+                path,
+                Rule::Cardinality,
+                json!(cardinality),
+                Some(&json!(count)),
+                format!("Attribute '{name}' has {count} value(s), expected {cardinality}"),
+            );
+        }
+    }
+
+    fn check_entry_codes(&mut self, name: &str, path: &str, constraints: &Constraints, value: &Value) {
+        let Some(codes) = constraints.entry_codes.as_ref() else {
+            return;
+        };
+        for (path, value) in elements(path, value) {
+            if !value.as_str().is_some_and(|v| codes.contains(&v)) {
+                self.error(
+                    name,
+                    &path,
+                    Rule::EntryCode,
+                    json!(codes),
+                    Some(value),
+                    format!("Attribute '{name}' must be one of [{}], got {value}", codes.join(", ")),
+                );
+            }
+        }
+    }
+
+    fn check_format(&mut self, name: &str, path: &str, constraints: &Constraints, value: &Value) {
+        let Some(format) = constraints.format else {
+            return;
+        };
+        let Some(regex) = format_regex(format) else {
+            return;
+        };
+        for (path, value) in elements(path, value) {
+            if let Some(text) = value.as_str().filter(|text| !regex.is_match(text)) {
+                self.error(
+                    name,
+                    &path,
+                    Rule::Format,
+                    json!(format),
+                    Some(value),
+                    format!("Attribute '{name}' value '{text}' does not match format {format}"),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(format: &str, text: &str) -> bool {
+        format_regex(format).unwrap().is_match(text)
+    }
+
+    #[test]
+    fn translates_date_formats() {
+        assert!(matches("YYYY-MM-DD", "2024-02-29"));
+        assert!(!matches("YYYY-MM-DD", "2024-13-01"));
+        assert!(!matches("YYYY-MM-DD", "2024-01-32"));
+        assert!(!matches("YYYY-MM-DD", "24-01-01"));
+        assert!(matches("DD.MM.YY", "31.12.99"));
+    }
+
+    #[test]
+    fn reads_mm_after_an_hour_as_minutes() {
+        assert!(matches("hh:MM", "23:59"));
+        assert!(!matches("hh:MM", "24:00"));
+        assert!(matches("YYYY-MM-DD HH:MM:ss", "2024-05-01 10:45:30"));
+        assert!(!matches("YYYY-MM-DD HH:MM:ss", "2024-45-01 10:05:30"));
+        assert!(matches("MM/hh", "12/08"));
+        assert!(!matches("MM/hh", "45/08"));
+    }
+
+    #[test]
+    fn uses_regex_formats_as_is() {
+        assert!(matches(r"^[A-Z]{2}\d+$", "AB12"));
+        assert!(!matches(r"^[A-Z]{2}\d+$", "ab12"));
+        assert!(format_regex("image/jpeg").is_none());
+        assert!(format_regex("^(unclosed$").is_none());
+    }
+
+    #[test]
+    fn parses_cardinalities() {
+        assert_eq!(parse_cardinality("3"), Some((3, Some(3))));
+        assert_eq!(parse_cardinality("1..5"), Some((1, Some(5))));
+        assert_eq!(parse_cardinality(" 0 .. 2 "), Some((0, Some(2))));
+        for open in ["1..", "1..*", "1..n"] {
+            assert_eq!(parse_cardinality(open), Some((1, None)), "{open}");
+        }
+        for invalid in ["", "a", "..3", "1..x", "-1"] {
+            assert_eq!(parse_cardinality(invalid), None, "{invalid}");
+        }
+    }
+
+    fn bundle() -> Value {
+        json!({
+            "capture_base": { "attributes": { "age": "Numeric", "name": "Text", "sex": "Text" } },
+            "overlays": [
+                { "type": "overlay/conformance/2.0.0", "attribute_conformances": { "name": "M" } },
+                { "type": "overlay/entry_code/2.0.0", "attribute_entry_codes": { "sex": ["M", "F"] } },
+            ],
+        })
+    }
+
+    fn no_sdk_errors(_: &Value) -> Result<Vec<String>, ()> {
+        Ok(vec![])
+    }
+
+    #[test]
+    fn checks_what_the_sdk_does_not() {
+        let errors = validate_record(&bundle(), &json!({ "sex": "Q" }), no_sdk_errors).unwrap();
+        let rules: Vec<(&str, Rule)> = errors.iter().map(|e| (e.path.as_str(), e.rule)).collect();
+        assert_eq!(rules, [("/name", Rule::Conformance), ("/sex", Rule::EntryCode)]);
+        assert!(validate_record(&bundle(), &json!({ "name": "Alice", "sex": "F" }), no_sdk_errors).unwrap().is_empty());
+    }
+
+    #[test]
+    fn adds_sdk_errors_for_attributes_found_valid() {
+        let record = json!({ "age": "ten", "name": "age", "sex": "F" });
+        let mut asked = vec![];
+        let sdk = |record: &Value| -> Result<Vec<String>, ()> {
+            asked.push(record.clone());
+            Ok(vec![format!("SDK says no to {record}")])
+        };
+        let errors = validate_record(&bundle(), &record, sdk).unwrap();
+        // `age` already has a type error, so the SDK is only asked about
+        // the others, one at a time.
+        assert_eq!(asked, [json!({ "name": "age" }), json!({ "sex": "F" })]);
+        let rules: Vec<(&str, Rule)> = errors.iter().map(|e| (e.path.as_str(), e.rule)).collect();
+        assert_eq!(rules, [("/age", Rule::Type), ("/name", Rule::Type), ("/sex", Rule::Type)]);
+        assert_eq!(errors[1].expected, Some(json!("Text")));
+
+        let failing = |_: &Value| Err("no SDK");
+        assert_eq!(validate_record(&bundle(), &record, failing), Err("no SDK"));
+    }
+
+    #[test]
+    fn treats_null_as_absent_for_the_sdk_too() {
+        // Like the SDK, which reports `null` as a value of the wrong type.
+        let sdk = |record: &Value| -> Result<Vec<String>, ()> {
+            let nulls = record.as_object().unwrap().iter().filter(|(_, value)| value.is_null());
+            Ok(nulls.map(|(name, _)| format!("Attribute \"{name}\" value (null) is not a number")).collect())
+        };
+        let record = json!({ "name": "Alice", "age": null, "sex": null });
+        assert_eq!(validate_record(&bundle(), &record, sdk).unwrap(), []);
+    }
+}
@@ -0,0 +1,304 @@
+//! What changed between two versions of a bundle, for reviewing schema
+//! changes beyond "the SAID is different".
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::additions::{self, OverlayKey};
+use crate::attributes;
+use crate::bundle;
+use crate::types::AttrType;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct BundleDiff {
+    pub old_digest: Option<String>,
+    pub new_digest: Option<String>,
+    pub added_attributes: Vec<Attribute>,
+    pub removed_attributes: Vec<Attribute>,
+    pub retyped_attributes: Vec<Retyped>,
+    pub added_overlays: Vec<OverlayKey>,
+    pub removed_overlays: Vec<OverlayKey>,
+    /// Overlays in both bundles whose content differs.
+    pub changed_overlays: Vec<OverlayKey>,
+    pub label_changes: Vec<LabelChange>,
+    pub entry_changes: Vec<EntryChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Attribute {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub attribute_type: Option<AttrType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Retyped {
+    pub name: String,
+    pub old_type: Option<AttrType>,
+    pub new_type: Option<AttrType>,
+}
+
+/// A label added (`old` is `None`), removed (`new` is `None`) or changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LabelChange {
+    pub language: String,
+    pub attribute: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// Like [`LabelChange`], for the label of one entry code.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EntryChange {
+    pub language: String,
+    pub attribute: String,
+    pub code: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+impl BundleDiff {
+    /// Whether the bundles differ in nothing but their SAIDs.
+    pub fn is_empty(&self) -> bool {
+        self.added_attributes.is_empty()
+            && self.removed_attributes.is_empty()
+            && self.retyped_attributes.is_empty()
+            && self.added_overlays.is_empty()
+            && self.removed_overlays.is_empty()
+            && self.changed_overlays.is_empty()
+            && self.label_changes.is_empty()
+            && self.entry_changes.is_empty()
+    }
+}
+
+pub fn diff(old: &Value, new: &Value) -> BundleDiff {
+    let old_attributes = attribute_types(old);
+    let new_attributes = attribute_types(new);
+    let mut diff = BundleDiff {
+        old_digest: bundle::digest(old).map(str::to_string),
+        new_digest: bundle::digest(new).map(str::to_string),
+        ..BundleDiff::default()
+    };
+
+    for (name, new_type) in &new_attributes {
+        match old_attributes.iter().find(|(old_name, _)| old_name == name) {
+            None => diff.added_attributes.push(Attribute {
+                name: name.clone(),
+                attribute_type: new_type.clone(),
+            }),
+            Some((_, old_type)) if old_type != new_type => diff.retyped_attributes.push(Retyped {
+                name: name.clone(),
+                old_type: old_type.clone(),
+                new_type: new_type.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (name, old_type) in &old_attributes {
+        if !new_attributes.iter().any(|(new_name, _)| new_name == name) {
+            diff.removed_attributes.push(Attribute {
+                name: name.clone(),
+                attribute_type: old_type.clone(),
+            });
+        }
+    }
+
+    let old_overlays = overlay_contents(old);
+    let new_overlays = overlay_contents(new);
+    for (key, contents) in &new_overlays {
+        match old_overlays.iter().find(|(old_key, _)| old_key == key) {
+            None => diff.added_overlays.push(key.clone()),
+            Some((_, old_contents)) if old_contents != contents => diff.changed_overlays.push(key.clone()),
+            Some(_) => {}
+        }
+    }
+    for (key, _) in &old_overlays {
+        if !new_overlays.iter().any(|(new_key, _)| new_key == key) {
+            diff.removed_overlays.push(key.clone());
+        }
+    }
+
+    let mut names: Vec<&String> = old_attributes.iter().map(|(name, _)| name).collect();
+    names.extend(diff.added_attributes.iter().map(|attribute| &attribute.name));
+    for name in names {
+        let old_descriptor = attributes::describe(old, name);
+        let new_descriptor = attributes::describe(new, name);
+        for (language, old_label, new_label) in changes(&old_descriptor.labels, &new_descriptor.labels) {
+            diff.label_changes.push(LabelChange {
+                language,
+                attribute: name.clone(),
+                old: old_label.cloned(),
+                new: new_label.cloned(),
+            });
+        }
+        for language in union_keys(&old_descriptor.entries, &new_descriptor.entries) {
+            let empty = BTreeMap::new();
+            let old_entries = old_descriptor.entries.get(&language).unwrap_or(&empty);
+            let new_entries = new_descriptor.entries.get(&language).unwrap_or(&empty);
+            for (code, old_label, new_label) in changes(old_entries, new_entries) {
+                diff.entry_changes.push(EntryChange {
+                    language: language.clone(),
+                    attribute: name.clone(),
+                    code,
+                    old: old_label.cloned(),
+                    new: new_label.cloned(),
+                });
+            }
+        }
+    }
+    diff
+}
+
+fn attribute_types(bundle: &Value) -> Vec<(String, Option<AttrType>)> {
+    bundle::attributes(bundle)
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), AttrType::from_json(value)))
+        .collect()
+}
+
+/// Overlay contents without SAIDs, grouped by type and language.
+fn overlay_contents(bundle: &Value) -> Vec<(OverlayKey, Vec<serde_json::Map<String, Value>>)> {
+    let mut grouped: Vec<(OverlayKey, Vec<_>)> = vec![];
+    for overlay in bundle::overlays(bundle) {
+        let key = OverlayKey::of(overlay);
+        let content = additions::content(overlay);
+        match grouped.iter_mut().find(|(known, _)| *known == key) {
+            Some((_, contents)) => contents.push(content),
+            None => grouped.push((key, vec![content])),
+        }
+    }
+    grouped
+}
+
+fn union_keys<V>(old: &BTreeMap<String, V>, new: &BTreeMap<String, V>) -> Vec<String> {
+    let mut keys: Vec<String> = old.keys().chain(new.keys()).cloned().collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+/// Keys whose value differs between `old` and `new`, with both values.
+fn changes<'a>(
+    old: &'a BTreeMap<String, String>,
+    new: &'a BTreeMap<String, String>,
+) -> Vec<(String, Option<&'a String>, Option<&'a String>)> {
+    union_keys(old, new)
+        .into_iter()
+        .filter_map(|key| {
+            let (old_value, new_value) = (old.get(&key), new.get(&key));
+            (old_value != new_value).then_some((key, old_value, new_value))
+        })
+        .collect()
+}
+
+/// A plain text report, one change per line, for reviews and terminals.
+impl fmt::Display for BundleDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digest = |digest: &Option<String>| digest.clone().unwrap_or_else(|| "(no digest)".to_string());
+        writeln!(f, "{} -> {}", digest(&self.old_digest), digest(&self.new_digest))?;
+        if self.is_empty() {
+            return writeln!(f, "No changes");
+        }
+
+        let type_name = |attribute_type: &Option<AttrType>| match attribute_type {
+            Some(attribute_type) => attribute_type.to_string(),
+            None => "(unknown type)".to_string(),
+        };
+        let overlay_name = |key: &OverlayKey| match &key.language {
+            Some(language) => format!("{} ({language})", key.overlay_type),
+            None => key.overlay_type.clone(),
+        };
+        let text = |value: &Option<String>| match value {
+            Some(value) => format!("{value:?}"),
+            None => "(none)".to_string(),
+        };
+
+        if !(self.added_attributes.is_empty() && self.removed_attributes.is_empty() && self.retyped_attributes.is_empty()) {
+            writeln!(f, "Attributes:")?;
+            for attribute in &self.added_attributes {
+                writeln!(f, "  + {}: {}", attribute.name, type_name(&attribute.attribute_type))?;
+            }
+            for attribute in &self.removed_attributes {
+                writeln!(f, "  - {}: {}", attribute.name, type_name(&attribute.attribute_type))?;
+            }
+            for retyped in &self.retyped_attributes {
+                let (old_type, new_type) = (type_name(&retyped.old_type), type_name(&retyped.new_type));
+                writeln!(f, "  ~ {}: {old_type} -> {new_type}", retyped.name)?;
+            }
+        }
+        if !(self.added_overlays.is_empty() && self.removed_overlays.is_empty() && self.changed_overlays.is_empty()) {
+            writeln!(f, "Overlays:")?;
+            for key in &self.added_overlays {
+                writeln!(f, "  + {}", overlay_name(key))?;
+            }
+            for key in &self.removed_overlays {
+                writeln!(f, "  - {}", overlay_name(key))?;
+            }
+            for key in &self.changed_overlays {
+                writeln!(f, "  ~ {}", overlay_name(key))?;
+            }
+        }
+        if !self.label_changes.is_empty() {
+            writeln!(f, "Labels:")?;
+            for change in &self.label_changes {
+                let (old, new) = (text(&change.old), text(&change.new));
+                writeln!(f, "  [{}] {}: {old} -> {new}", change.language, change.attribute)?;
+            }
+        }
+        if !self.entry_changes.is_empty() {
+            writeln!(f, "Entries:")?;
+            for change in &self.entry_changes {
+                let (old, new) = (text(&change.old), text(&change.new));
+                writeln!(f, "  [{}] {}.{}: {old} -> {new}", change.language, change.attribute, change.code)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn bundle(attributes: Value, label: &str) -> Value {
+        json!({
+            "digest": label,
+            "capture_base": { "attributes": attributes },
+            "overlays": [
+                { "type": "overlay/label/2.0.0", "language": "en", "attribute_labels": { "name": label } },
+                { "type": "overlay/meta/2.0.0", "language": "en", "name": "Person" },
+            ],
+        })
+    }
+
+    #[test]
+    fn reports_attribute_and_label_changes() {
+        let old = bundle(json!({ "name": "Text", "age": "Text", "nick": "Text" }), "Name");
+        let new = bundle(json!({ "name": "Text", "age": "Numeric", "email": "Text" }), "Full name");
+        let diff = diff(&old, &new);
+
+        assert_eq!(diff.added_attributes, [Attribute { name: "email".to_string(), attribute_type: Some(AttrType::Text) }]);
+        assert_eq!(diff.removed_attributes[0].name, "nick");
+        assert_eq!(diff.retyped_attributes[0].new_type, Some(AttrType::Numeric));
+        assert_eq!(diff.changed_overlays, [OverlayKey { overlay_type: "overlay/label/2.0.0".to_string(), language: Some("en".to_string()) }]);
+        assert_eq!(diff.label_changes[0].old.as_deref(), Some("Name"));
+        assert_eq!(diff.label_changes[0].new.as_deref(), Some("Full name"));
+        assert!(diff.to_string().contains("  ~ age: Text -> Numeric\n"));
+    }
+
+    #[test]
+    fn ignores_saids() {
+        let old = bundle(json!({ "name": "Text" }), "Name");
+        let mut new = old.clone();
+        new["digest"] = json!("EOther");
+        new["overlays"][0]["digest"] = json!("EOther");
+        let diff = diff(&old, &new);
+        assert!(diff.is_empty());
+        assert!(diff.to_string().ends_with("No changes\n"));
+    }
+}
@@ -0,0 +1,226 @@
+//! Adding, replacing and removing the overlays of a built bundle.
+//!
+//! The bundles returned are sealed again: the overlays point at the capture
+//! base, every SAID is recomputed and `v` declares the new size. Checking the
+//! overlays against an overlay registry is left to the bindings, which load
+//! the result through the SDK.
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::version::Version;
+use crate::{bundle, said};
+
+/// Selects overlays by type and language; an unset field matches any.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OverlayFilter {
+    /// The full type, e.g. `overlay/label/2.0.0`, or the name, e.g. `label`.
+    #[serde(rename = "type")]
+    pub overlay_type: Option<String>,
+    pub language: Option<String>,
+}
+
+impl OverlayFilter {
+    pub fn matches(&self, overlay: &Value) -> bool {
+        let type_matches = match &self.overlay_type {
+            Some(wanted) => {
+                overlay.get("type").and_then(Value::as_str) == Some(wanted.as_str())
+                    || bundle::overlay_name(overlay) == Some(wanted.as_str())
+            }
+            None => true,
+        };
+        let language_matches = match &self.language {
+            Some(wanted) => bundle::overlay_language(overlay) == Some(wanted.as_str()),
+            None => true,
+        };
+        type_matches && language_matches
+    }
+}
+
+/// Adds `overlay`, given as its `type`, `language` if it has one, and
+/// fields. The type may be a name such as `label` when the bundle already
+/// has an overlay of that name. Refuses an overlay whose type and language
+/// the bundle already has; replace it instead.
+pub fn add_overlay(bundle: &Value, overlay: &Value) -> Result<Value, String> {
+    let overlay = prepare(bundle, overlay)?;
+    if position(bundle, &overlay).is_some() {
+        return Err(format!("The bundle already has {}; replace it instead", describe(&overlay)));
+    }
+    let mut edited = bundle.clone();
+    overlays_mut(&mut edited)?.push(overlay);
+    seal(&mut edited)?;
+    Ok(edited)
+}
+
+/// Replaces the overlay of the same type and language as `overlay`, keeping
+/// its place among the overlays.
+pub fn replace_overlay(bundle: &Value, overlay: &Value) -> Result<Value, String> {
+    let overlay = prepare(bundle, overlay)?;
+    let index = position(bundle, &overlay).ok_or_else(|| format!("The bundle has no {}", describe(&overlay)))?;
+    let mut edited = bundle.clone();
+    overlays_mut(&mut edited)?[index] = overlay;
+    seal(&mut edited)?;
+    Ok(edited)
+}
+
+/// Removes the overlays matching `filter` and returns the bundle with how
+/// many were removed. Matching none is an error, as it usually means a
+/// misspelt type or language.
+pub fn remove_overlays(bundle: &Value, filter: &OverlayFilter) -> Result<(Value, usize), String> {
+    let mut edited = bundle.clone();
+    let overlays = overlays_mut(&mut edited)?;
+    let before = overlays.len();
+    overlays.retain(|overlay| !filter.matches(overlay));
+    let removed = before - overlays.len();
+    if removed == 0 {
+        return Err("No overlay matches the filter".to_string());
+    }
+    seal(&mut edited)?;
+    Ok((edited, removed))
+}
+
+/// Recomputes the SAIDs of the capture base, of each overlay and of the
+/// bundle, and the size in `v`.
+pub fn seal(bundle: &mut Value) -> Result<(), String> {
+    let fields = bundle.as_object_mut().ok_or("The bundle is not an object")?;
+    let capture_base = fields.get_mut("capture_base").ok_or("The bundle has no capture base")?;
+    let capture_base_digest = said::embed(capture_base)?;
+
+    if let Some(overlays) = fields.get_mut("overlays").and_then(Value::as_array_mut) {
+        for overlay in overlays {
+            let overlay_fields = overlay.as_object_mut().ok_or("An overlay is not an object")?;
+            overlay_fields.insert("capture_base".to_string(), Value::String(capture_base_digest.clone()));
+            said::embed(overlay)?;
+        }
+    }
+
+    if let Some(v) = bundle.get("v").and_then(Value::as_str) {
+        let mut version: Version = v.parse()?;
+        // The digest is about to be replaced by one of the same length, so
+        // the size can be taken now.
+        version.size = serde_json::to_vec(bundle).map_err(|e| e.to_string())?.len();
+        bundle["v"] = Value::String(version.to_string());
+    }
+    said::embed(bundle)?;
+    Ok(())
+}
+
+/// The overlay in the field order of the SDK's serialization: digest,
+/// capture base, type and language first.
+pub(crate) fn prepare(bundle: &Value, overlay: &Value) -> Result<Value, String> {
+    let fields = overlay.as_object().ok_or("The overlay is not an object")?;
+    let given_type = fields
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or("The overlay has no 'type'")?;
+    let overlay_type = if given_type.contains('/') {
+        given_type.to_string()
+    } else {
+        bundle::overlays(bundle)
+            .iter()
+            .find(|known| bundle::overlay_name(known) == Some(given_type))
+            .and_then(|known| known.get("type")?.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| format!("The bundle has no '{given_type}' overlay to take the version from; give the full type, e.g. overlay/{given_type}/2.0.0"))?
+    };
+
+    let mut prepared = Map::new();
+    prepared.insert("digest".to_string(), Value::String(String::new()));
+    prepared.insert("capture_base".to_string(), Value::String(String::new()));
+    prepared.insert("type".to_string(), Value::String(overlay_type));
+    if let Some(language) = fields.get("language") {
+        prepared.insert("language".to_string(), language.clone());
+    }
+    for (field, value) in fields {
+        if !matches!(field.as_str(), "digest" | "d" | "capture_base" | "type" | "language") {
+            prepared.insert(field.clone(), value.clone());
+        }
+    }
+    Ok(Value::Object(prepared))
+}
+
+pub(crate) fn position(bundle: &Value, overlay: &Value) -> Option<usize> {
+    let filter = OverlayFilter {
+        overlay_type: overlay.get("type").and_then(Value::as_str).map(str::to_string),
+        language: None,
+    };
+    let language = bundle::overlay_language(overlay);
+    bundle::overlays(bundle)
+        .iter()
+        .position(|known| filter.matches(known) && bundle::overlay_language(known) == language)
+}
+
+fn describe(overlay: &Value) -> String {
+    let overlay_type = overlay.get("type").and_then(Value::as_str).unwrap_or_default();
+    match bundle::overlay_language(overlay) {
+        Some(language) => format!("{overlay_type} overlay in '{language}'"),
+        None => format!("{overlay_type} overlay"),
+    }
+}
+
+pub(crate) fn overlays_mut(bundle: &mut Value) -> Result<&mut Vec<Value>, String> {
+    bundle
+        .as_object_mut()
+        .ok_or("The bundle is not an object")?
+        .entry("overlays")
+        .or_insert_with(|| Value::Array(vec![]))
+        .as_array_mut()
+        .ok_or_else(|| "The bundle's overlays are not a list".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::fixtures;
+
+    fn label(language: &str, label: &str) -> Value {
+        json!({ "type": "label", "language": language, "attribute_labels": { "fullName": label } })
+    }
+
+    #[test]
+    fn adds_an_overlay_and_seals_the_bundle() {
+        let bundle = fixtures::passport();
+        let edited = add_overlay(&bundle, &label("en", "Full name")).unwrap();
+        assert!(said::verify(&edited).valid);
+        assert_ne!(bundle::digest(&edited), bundle::digest(&bundle));
+        let added = bundle::overlays(&edited).last().unwrap();
+        assert_eq!(added["capture_base"], edited["capture_base"]["digest"]);
+        assert_eq!(added["type"], "overlay/label/2.0.0");
+
+        let check = crate::version::check(&edited).unwrap();
+        assert!(check.size_matches);
+    }
+
+    #[test]
+    fn refuses_to_add_what_it_should_replace() {
+        let bundle = fixtures::passport();
+        let err = add_overlay(&bundle, &label("fr", "Nom")).unwrap_err();
+        assert!(err.contains("replace it instead"), "{err}");
+
+        let replaced = replace_overlay(&bundle, &label("fr", "Nom")).unwrap();
+        assert_eq!(bundle::overlays(&replaced).len(), bundle::overlays(&bundle).len());
+        assert_eq!(bundle::overlays(&replaced)[4]["attribute_labels"], json!({ "fullName": "Nom" }));
+        assert!(replace_overlay(&bundle, &label("de", "Name")).is_err());
+        assert!(add_overlay(&bundle, &json!({ "type": "sensitive", "attributes": [] })).unwrap_err().contains("overlay/sensitive/2.0.0"));
+    }
+
+    #[test]
+    fn removes_overlays_matching_a_filter() {
+        let bundle = fixtures::passport();
+        let filter = OverlayFilter {
+            overlay_type: Some("meta".to_string()),
+            language: None,
+        };
+        let (edited, removed) = remove_overlays(&bundle, &filter).unwrap();
+        assert_eq!(removed, 4);
+        assert!(said::verify(&edited).valid);
+
+        let filter = OverlayFilter {
+            overlay_type: None,
+            language: Some("de".to_string()),
+        };
+        assert!(remove_overlays(&bundle, &filter).is_err());
+    }
+}
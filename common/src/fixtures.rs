@@ -0,0 +1,8 @@
+//! Bundles the unit tests share.
+
+use serde_json::Value;
+
+/// Built by the SDK from `passport.ocafile`, in the SDK's field order.
+pub(crate) fn passport() -> Value {
+    serde_json::from_str(include_str!("../../js/example/test/assets/oca_new.json")).unwrap()
+}
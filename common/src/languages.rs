@@ -0,0 +1,143 @@
+//! Narrowing a bundle to some of its languages, and moving the overlays of
+//! one language out into a translation package and back.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{additions, bundle, edit};
+
+/// The overlays of one language, without their SAIDs, for translators to
+/// edit and merge back with [`merge_translation`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Translation {
+    /// Merging writes every overlay in this language, so changing it turns
+    /// the package into a translation to another language.
+    pub language: String,
+    /// SAID of the capture base the overlays describe.
+    pub capture_base: String,
+    /// Each with its `type` and fields.
+    pub overlays: Vec<Value>,
+}
+
+/// The languages of the bundle's overlays, in order of appearance.
+pub fn languages(bundle: &Value) -> Vec<String> {
+    let mut languages: Vec<String> = vec![];
+    for language in bundle::overlays(bundle).iter().filter_map(bundle::overlay_language) {
+        if !languages.iter().any(|known| known == language) {
+            languages.push(language.to_string());
+        }
+    }
+    languages
+}
+
+/// Drops the overlays in languages other than `keep`. Overlays without a
+/// language are kept.
+pub fn keep_languages(bundle: &Value, keep: &[String]) -> Result<Value, String> {
+    let mut pruned = bundle.clone();
+    edit::overlays_mut(&mut pruned)?.retain(|overlay| match bundle::overlay_language(overlay) {
+        Some(language) => keep.iter().any(|kept| kept == language),
+        None => true,
+    });
+    edit::seal(&mut pruned)?;
+    Ok(pruned)
+}
+
+pub fn extract_translation(bundle: &Value, language: &str) -> Result<Translation, String> {
+    let capture_base = bundle
+        .get("capture_base")
+        .and_then(bundle::digest)
+        .ok_or("The bundle's capture base has no SAID")?;
+    let overlays: Vec<Value> = bundle::overlays(bundle)
+        .iter()
+        .filter(|overlay| bundle::overlay_language(overlay) == Some(language))
+        .map(|overlay| {
+            let mut fields = additions::content(overlay);
+            fields.retain(|field, _| field != "language");
+            Value::Object(fields)
+        })
+        .collect();
+    if overlays.is_empty() {
+        return Err(format!(
+            "The bundle has no overlays in '{language}'; it has {}",
+            languages(bundle).join(", ")
+        ));
+    }
+
+    Ok(Translation {
+        language: language.to_string(),
+        capture_base: capture_base.to_string(),
+        overlays,
+    })
+}
+
+/// Writes the overlays of `translation` into the bundle, replacing those of
+/// the same type in its language, and recomputes the SAIDs.
+pub fn merge_translation(bundle: &Value, translation: &Translation) -> Result<Value, String> {
+    let capture_base = bundle.get("capture_base").and_then(bundle::digest);
+    if capture_base != Some(translation.capture_base.as_str()) {
+        return Err(format!(
+            "The translation belongs to capture base {}, not {}",
+            translation.capture_base,
+            capture_base.unwrap_or("(none)"),
+        ));
+    }
+
+    let mut merged = bundle.clone();
+    for overlay in &translation.overlays {
+        let mut overlay = overlay.clone();
+        overlay
+            .as_object_mut()
+            .ok_or("A translated overlay is not an object")?
+            .insert("language".to_string(), Value::String(translation.language.clone()));
+        let overlay = edit::prepare(&merged, &overlay)?;
+        match edit::position(&merged, &overlay) {
+            Some(index) => edit::overlays_mut(&mut merged)?[index] = overlay,
+            None => edit::overlays_mut(&mut merged)?.push(overlay),
+        }
+    }
+    edit::seal(&mut merged)?;
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, said};
+
+    #[test]
+    fn lists_and_keeps_languages() {
+        let bundle = fixtures::passport();
+        assert_eq!(languages(&bundle), ["en", "epo", "fr", "pl"]);
+
+        let pruned = keep_languages(&bundle, &["en".to_string()]).unwrap();
+        assert_eq!(languages(&pruned), ["en"]);
+        // Overlays without a language stay.
+        assert_eq!(bundle::overlays(&pruned).len(), 5);
+        assert!(said::verify(&pruned).valid);
+    }
+
+    #[test]
+    fn merging_an_extracted_translation_restores_the_bundle() {
+        let bundle = fixtures::passport();
+        let translation = extract_translation(&bundle, "fr").unwrap();
+        assert_eq!(translation.overlays.len(), 3);
+        assert!(translation.overlays.iter().all(|overlay| overlay.get("language").is_none() && overlay.get("digest").is_none()));
+        let mut sealed = bundle.clone();
+        edit::seal(&mut sealed).unwrap();
+        assert_eq!(merge_translation(&bundle, &translation).unwrap(), sealed);
+    }
+
+    #[test]
+    fn merges_a_translation_into_another_language() {
+        let bundle = fixtures::passport();
+        let mut translation = extract_translation(&bundle, "fr").unwrap();
+        translation.language = "de".to_string();
+        let merged = merge_translation(&bundle, &translation).unwrap();
+        assert_eq!(languages(&merged), ["en", "epo", "fr", "pl", "de"]);
+        assert!(said::verify(&merged).valid);
+
+        translation.capture_base = "EOther".to_string();
+        assert!(merge_translation(&bundle, &translation).is_err());
+        assert!(extract_translation(&bundle, "de").unwrap_err().contains("en, epo, fr, pl"));
+    }
+}
@@ -0,0 +1,210 @@
+//! Reading bundles of the 1.x format, as written by `oca-bundle` 0.3: SAIDs
+//! in `d`, a `spec/capture_base/1.0` capture base with `flagged_attributes`,
+//! and overlays keyed by name, those with a language as lists.
+//!
+//! Such a bundle is upgraded by writing its content as an OCAfile, which the
+//! 2.0 SDK then builds. The SAIDs change with the format, so references to
+//! the old bundle by SAID need updating too.
+
+use serde_json::{Map, Value};
+
+use crate::builder::{BundleBuilder, Rendered};
+use crate::bundle;
+use crate::types::AttrType;
+use crate::version::Version;
+
+#[derive(Debug, Clone)]
+pub struct Upgrade {
+    pub rendered: Rendered,
+    /// What the 2.0 format has no place for, e.g. layout overlays.
+    pub skipped: Vec<String>,
+}
+
+/// Whether the bundle is in the 1.x format, judged by its version string
+/// when it has one, else by the shape of its capture base and overlays.
+pub fn is_legacy(bundle: &Value) -> bool {
+    if let Some(version) = bundle.get("v").and_then(Value::as_str).and_then(|v| v.parse::<Version>().ok()) {
+        return version.major == 1;
+    }
+    let capture_base_type = bundle::capture_base(bundle)
+        .and_then(|cb| cb.get("type"))
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    capture_base_type.starts_with("spec/capture_base/1") || bundle.get("overlays").is_some_and(Value::is_object)
+}
+
+/// Writes the content of a 1.x bundle as an OCAfile.
+pub fn upgrade(bundle: &Value) -> Result<Upgrade, String> {
+    let capture_base = bundle::capture_base(bundle).ok_or("The bundle has no capture base")?;
+    let mut builder = BundleBuilder::new();
+    let mut skipped = vec![];
+
+    let attributes = capture_base
+        .get("attributes")
+        .and_then(Value::as_object)
+        .ok_or("The capture base has no attributes")?;
+    for (name, attribute_type) in attributes {
+        let attribute_type = AttrType::from_json(attribute_type)
+            .ok_or_else(|| format!("Unknown type {attribute_type} of attribute '{name}'"))?;
+        builder.add_attribute(name, attribute_type)?;
+    }
+    for name in capture_base
+        .get("flagged_attributes")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+    {
+        builder.sensitive(name)?;
+    }
+
+    let names: Vec<&str> = attributes.keys().map(String::as_str).collect();
+    for overlay in overlays(bundle) {
+        upgrade_overlay(&mut builder, &names, overlay, &mut skipped)?;
+    }
+
+    Ok(Upgrade {
+        rendered: builder.render()?,
+        skipped,
+    })
+}
+
+/// The overlays of a 1.x bundle, whether keyed by name or listed.
+fn overlays(bundle: &Value) -> Vec<&Value> {
+    match bundle.get("overlays") {
+        Some(Value::Object(by_name)) => by_name
+            .values()
+            .flat_map(|overlays| match overlays {
+                Value::Array(overlays) => overlays.iter().collect(),
+                overlay => vec![overlay],
+            })
+            .collect(),
+        Some(Value::Array(overlays)) => overlays.iter().collect(),
+        _ => vec![],
+    }
+}
+
+fn upgrade_overlay(
+    builder: &mut BundleBuilder,
+    attributes: &[&str],
+    overlay: &Value,
+    skipped: &mut Vec<String>,
+) -> Result<(), String> {
+    let name = bundle::overlay_name(overlay).unwrap_or_default();
+    let language = bundle::overlay_language(overlay);
+    let fields = overlay.as_object().cloned().unwrap_or_default();
+    let per_attribute = |field: &str| -> Map<String, Value> {
+        fields.get(field).and_then(Value::as_object).cloned().unwrap_or_default()
+    };
+    let text = |value: &Value| value.as_str().map(str::to_string).unwrap_or_else(|| value.to_string());
+    let in_language = |what: &str| language.ok_or_else(|| format!("The {what} overlay has no language"));
+
+    match name {
+        "label" => {
+            let language = in_language("label")?;
+            for (attribute, label) in per_attribute("attribute_labels") {
+                builder.label(language, &attribute, &text(&label))?;
+            }
+            if fields.get("category_labels").and_then(Value::as_object).is_some_and(|c| !c.is_empty()) {
+                skipped.push(format!("category labels of the label overlay in '{language}'"));
+            }
+        }
+        "information" => {
+            let language = in_language("information")?;
+            for (attribute, information) in per_attribute("attribute_information") {
+                builder.information(language, &attribute, &text(&information))?;
+            }
+        }
+        "entry" => {
+            let language = in_language("entry")?;
+            for (attribute, entries) in per_attribute("attribute_entries") {
+                match entries {
+                    Value::Object(entries) => {
+                        let entries = entries.iter().map(|(code, label)| (code.clone(), text(label))).collect();
+                        builder.entries(language, &attribute, entries)?;
+                    }
+                    entries => skipped.push(format!("entries of '{attribute}' in '{language}' by reference {entries}")),
+                }
+            }
+        }
+        "entry_code" => {
+            for (attribute, codes) in per_attribute("attribute_entry_codes") {
+                match codes {
+                    Value::Array(codes) => builder.entry_codes(&attribute, codes.iter().map(text).collect())?,
+                    codes => skipped.push(format!("entry codes of '{attribute}' by reference {codes}")),
+                }
+            }
+        }
+        "conformance" => {
+            for (attribute, conformance) in per_attribute("attribute_conformance") {
+                builder.conformance(&attribute, &text(&conformance))?;
+            }
+        }
+        "format" => {
+            for (attribute, format) in per_attribute("attribute_formats") {
+                builder.format(&attribute, &text(&format))?;
+            }
+        }
+        "cardinality" => {
+            for (attribute, cardinality) in per_attribute("attribute_cardinality") {
+                builder.cardinality(&attribute, &text(&cardinality))?;
+            }
+        }
+        "unit" => {
+            for (attribute, unit) in per_attribute("attribute_units") {
+                builder.unit(&attribute, &text(&unit))?;
+            }
+        }
+        "standard" => {
+            for (attribute, standard) in per_attribute("attribute_standards") {
+                builder.standard(&attribute, &text(&standard))?;
+            }
+        }
+        "character_encoding" => {
+            // 2.0 has no default encoding, so it is spelled out for every
+            // attribute without one of its own.
+            let encodings = per_attribute("attribute_character_encoding");
+            let default = fields.get("default_character_encoding").filter(|d| !d.is_null());
+            for attribute in attributes {
+                if let Some(encoding) = encodings.get(*attribute).or(default) {
+                    builder.character_encoding(attribute, &text(encoding))?;
+                }
+            }
+        }
+        "meta" => {
+            let language = in_language("meta")?;
+            let mut meta = fields;
+            meta.retain(|field, _| !matches!(field.as_str(), "d" | "digest" | "capture_base" | "type" | "language"));
+            builder.meta(language, meta)?;
+        }
+        name => skipped.push(match language {
+            Some(language) => format!("{name} overlay in '{language}'"),
+            None => format!("{name} overlay"),
+        }),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn tells_legacy_bundles_by_version_string() {
+        assert!(is_legacy(&json!({ "v": "OCAS10JSON000646_" })));
+        assert!(is_legacy(&json!({ "v": "OCAS11JSON0009a0_" })));
+        assert!(!is_legacy(&json!({ "v": "OCAS02JSON0012ce_" })));
+    }
+
+    #[test]
+    fn tells_legacy_bundles_by_shape() {
+        let legacy = json!({
+            "capture_base": { "type": "spec/capture_base/1.0", "attributes": {} },
+            "overlays": {},
+        });
+        assert!(is_legacy(&legacy));
+        assert!(!is_legacy(&json!({ "capture_base": { "attributes": {} }, "overlays": [] })));
+    }
+}
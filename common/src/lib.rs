@@ -0,0 +1,21 @@
+//! Logic shared by the OCA language bindings that only needs the JSON form
+//! of a bundle, so it can be written once instead of per binding.
+
+pub mod additions;
+pub mod attributes;
+pub mod build;
+pub mod builder;
+pub mod bundle;
+pub mod compat;
+pub mod data;
+pub mod diff;
+pub mod edit;
+#[cfg(test)]
+mod fixtures;
+pub mod languages;
+pub mod legacy;
+pub mod resolve;
+pub mod said;
+pub mod semantics;
+pub mod types;
+pub mod version;
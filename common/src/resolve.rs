@@ -0,0 +1,288 @@
+//! Following `refs:` and `refn:` attribute types to the bundles they point
+//! at.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use crate::types::AttrType;
+
+/// How deep references are followed before giving up.
+pub const MAX_DEPTH: usize = 16;
+
+/// A reference to another bundle, by SAID or by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reference {
+    Said(String),
+    Name(String),
+}
+
+impl Reference {
+    /// The reference an attribute type ends in, looking through arrays.
+    pub fn of(attr_type: &AttrType) -> Option<Reference> {
+        match attr_type {
+            AttrType::RefSaid(said) => Some(Reference::Said(said.clone())),
+            AttrType::RefName(name) => Some(Reference::Name(name.clone())),
+            AttrType::Array(items) => Reference::of(items),
+            _ => None,
+        }
+    }
+}
+
+impl From<Reference> for AttrType {
+    fn from(reference: Reference) -> AttrType {
+        match reference {
+            Reference::Said(said) => AttrType::RefSaid(said),
+            Reference::Name(name) => AttrType::RefName(name),
+        }
+    }
+}
+
+impl fmt::Display for Reference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&AttrType::from(self.clone()), f)
+    }
+}
+
+/// Maps a reference to the JSON form of the bundle it points at.
+pub trait BundleResolver {
+    /// `Ok(None)` when the reference is unknown to this resolver.
+    fn resolve(&self, reference: &Reference) -> Result<Option<Value>, String>;
+}
+
+/// Resolves references against a directory of `<said>.json` files. Names
+/// are looked up as `<name>.json`, so a name can be given to a bundle by
+/// copying or linking its file. A bundle found by SAID must have that
+/// digest.
+pub struct DirResolver {
+    dir: PathBuf,
+}
+
+impl DirResolver {
+    pub fn new(dir: impl Into<PathBuf>) -> Result<DirResolver, String> {
+        let dir = dir.into();
+        if !dir.is_dir() {
+            return Err(format!("'{}' is not a directory", dir.display()));
+        }
+        Ok(DirResolver { dir })
+    }
+}
+
+impl BundleResolver for DirResolver {
+    fn resolve(&self, reference: &Reference) -> Result<Option<Value>, String> {
+        let (Reference::Said(key) | Reference::Name(key)) = reference;
+        if key.is_empty() || key.contains(['/', '\\']) || key.starts_with('.') {
+            return Err(format!("Cannot look up {reference} in a directory"));
+        }
+        let path = self.dir.join(format!("{key}.json"));
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("Failed to read '{}': {e}", path.display())),
+        };
+        let bundle: Value =
+            serde_json::from_str(&text).map_err(|e| format!("Invalid bundle in '{}': {e}", path.display()))?;
+        if let Reference::Said(said) = reference {
+            let digest = crate::bundle::digest(&bundle);
+            if digest != Some(said.as_str()) {
+                return Err(format!(
+                    "Bundle in '{}' has digest '{}', not {reference}",
+                    path.display(),
+                    digest.unwrap_or_default()
+                ));
+            }
+        }
+        Ok(Some(bundle))
+    }
+}
+
+/// Tracks the references being followed. Walking a schema stops at cycles;
+/// walking data may revisit a bundle, since the data itself is finite, and
+/// only [`MAX_DEPTH`] applies.
+pub(crate) struct Trail {
+    references: Vec<Reference>,
+    allow_cycles: bool,
+}
+
+impl Trail {
+    /// A trail for walking the schema of `bundle`, which counts as visited.
+    pub(crate) fn for_schema(bundle: &Value) -> Trail {
+        Trail {
+            references: crate::bundle::digest(bundle)
+                .map(|said| Reference::Said(said.to_string()))
+                .into_iter()
+                .collect(),
+            allow_cycles: false,
+        }
+    }
+
+    pub(crate) fn for_data() -> Trail {
+        Trail {
+            references: vec![],
+            allow_cycles: true,
+        }
+    }
+
+    /// Resolves `reference` unless that would loop or exceed [`MAX_DEPTH`].
+    pub(crate) fn follow(&self, resolver: &dyn BundleResolver, reference: &Reference) -> Result<Value, String> {
+        if !self.allow_cycles && self.references.contains(reference) {
+            return Err(format!("Reference cycle through {reference}"));
+        }
+        if self.references.len() >= MAX_DEPTH {
+            return Err(format!("References nested deeper than {MAX_DEPTH} at {reference}"));
+        }
+        resolver
+            .resolve(reference)?
+            .ok_or_else(|| format!("Referenced bundle {reference} not found"))
+    }
+
+    pub(crate) fn push(&mut self, reference: Reference) {
+        self.references.push(reference);
+    }
+
+    pub(crate) fn pop(&mut self) {
+        self.references.pop();
+    }
+}
+
+/// Replaces `refn:<name>` types in an OCAfile with `refs:<said>`, using the
+/// digest of the bundle each name resolves to. Quoted text is left alone.
+pub fn substitute_names(ocafile: &str, resolver: &dyn BundleResolver) -> Result<String, Vec<String>> {
+    let mut errors = vec![];
+    let output = rewrite_names(ocafile, |name| match resolve_name(resolver, name) {
+        Ok(said) => Some(format!("refs:{said}")),
+        Err(e) => {
+            errors.push(e);
+            None
+        }
+    });
+
+    if errors.is_empty() {
+        Ok(output)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Names referenced by `refn:` types of an OCAfile, in order of appearance.
+pub fn referenced_names(ocafile: &str) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    rewrite_names(ocafile, |name| {
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+        None
+    });
+    names
+}
+
+/// Calls `replace` for every `refn:<name>` outside quoted text and puts its
+/// result, if any, in place of the reference.
+fn rewrite_names(ocafile: &str, mut replace: impl FnMut(&str) -> Option<String>) -> String {
+    let mut output = String::with_capacity(ocafile.len());
+    let mut rest = ocafile;
+    let mut quoted = false;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '"' {
+            quoted = !quoted;
+        } else if c == '\n' {
+            quoted = false;
+        } else if !quoted && rest.starts_with("refn:") && !output.ends_with(is_name_char) {
+            let name_len = rest[5..].find(|c: char| !is_name_char(c)).unwrap_or(rest.len() - 5);
+            let reference = &rest[..5 + name_len];
+            match replace(&reference[5..]) {
+                Some(replacement) => output.push_str(&replacement),
+                None => output.push_str(reference),
+            }
+            rest = &rest[reference.len()..];
+            continue;
+        }
+        output.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    output
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+fn resolve_name(resolver: &dyn BundleResolver, name: &str) -> Result<String, String> {
+    let reference = Reference::Name(name.to_string());
+    let bundle = resolver
+        .resolve(&reference)?
+        .ok_or_else(|| format!("Referenced bundle {reference} not found"))?;
+    crate::bundle::digest(&bundle)
+        .map(str::to_string)
+        .ok_or_else(|| format!("Referenced bundle {reference} has no digest"))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    struct Names;
+
+    impl BundleResolver for Names {
+        fn resolve(&self, reference: &Reference) -> Result<Option<Value>, String> {
+            Ok(match reference {
+                Reference::Name(name) if name == "address" => Some(json!({ "digest": "EAddress" })),
+                Reference::Name(name) if name == "undigested" => Some(json!({})),
+                _ => None,
+            })
+        }
+    }
+
+    #[test]
+    fn substitutes_names_outside_quoted_text() {
+        let ocafile = "ADD ATTRIBUTE home=refn:address work=Array[refn:address]\n  name=\"see refn:address\"\n";
+        assert_eq!(
+            substitute_names(ocafile, &Names).unwrap(),
+            "ADD ATTRIBUTE home=refs:EAddress work=Array[refs:EAddress]\n  name=\"see refn:address\"\n"
+        );
+        assert_eq!(referenced_names(ocafile), ["address"]);
+        assert_eq!(referenced_names("x=myrefn:address"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn collects_every_unresolved_name() {
+        let errors = substitute_names("a=refn:nowhere b=refn:undigested", &Names).unwrap_err();
+        assert_eq!(errors, ["Referenced bundle refn:nowhere not found", "Referenced bundle refn:undigested has no digest"]);
+    }
+
+    #[test]
+    fn keeps_directory_lookups_inside_the_directory() {
+        let resolver = DirResolver::new(std::env::temp_dir()).unwrap();
+        for key in ["../etc", "a/b", ".hidden", ""] {
+            assert!(resolver.resolve(&Reference::Name(key.to_string())).is_err(), "{key}");
+        }
+        assert!(DirResolver::new(std::env::temp_dir().join("no-such-oca-store")).is_err());
+    }
+
+    #[test]
+    fn checks_the_digest_of_bundles_found_by_said() {
+        let dir = std::env::temp_dir().join(format!("oca-dir-resolver-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("EAddress.json"), r#"{ "digest": "EAddress" }"#).unwrap();
+        std::fs::write(dir.join("EForged.json"), r#"{ "digest": "EAddress" }"#).unwrap();
+        let resolver = DirResolver::new(&dir).unwrap();
+
+        let said = |key: &str| resolver.resolve(&Reference::Said(key.to_string()));
+        assert_eq!(said("EAddress").unwrap(), Some(json!({ "digest": "EAddress" })));
+        assert!(said("EForged").unwrap_err().contains("has digest 'EAddress', not refs:EForged"));
+        assert!(resolver.resolve(&Reference::Name("EForged".to_string())).unwrap().is_some());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn stops_at_schema_cycles() {
+        let bundle = json!({ "digest": "EAddress" });
+        let trail = Trail::for_schema(&bundle);
+        assert!(trail.follow(&Names, &Reference::Said("EAddress".to_string())).unwrap_err().contains("cycle"));
+        assert!(Trail::for_data().follow(&Names, &Reference::Name("address".to_string())).is_ok());
+    }
+}
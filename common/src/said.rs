@@ -0,0 +1,187 @@
+//! Recomputing the self-addressing identifiers (SAIDs) embedded in a bundle.
+//!
+//! A SAID is the digest of an object's compact JSON with the digest field
+//! itself replaced by `#` placeholders, encoded as CESR. The bundle digest
+//! covers the capture base and overlays with their own digests filled in.
+
+use std::str::FromStr;
+
+use said::derivation::{HashFunction, HashFunctionCode};
+use said::SelfAddressingIdentifier;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::bundle;
+
+/// The field OCA 2.0 objects keep their SAID in.
+const DIGEST_FIELD: &str = "digest";
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ObjectVerification {
+    /// `capture_base`, `overlays[<index>]` or `bundle`.
+    pub path: String,
+    #[serde(rename = "type")]
+    pub object_type: Option<String>,
+    pub language: Option<String>,
+    /// The SAID found in the object.
+    pub embedded: Option<String>,
+    /// The SAID recomputed from the object's content.
+    pub computed: Option<String>,
+    pub valid: bool,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BundleVerification {
+    pub valid: bool,
+    /// The capture base, each overlay and the bundle itself, in that order.
+    pub objects: Vec<ObjectVerification>,
+}
+
+/// Recomputes every SAID of `bundle` and compares it with the embedded one.
+/// Overlays must also point at the capture base they are part of.
+pub fn verify(bundle: &Value) -> BundleVerification {
+    let mut objects = vec![];
+
+    let capture_base = bundle.get("capture_base").unwrap_or(&Value::Null);
+    let capture_base_check = check("capture_base", capture_base);
+    let capture_base_digest = capture_base_check.embedded.clone();
+    objects.push(capture_base_check);
+
+    for (index, overlay) in bundle::overlays(bundle).iter().enumerate() {
+        let mut overlay_check = check(&format!("overlays[{index}]"), overlay);
+        let target = overlay.get("capture_base").and_then(Value::as_str);
+        if overlay_check.valid && target != capture_base_digest.as_deref() {
+            overlay_check.valid = false;
+            overlay_check.message = Some(format!(
+                "Overlay belongs to capture base {}, not {}",
+                target.unwrap_or("(none)"),
+                capture_base_digest.as_deref().unwrap_or("(none)"),
+            ));
+        }
+        objects.push(overlay_check);
+    }
+
+    objects.push(check("bundle", bundle));
+
+    BundleVerification {
+        valid: objects.iter().all(|object| object.valid),
+        objects,
+    }
+}
+
+/// Computes the SAID of `object`, whatever its digest field holds now.
+///
+/// Fields are hashed in the order `object` holds them, which is the order
+/// they were parsed in when `serde_json`'s `preserve_order` is on. The SDK
+/// turns it on, so it is on in every binding.
+pub fn compute(object: &Value) -> Result<String, String> {
+    derive(&HashFunction::from(HashFunctionCode::Blake3_256), object).map(|said| said.to_string())
+}
+
+/// Computes the SAID of `object` and writes it into its digest field.
+pub fn embed(object: &mut Value) -> Result<String, String> {
+    let said = compute(object)?;
+    let fields = object.as_object_mut().ok_or("Only objects have a SAID")?;
+    fields.insert(DIGEST_FIELD.to_string(), Value::String(said.clone()));
+    Ok(said)
+}
+
+fn derive(hash: &HashFunction, object: &Value) -> Result<SelfAddressingIdentifier, String> {
+    let mut object = object.clone();
+    let fields = object.as_object_mut().ok_or("Only objects have a SAID")?;
+    let placeholder = "#".repeat(hash.derive(&[]).to_string().len());
+    fields.insert(DIGEST_FIELD.to_string(), Value::String(placeholder));
+    let serialized = serde_json::to_vec(&object).map_err(|e| e.to_string())?;
+    Ok(hash.derive(&serialized))
+}
+
+fn check(path: &str, object: &Value) -> ObjectVerification {
+    let embedded = object.get(DIGEST_FIELD).and_then(Value::as_str).map(str::to_string);
+    let (computed, message) = match &embedded {
+        None => match compute(object) {
+            Ok(computed) => (Some(computed), Some("No SAID embedded".to_string())),
+            Err(e) => (None, Some(e)),
+        },
+        Some(embedded) => match SelfAddressingIdentifier::from_str(embedded) {
+            Err(e) => (None, Some(format!("Embedded digest {embedded} is not a SAID: {e}"))),
+            Ok(said) => match derive(&said.derivation, object) {
+                Err(e) => (None, Some(e)),
+                Ok(computed) if computed != said => {
+                    let message = format!("Embedded SAID {embedded} does not match the content, which hashes to {computed}");
+                    (Some(computed.to_string()), Some(message))
+                }
+                Ok(computed) => (Some(computed.to_string()), None),
+            },
+        },
+    };
+
+    ObjectVerification {
+        path: path.to_string(),
+        object_type: object.get("type").and_then(Value::as_str).map(str::to_string),
+        language: object.get("language").and_then(Value::as_str).map(str::to_string),
+        valid: message.is_none(),
+        embedded,
+        computed,
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sealed_bundle() -> Value {
+        let mut bundle = json!({
+            "v": "OCAS02JSON000000_",
+            "digest": "",
+            "capture_base": { "type": "capture_base/2.0.0", "digest": "", "attributes": { "name": "Text" } },
+            "overlays": [
+                { "type": "overlay/label/2.0.0", "digest": "", "capture_base": "", "language": "en", "attribute_labels": { "name": "Name" } },
+            ],
+        });
+        let capture_base = embed(&mut bundle["capture_base"]).unwrap();
+        bundle["overlays"][0]["capture_base"] = capture_base.into();
+        embed(&mut bundle["overlays"][0]).unwrap();
+        embed(&mut bundle).unwrap();
+        bundle
+    }
+
+    #[test]
+    fn verifies_what_it_embeds() {
+        let verification = verify(&sealed_bundle());
+        assert!(verification.valid, "{:#?}", verification.objects);
+        let paths: Vec<_> = verification.objects.iter().map(|o| o.path.as_str()).collect();
+        assert_eq!(paths, ["capture_base", "overlays[0]", "bundle"]);
+    }
+
+    #[test]
+    fn reports_tampered_content() {
+        let mut bundle = sealed_bundle();
+        bundle["overlays"][0]["attribute_labels"]["name"] = "Changed".into();
+        let verification = verify(&bundle);
+        assert!(!verification.valid);
+        let overlay = &verification.objects[1];
+        assert!(!overlay.valid);
+        assert!(overlay.message.as_deref().unwrap().contains("does not match the content"));
+    }
+
+    #[test]
+    fn reports_digests_that_are_not_saids() {
+        let mut bundle = sealed_bundle();
+        bundle["capture_base"]["digest"] = "not a said".into();
+        let capture_base = &verify(&bundle).objects[0];
+        assert!(!capture_base.valid);
+        assert!(capture_base.message.as_deref().unwrap().contains("is not a SAID"));
+    }
+
+    #[test]
+    fn computes_cesr_blake3_digests() {
+        let said = compute(&json!({ "digest": "", "a": 1 })).unwrap();
+        assert_eq!(said.parse::<SelfAddressingIdentifier>().unwrap().derivation, HashFunctionCode::Blake3_256.into());
+        assert_eq!(said, compute(&json!({ "digest": said.clone(), "a": 1 })).unwrap());
+        assert_ne!(said, compute(&json!({ "digest": "", "a": 2 })).unwrap());
+        assert!(compute(&json!([])).is_err());
+    }
+}
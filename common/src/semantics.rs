@@ -0,0 +1,240 @@
+//! Structural checks of a bundle that can name the overlay and attribute
+//! they concern, used to give semantic validation errors a category. They
+//! cover what the SDK validator does not check; every binding reports them
+//! along with the SDK's own findings.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::bundle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    /// The bundle could not be read at all.
+    Format,
+    /// An overlay points at a different capture base.
+    CaptureBaseMismatch,
+    /// An overlay mentions an attribute the capture base does not declare.
+    UnknownAttribute,
+    /// An entry overlay translates a code the entry code overlay lacks.
+    EntryCodeMismatch,
+    /// Reported by the SDK validator without further structure.
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SemanticIssue {
+    pub category: Category,
+    pub overlay: Option<String>,
+    pub attribute: Option<String>,
+    pub message: String,
+}
+
+impl SemanticIssue {
+    pub fn new(category: Category, message: impl Into<String>) -> SemanticIssue {
+        SemanticIssue {
+            category,
+            overlay: None,
+            attribute: None,
+            message: message.into(),
+        }
+    }
+}
+
+/// Adds the SDK's messages to the structural issues as `Other`, annotated
+/// with the overlay and attribute they quote, if any. The structural checks
+/// leave what the SDK checks, such as duplicate overlays, to it.
+pub fn with_sdk_errors(bundle: &Value, mut issues: Vec<SemanticIssue>, sdk_errors: Vec<String>) -> Vec<SemanticIssue> {
+    let attributes: Vec<&str> = bundle::attributes(bundle).into_iter().map(|(name, _)| name).collect();
+    let overlays: Vec<&str> = bundle::overlays(bundle).iter().filter_map(bundle::overlay_name).collect();
+    let mentioned = |message: &str, names: &[&str]| {
+        names
+            .iter()
+            .find(|name| message.contains(&format!("'{name}'")) || message.contains(&format!("\"{name}\"")))
+            .map(|name| name.to_string())
+    };
+
+    let sdk_issues: Vec<SemanticIssue> = sdk_errors
+        .into_iter()
+        .map(|message| SemanticIssue {
+            category: Category::Other,
+            overlay: mentioned(&message, &overlays),
+            attribute: mentioned(&message, &attributes),
+            message,
+        })
+        .collect();
+    issues.extend(sdk_issues);
+    issues
+}
+
+/// Checks overlay references, attribute names and entry codes against the
+/// bundle's capture base.
+pub fn check_bundle(bundle: &Value) -> Vec<SemanticIssue> {
+    let mut issues = vec![];
+    let attributes: Vec<&str> = bundle::attributes(bundle).into_iter().map(|(name, _)| name).collect();
+    let capture_base_digest = bundle::capture_base(bundle)
+        .and_then(|cb| cb.get("digest").or_else(|| cb.get("d")))
+        .and_then(Value::as_str);
+
+    for overlay in bundle::overlays(bundle) {
+        let Some(name) = bundle::overlay_name(overlay) else {
+            issues.push(SemanticIssue::new(Category::Format, "Overlay without a type"));
+            continue;
+        };
+        let issue = |category, attribute: Option<&str>, message: String| SemanticIssue {
+            category,
+            overlay: Some(name.to_string()),
+            attribute: attribute.map(str::to_string),
+            message,
+        };
+
+        let overlay_capture_base = overlay.get("capture_base").and_then(Value::as_str);
+        if let (Some(expected), Some(actual)) = (capture_base_digest, overlay_capture_base) {
+            if expected != actual {
+                issues.push(issue(
+                    Category::CaptureBaseMismatch,
+                    None,
+                    format!("Overlay '{name}' refers to capture base {actual}, expected {expected}"),
+                ));
+            }
+        }
+
+        for attribute in overlay_attribute_names(overlay) {
+            if !attributes.contains(&attribute) {
+                issues.push(issue(
+                    Category::UnknownAttribute,
+                    Some(attribute),
+                    format!("Overlay '{name}' refers to unknown attribute '{attribute}'"),
+                ));
+            }
+        }
+
+        if name == "entry" {
+            check_entries(bundle, overlay, &mut issues);
+        }
+    }
+
+    issues
+}
+
+/// Fields of the standard overlays that are keyed by attribute name.
+pub const ATTRIBUTE_FIELDS: [&str; 11] = [
+    "attribute_labels",
+    "attribute_information",
+    "attribute_character_encodings",
+    "attribute_conformances",
+    "attribute_formats",
+    "attribute_units",
+    "attribute_cardinalities",
+    "attribute_entry_codes",
+    "attribute_entries",
+    "attribute_standards",
+    "attribute_mappings",
+];
+
+/// Attribute names an overlay refers to: the keys of its attribute-keyed
+/// objects and the items of its `attributes` list.
+pub fn overlay_attribute_names(overlay: &Value) -> Vec<&str> {
+    let Some(fields) = bundle::overlay_fields(overlay) else {
+        return vec![];
+    };
+    let mut names = vec![];
+    for (field, value) in fields {
+        match value {
+            Value::Object(map) if ATTRIBUTE_FIELDS.contains(&field.as_str()) => {
+                names.extend(map.keys().map(String::as_str));
+            }
+            Value::Array(items) if field == "attributes" => {
+                names.extend(items.iter().filter_map(Value::as_str));
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
+fn check_entries(bundle: &Value, overlay: &Value, issues: &mut Vec<SemanticIssue>) {
+    let Some(entries) = bundle::overlay_fields(overlay)
+        .and_then(|fields| fields.get("attribute_entries"))
+        .and_then(Value::as_object)
+    else {
+        return;
+    };
+    for (attribute, translations) in entries {
+        let Some(codes) = bundle::attribute_value(bundle, "entry_code", "attribute_entry_codes", attribute)
+            .and_then(Value::as_array)
+        else {
+            continue;
+        };
+        let Some(translations) = translations.as_object() else {
+            continue;
+        };
+        for code in translations.keys() {
+            if !codes.iter().any(|c| c.as_str() == Some(code)) {
+                issues.push(SemanticIssue {
+                    category: Category::EntryCodeMismatch,
+                    overlay: Some("entry".to_string()),
+                    attribute: Some(attribute.clone()),
+                    message: format!("Entry '{code}' of attribute '{attribute}' is not one of its entry codes"),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn bundle() -> Value {
+        json!({
+            "capture_base": { "digest": "ECapture", "attributes": { "sex": "Text", "name": "Text" } },
+            "overlays": [
+                {
+                    "type": "overlay/label/2.0.0", "capture_base": "ECapture", "language": "en",
+                    "attribute_labels": { "name": "Name", "age": "Age" },
+                },
+                { "type": "overlay/label/2.0.0", "capture_base": "EOther", "language": "en", "attribute_labels": { "name": "Name" } },
+                { "type": "overlay/entry_code/2.0.0", "capture_base": "ECapture", "attribute_entry_codes": { "sex": ["F", "M"] } },
+                {
+                    "type": "overlay/entry/2.0.0", "capture_base": "ECapture", "language": "en",
+                    "attribute_entries": { "sex": { "F": "Female", "X": "Other" } },
+                },
+                { "capture_base": "ECapture" },
+            ],
+        })
+    }
+
+    #[test]
+    fn categorizes_structural_issues() {
+        let issues = check_bundle(&bundle());
+        let found: Vec<(Category, Option<&str>)> = issues.iter().map(|i| (i.category, i.attribute.as_deref())).collect();
+        assert_eq!(
+            found,
+            [
+                (Category::UnknownAttribute, Some("age")),
+                (Category::CaptureBaseMismatch, None),
+                (Category::EntryCodeMismatch, Some("sex")),
+                (Category::Format, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn adds_every_sdk_error() {
+        let bundle = bundle();
+        let sdk_errors = vec![
+            "Attribute 'name' of overlay \"label\" is odd".to_string(),
+            "Page 'sexy' is odd".to_string(),
+        ];
+        let issues = with_sdk_errors(&bundle, check_bundle(&bundle), sdk_errors);
+        let others: Vec<&SemanticIssue> = issues.iter().filter(|i| i.category == Category::Other).collect();
+        assert_eq!(others.len(), 2);
+        assert_eq!(others[0].attribute.as_deref(), Some("name"));
+        assert_eq!(others[0].overlay.as_deref(), Some("label"));
+        assert_eq!((others[1].overlay.as_deref(), others[1].attribute.as_deref()), (None, None));
+    }
+}
@@ -0,0 +1,195 @@
+//! Attribute types as they appear in a capture base.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Serializes as a type tree (see [`TypeTree`]) rather than the lossy
+/// OCAfile spelling, so that `Null` and references stay distinguishable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "TypeTree", try_from = "TypeTree")]
+pub enum AttrType {
+    Text,
+    Numeric,
+    Boolean,
+    DateTime,
+    Binary,
+    RefSaid(String),
+    RefName(String),
+    Array(Box<AttrType>),
+    Null,
+}
+
+impl AttrType {
+    /// Parses a capture base type. Arrays may be serialized either as a
+    /// one-element JSON array or in OCAfile syntax (`Array[Text]`).
+    pub fn from_json(value: &Value) -> Option<AttrType> {
+        match value {
+            Value::Null => Some(AttrType::Null),
+            Value::String(s) => AttrType::parse(s),
+            Value::Array(items) if items.len() == 1 => {
+                Some(AttrType::Array(Box::new(AttrType::from_json(&items[0])?)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Parses the OCAfile spelling of a type.
+    pub fn parse(s: &str) -> Option<AttrType> {
+        let s = s.trim();
+        if let Some(inner) = s
+            .strip_prefix("Array[")
+            .or_else(|| s.strip_prefix('['))
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            return Some(AttrType::Array(Box::new(AttrType::parse(inner)?)));
+        }
+        if let Some(said) = s.strip_prefix("refs:") {
+            return (!said.is_empty()).then(|| AttrType::RefSaid(said.to_string()));
+        }
+        if let Some(name) = s.strip_prefix("refn:") {
+            return (!name.is_empty()).then(|| AttrType::RefName(name.to_string()));
+        }
+        match s {
+            "Text" => Some(AttrType::Text),
+            "Numeric" => Some(AttrType::Numeric),
+            "Boolean" => Some(AttrType::Boolean),
+            "DateTime" => Some(AttrType::DateTime),
+            "Binary" => Some(AttrType::Binary),
+            "Null" => Some(AttrType::Null),
+            _ => None,
+        }
+    }
+}
+
+/// Structured form of an attribute type, e.g.
+/// `{"kind": "array", "items": {"kind": "reference", "said": "E..."}}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TypeTree {
+    Value {
+        #[serde(rename = "type")]
+        value_type: String,
+    },
+    /// Exactly one of `said` and `name` is set.
+    Reference {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        said: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+    Array {
+        items: Box<TypeTree>,
+    },
+    Null,
+}
+
+impl From<AttrType> for TypeTree {
+    fn from(attr_type: AttrType) -> TypeTree {
+        match attr_type {
+            AttrType::RefSaid(said) => TypeTree::Reference {
+                said: Some(said),
+                name: None,
+            },
+            AttrType::RefName(name) => TypeTree::Reference {
+                said: None,
+                name: Some(name),
+            },
+            AttrType::Array(items) => TypeTree::Array {
+                items: Box::new(TypeTree::from(*items)),
+            },
+            AttrType::Null => TypeTree::Null,
+            value => TypeTree::Value {
+                value_type: value.to_string(),
+            },
+        }
+    }
+}
+
+impl TryFrom<TypeTree> for AttrType {
+    type Error = String;
+
+    fn try_from(tree: TypeTree) -> Result<AttrType, String> {
+        match tree {
+            TypeTree::Value { value_type } => match value_type.as_str() {
+                "Text" => Ok(AttrType::Text),
+                "Numeric" => Ok(AttrType::Numeric),
+                "Boolean" => Ok(AttrType::Boolean),
+                "DateTime" => Ok(AttrType::DateTime),
+                "Binary" => Ok(AttrType::Binary),
+                other => Err(format!("Unknown value type '{other}'")),
+            },
+            TypeTree::Reference {
+                said: Some(said),
+                name: None,
+            } if !said.is_empty() => Ok(AttrType::RefSaid(said)),
+            TypeTree::Reference {
+                said: None,
+                name: Some(name),
+            } if !name.is_empty() => Ok(AttrType::RefName(name)),
+            TypeTree::Reference { .. } => Err("A reference needs exactly one non-empty 'said' or 'name'".to_string()),
+            TypeTree::Array { items } => Ok(AttrType::Array(Box::new(AttrType::try_from(*items)?))),
+            TypeTree::Null => Ok(AttrType::Null),
+        }
+    }
+}
+
+impl std::fmt::Display for AttrType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttrType::Text => write!(f, "Text"),
+            AttrType::Numeric => write!(f, "Numeric"),
+            AttrType::Boolean => write!(f, "Boolean"),
+            AttrType::DateTime => write!(f, "DateTime"),
+            AttrType::Binary => write!(f, "Binary"),
+            AttrType::RefSaid(said) => write!(f, "refs:{said}"),
+            AttrType::RefName(name) => write!(f, "refn:{name}"),
+            AttrType::Array(items) => write!(f, "Array[{items}]"),
+            AttrType::Null => write!(f, "Null"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_ocafile_spellings() {
+        assert_eq!(AttrType::parse("Text"), Some(AttrType::Text));
+        let address = AttrType::RefName("address".to_string());
+        let nested = AttrType::Array(Box::new(AttrType::Array(Box::new(address))));
+        assert_eq!(AttrType::parse("Array[Array[refn:address]]"), Some(nested));
+        assert_eq!(AttrType::parse("[refs:EAbc]"), Some(AttrType::Array(Box::new(AttrType::RefSaid("EAbc".to_string())))));
+        assert_eq!(AttrType::parse("Integer"), None);
+        for missing in ["", " ", "refs:", "refn:", "Array[]", "Array[refn:]"] {
+            assert_eq!(AttrType::parse(missing), None, "{missing:?}");
+        }
+        assert_eq!(AttrType::from_json(&json!(["DateTime"])), Some(AttrType::Array(Box::new(AttrType::DateTime))));
+        assert_eq!(AttrType::from_json(&json!(null)), Some(AttrType::Null));
+        assert_eq!(AttrType::from_json(&json!(["Text", "Numeric"])), None);
+    }
+
+    #[test]
+    fn round_trips_through_the_type_tree() {
+        for spelling in ["Numeric", "refs:EAbc", "refn:address", "Array[Binary]", "Null"] {
+            let attr_type = AttrType::parse(spelling).unwrap();
+            let tree = serde_json::to_value(&attr_type).unwrap();
+            assert_eq!(serde_json::from_value::<AttrType>(tree).unwrap(), attr_type);
+            assert_eq!(attr_type.to_string(), spelling);
+        }
+        assert_eq!(
+            serde_json::to_value(AttrType::parse("Array[refn:address]").unwrap()).unwrap(),
+            json!({ "kind": "array", "items": { "kind": "reference", "name": "address" } })
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_trees() {
+        let both = json!({ "kind": "reference", "said": "EAbc", "name": "address" });
+        assert!(serde_json::from_value::<AttrType>(both).is_err());
+        assert!(serde_json::from_value::<AttrType>(json!({ "kind": "value", "type": "Integer" })).is_err());
+        assert!(serde_json::from_value::<AttrType>(json!({ "kind": "reference", "name": "" })).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+//! The version string a bundle carries in `v`, e.g. `OCAS02JSON000106_`:
+//! protocol, major and minor version as one hex digit each, serialization
+//! format and the size of the serialized bundle as six hex digits, read the
+//! way `said::version::SerializationInfo` reads them. OCA 2.0 bundles carry
+//! `OCAS02JSON…` and those of the 1.x format `OCAS10JSON…` or `OCAS11JSON…`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// The versions these bindings read: the JSON form of OCA 2.0 bundles.
+pub const SUPPORTED: &[(&str, u8, u8, &str)] = &[("OCAS", 0, 2, "JSON")];
+
+const LENGTH: usize = 17;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Version {
+    pub protocol: String,
+    pub major: u8,
+    pub minor: u8,
+    pub format: String,
+    /// Declared length of the serialized bundle in bytes.
+    pub size: usize,
+}
+
+impl Version {
+    pub fn is_supported(&self) -> bool {
+        SUPPORTED.iter().any(|&(protocol, major, minor, format)| {
+            self.protocol == protocol && self.major == major && self.minor == minor && self.format == format
+        })
+    }
+}
+
+impl FromStr for Version {
+    type Err = String;
+
+    fn from_str(v: &str) -> Result<Version, String> {
+        let invalid = |reason: &str| format!("Invalid version string '{v}': {reason}");
+        if v.len() != LENGTH || !v.is_ascii() {
+            return Err(invalid(&format!("expected {LENGTH} ASCII characters")));
+        }
+        if !v.ends_with('_') {
+            return Err(invalid("expected it to end with '_'"));
+        }
+        let hex = |digits: &str, what: &str| {
+            usize::from_str_radix(digits, 16).map_err(|_| invalid(&format!("{what} '{digits}' is not hexadecimal")))
+        };
+        let protocol = &v[0..4];
+        let format = &v[6..10];
+        if !protocol.chars().all(|c| c.is_ascii_uppercase()) || !format.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(invalid("protocol and format must be upper case letters"));
+        }
+
+        Ok(Version {
+            protocol: protocol.to_string(),
+            major: hex(&v[4..5], "major version")? as u8,
+            minor: hex(&v[5..6], "minor version")? as u8,
+            format: format.to_string(),
+            size: hex(&v[10..16], "size")?,
+        })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{:x}{:x}{}{:06x}_", self.protocol, self.major, self.minor, self.format, self.size)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VersionCheck {
+    pub version: Version,
+    /// Length of the bundle serialized as compact JSON.
+    pub actual_size: usize,
+    pub size_matches: bool,
+    pub supported: bool,
+}
+
+/// Parses the bundle's `v` and compares the declared size with the actual
+/// one.
+pub fn check(bundle: &Value) -> Result<VersionCheck, String> {
+    let v = bundle
+        .get("v")
+        .ok_or("The bundle has no version string 'v'")?
+        .as_str()
+        .ok_or("The bundle's version 'v' is not a string")?;
+    let version: Version = v.parse()?;
+    let actual_size = serde_json::to_vec(bundle).map_err(|e| e.to_string())?.len();
+
+    Ok(VersionCheck {
+        size_matches: version.size == actual_size,
+        supported: version.is_supported(),
+        version,
+        actual_size,
+    })
+}
+
+/// Refuses bundles whose `v` names a protocol version these bindings cannot
+/// read, before deserialization fails on them with a less helpful error.
+/// Bundles without `v` are left to the deserializer.
+pub fn require_supported(bundle: &Value) -> Result<(), String> {
+    let Some(v) = bundle.get("v").and_then(Value::as_str) else {
+        return Ok(());
+    };
+    let version: Version = v.parse()?;
+    if version.is_supported() {
+        return Ok(());
+    }
+    let supported: Vec<String> = SUPPORTED
+        .iter()
+        .map(|&(protocol, major, minor, format)| format!("{protocol}{major:x}{minor:x}{format}"))
+        .collect();
+    Err(format!(
+        "Unsupported bundle version {v}: protocol {} {}.{} in {}, expected {}",
+        version.protocol,
+        version.major,
+        version.minor,
+        version.format,
+        supported.join(" or "),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_oca_2_version_strings() {
+        let version: Version = "OCAS02JSON0012ce_".parse().unwrap();
+        assert_eq!(
+            version,
+            Version {
+                protocol: "OCAS".to_string(),
+                major: 0,
+                minor: 2,
+                format: "JSON".to_string(),
+                size: 0x12ce,
+            }
+        );
+        assert!(version.is_supported());
+        assert_eq!(version.to_string(), "OCAS02JSON0012ce_");
+    }
+
+    #[test]
+    fn parses_1x_version_strings() {
+        for (v, minor) in [("OCAS10JSON000646_", 0), ("OCAS11JSON0009a0_", 1)] {
+            let version: Version = v.parse().unwrap();
+            assert_eq!((version.major, version.minor), (1, minor), "{v}");
+            assert!(!version.is_supported());
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_version_strings() {
+        for v in ["OCAS02JSON000106", "OCAS02JSON0001060", "ocas02JSON000106_", "OCASx2JSON000106_", "OCAS02JSON00010g_"] {
+            assert!(v.parse::<Version>().is_err(), "{v}");
+        }
+    }
+
+    #[test]
+    fn refuses_unsupported_versions() {
+        assert!(require_supported(&serde_json::json!({ "v": "OCAS02JSON000106_" })).is_ok());
+        assert!(require_supported(&serde_json::json!({})).is_ok());
+        let err = require_supported(&serde_json::json!({ "v": "OCAS11JSON0009a0_" })).unwrap_err();
+        assert!(err.contains("protocol OCAS 1.1 in JSON, expected OCAS02JSON"), "{err}");
+    }
+
+    #[test]
+    fn compares_the_declared_size() {
+        let mut bundle = serde_json::json!({ "v": "OCAS02JSON000000_", "d": "" });
+        let size = serde_json::to_vec(&bundle).unwrap().len();
+        bundle["v"] = format!("OCAS02JSON{size:06x}_").into();
+        let check = check(&bundle).unwrap();
+        assert!(check.size_matches && check.supported);
+    }
+}
@@ -11,10 +11,58 @@ import 'package:uuid/uuid.dart';
 import 'dart:ffi' as ffi;
 
 abstract class OcaDart {
-  Future<OcaBundle> loadOca({required String json, dynamic hint});
+  /// Loads bundle JSON of the 2.0 format, or of the 1.x format, which is
+  /// upgraded first; see `OcaBundle::upgrade_notes`. The SAIDs of an upgraded
+  /// bundle differ from those of the 1.x bundle.
+  Future<OcaBundle> loadOca(
+      {required String json, OcaRegistry? registry, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kLoadOcaConstMeta;
 
+  /// Whether `load_oca` would upgrade the bundle from the 1.x format.
+  Future<bool> isLegacyBundle({required String json, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kIsLegacyBundleConstMeta;
+
+  /// Returns the OCAfile AST as JSON. `meta` and `commands` are what most
+  /// callers want.
+  Future<String> parseOcafile(
+      {required String ocafile, OcaRegistry? registry, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kParseOcafileConstMeta;
+
+  /// With a `base` bundle, the OCAfile's commands are applied on top of it.
+  Future<OcaBundle> buildFromOcafile(
+      {required String ocafile,
+      OcaRegistry? registry,
+      OcaBundle? base,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kBuildFromOcafileConstMeta;
+
+  Future<String> generateOcafile({required OcaBundle bundle, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kGenerateOcafileConstMeta;
+
+  /// Recomputes the SAIDs of the capture base, each overlay and the bundle and
+  /// compares them with the embedded ones, in that order. The bundle is intact
+  /// when every one of them is `valid`.
+  Future<List<OcaSaidVerification>> verifyBundle(
+      {required String json, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kVerifyBundleConstMeta;
+
+  Future<OcaRegistry> newStaticMethodOcaRegistry({dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNewStaticMethodOcaRegistryConstMeta;
+
+  /// Parses the contents of one or more `.overlayfile`s.
+  Future<OcaRegistry> fromOverlayFileStaticMethodOcaRegistry(
+      {required String overlayFile, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kFromOverlayFileStaticMethodOcaRegistryConstMeta;
+
   Future<OcaBox> newStaticMethodOcaBox({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kNewStaticMethodOcaBoxConstMeta;
@@ -33,21 +81,13 @@ abstract class OcaDart {
 
   FlutterRustBridgeTaskConstMeta get kAddAttributeMethodOcaBoxConstMeta;
 
+  /// Builds the bundle the same way as the equivalent OCAfile, so it gets
+  /// the same SAID.
   Future<OcaBundle> generateBundleMethodOcaBox(
-      {required OcaBox that, dynamic hint});
+      {required OcaBox that, OcaRegistry? registry, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kGenerateBundleMethodOcaBoxConstMeta;
 
-  Future<void> addFormLayoutMethodOcaBox(
-      {required OcaBox that, required String layout, dynamic hint});
-
-  FlutterRustBridgeTaskConstMeta get kAddFormLayoutMethodOcaBoxConstMeta;
-
-  Future<void> addCredentialLayoutMethodOcaBox(
-      {required OcaBox that, required String layout, dynamic hint});
-
-  FlutterRustBridgeTaskConstMeta get kAddCredentialLayoutMethodOcaBoxConstMeta;
-
   Future<OcaAttr> newStaticMethodOcaAttr({required String name, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kNewStaticMethodOcaAttrConstMeta;
@@ -57,6 +97,15 @@ abstract class OcaDart {
 
   FlutterRustBridgeTaskConstMeta get kSetAttributeTypeMethodOcaAttrConstMeta;
 
+  /// Sets the type by its OCAfile spelling, e.g. `refs:E...` or
+  /// `Array[refn:address]`, for types `OcaAttrType` has no variant for.
+  Future<void> setAttributeTypeSpellingMethodOcaAttr(
+      {required OcaAttr that, required String spelling, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kSetAttributeTypeSpellingMethodOcaAttrConstMeta;
+
+  /// Lists the attribute in the sensitive overlay.
   Future<void> setFlaggedMethodOcaAttr({required OcaAttr that, dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kSetFlaggedMethodOcaAttrConstMeta;
@@ -97,11 +146,7 @@ abstract class OcaDart {
 
   FlutterRustBridgeTaskConstMeta get kSetEntryCodesMethodOcaAttrConstMeta;
 
-  Future<void> setEntryCodesSaiMethodOcaAttr(
-      {required OcaAttr that, required String sai, dynamic hint});
-
-  FlutterRustBridgeTaskConstMeta get kSetEntryCodesSaiMethodOcaAttrConstMeta;
-
+  /// `entries` maps entry codes to their labels in `lang`.
   Future<void> setEntryMethodOcaAttr(
       {required OcaAttr that,
       required String lang,
@@ -110,15 +155,11 @@ abstract class OcaDart {
 
   FlutterRustBridgeTaskConstMeta get kSetEntryMethodOcaAttrConstMeta;
 
-  Future<void> setUnitMetricMethodOcaAttr(
-      {required OcaAttr that, required OcaMetricUnit unit, dynamic hint});
-
-  FlutterRustBridgeTaskConstMeta get kSetUnitMetricMethodOcaAttrConstMeta;
+  /// A unit symbol such as `kg`, as listed by the unit overlay.
+  Future<void> setUnitMethodOcaAttr(
+      {required OcaAttr that, required String unit, dynamic hint});
 
-  Future<void> setUnitImperialMethodOcaAttr(
-      {required OcaAttr that, required OcaImperialUnit unit, dynamic hint});
-
-  FlutterRustBridgeTaskConstMeta get kSetUnitImperialMethodOcaAttrConstMeta;
+  FlutterRustBridgeTaskConstMeta get kSetUnitMethodOcaAttrConstMeta;
 
   Future<void> setFormatMethodOcaAttr(
       {required OcaAttr that, required String format, dynamic hint});
@@ -143,6 +184,63 @@ abstract class OcaDart {
 
   FlutterRustBridgeTaskConstMeta get kOverlaysMethodOcaBundleConstMeta;
 
+  /// The languages of the overlays, in order of appearance.
+  Future<List<String>> languagesMethodOcaBundle(
+      {required OcaBundle that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLanguagesMethodOcaBundleConstMeta;
+
+  /// A copy of the bundle without the overlays in other languages than
+  /// `keep`. Overlays without a language are kept.
+  Future<OcaBundle> keepLanguagesMethodOcaBundle(
+      {required OcaBundle that, required List<String> keep, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kKeepLanguagesMethodOcaBundleConstMeta;
+
+  /// The overlays in `language` as a translation package, in JSON: the
+  /// `language`, the `capture_base` SAID and the `overlays` without SAIDs.
+  Future<String> extractTranslationMethodOcaBundle(
+      {required OcaBundle that, required String language, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kExtractTranslationMethodOcaBundleConstMeta;
+
+  /// A copy of the bundle with the overlays of a translation package, as
+  /// returned by `extract_translation`, replacing those of the same type
+  /// in its language.
+  Future<OcaBundle> mergeTranslationMethodOcaBundle(
+      {required OcaBundle that, required String translation, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kMergeTranslationMethodOcaBundleConstMeta;
+
+  /// One descriptor per attribute of the capture base, in capture base
+  /// order, with what the overlays say about it.
+  Future<List<OcaAttributeDescriptor>> attributesMethodOcaBundle(
+      {required OcaBundle that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kAttributesMethodOcaBundleConstMeta;
+
+  /// What a bundle loaded from the 1.x format lost in the upgrade, e.g.
+  /// its form layout. Empty for bundles that needed no upgrade.
+  Future<List<String>> upgradeNotesMethodOcaBundle(
+      {required OcaBundle that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kUpgradeNotesMethodOcaBundleConstMeta;
+
+  /// Checks the overlays against the capture base and each other. Empty
+  /// when the bundle is consistent.
+  Future<List<OcaSemanticIssue>> validateSemanticsMethodOcaBundle(
+      {required OcaBundle that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kValidateSemanticsMethodOcaBundleConstMeta;
+
+  /// Checks a data record, given as a JSON object, against the attributes
+  /// and constraint overlays. Empty when the record is valid.
+  Future<List<OcaDataError>> validateDataMethodOcaBundle(
+      {required OcaBundle that, required String record, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kValidateDataMethodOcaBundleConstMeta;
+
   Future<OcaMap> attributesMethodOcaCaptureBase(
       {required OcaCaptureBase that, dynamic hint});
 
@@ -154,6 +252,175 @@ abstract class OcaDart {
   FlutterRustBridgeTaskConstMeta
       get kFlaggedAttributesMethodOcaCaptureBaseConstMeta;
 
+  /// The full type, e.g. `overlay/label/2.0.0`.
+  Future<String> overlayTypeMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kOverlayTypeMethodOcaOverlayConstMeta;
+
+  /// The short name of the type, e.g. `label`.
+  Future<String?> nameMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNameMethodOcaOverlayConstMeta;
+
+  Future<String?> languageMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLanguageMethodOcaOverlayConstMeta;
+
+  Future<String?> saidMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kSaidMethodOcaOverlayConstMeta;
+
+  /// The SAID of the capture base the overlay belongs to.
+  Future<String?> captureBaseMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCaptureBaseMethodOcaOverlayConstMeta;
+
+  Future<String> toJsonMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kToJsonMethodOcaOverlayConstMeta;
+
+  /// Attribute names and their labels.
+  Future<OcaMap> labelsMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kLabelsMethodOcaOverlayConstMeta;
+
+  /// The entry codes and their labels for `attribute`, unless they are
+  /// given by reference to another bundle.
+  Future<OcaMap?> entriesMethodOcaOverlay(
+      {required OcaOverlay that, required String attribute, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kEntriesMethodOcaOverlayConstMeta;
+
+  /// The entry codes of `attribute`, unless they are given by reference
+  /// to another bundle.
+  Future<List<String>?> entryCodesMethodOcaOverlay(
+      {required OcaOverlay that, required String attribute, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kEntryCodesMethodOcaOverlayConstMeta;
+
+  Future<OcaMap> formatsMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kFormatsMethodOcaOverlayConstMeta;
+
+  Future<OcaMap> unitsMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kUnitsMethodOcaOverlayConstMeta;
+
+  Future<OcaMap> conformancesMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kConformancesMethodOcaOverlayConstMeta;
+
+  Future<OcaMap> cardinalitiesMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kCardinalitiesMethodOcaOverlayConstMeta;
+
+  /// The meta fields, such as `name` and `description`. Values that are
+  /// not text are given as JSON.
+  Future<OcaMap> metaMethodOcaOverlay({required OcaOverlay that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kMetaMethodOcaOverlayConstMeta;
+
+  Future<String> nameMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kNameMethodOcaAttributeDescriptorConstMeta;
+
+  /// The OCAfile spelling of the type, e.g. `Array[Text]`.
+  Future<String?> attributeTypeMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kAttributeTypeMethodOcaAttributeDescriptorConstMeta;
+
+  /// Languages and the label in each.
+  Future<OcaMap> labelsMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kLabelsMethodOcaAttributeDescriptorConstMeta;
+
+  /// Languages and the information text in each.
+  Future<OcaMap> informationMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kInformationMethodOcaAttributeDescriptorConstMeta;
+
+  Future<String?> conformanceMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kConformanceMethodOcaAttributeDescriptorConstMeta;
+
+  Future<String?> cardinalityMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kCardinalityMethodOcaAttributeDescriptorConstMeta;
+
+  Future<String?> formatMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kFormatMethodOcaAttributeDescriptorConstMeta;
+
+  Future<String?> unitMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta get kUnitMethodOcaAttributeDescriptorConstMeta;
+
+  Future<String?> characterEncodingMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kCharacterEncodingMethodOcaAttributeDescriptorConstMeta;
+
+  Future<String?> standardMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kStandardMethodOcaAttributeDescriptorConstMeta;
+
+  /// The entry codes, unless they are given by reference to another
+  /// bundle.
+  Future<List<String>?> entryCodesMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kEntryCodesMethodOcaAttributeDescriptorConstMeta;
+
+  /// The entry codes and their labels in `lang`.
+  Future<OcaMap?> entriesMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that,
+      required String lang,
+      dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kEntriesMethodOcaAttributeDescriptorConstMeta;
+
+  Future<bool> sensitiveMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kSensitiveMethodOcaAttributeDescriptorConstMeta;
+
+  Future<String> toJsonMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint});
+
+  FlutterRustBridgeTaskConstMeta
+      get kToJsonMethodOcaAttributeDescriptorConstMeta;
+
   Future<OcaMap> newStaticMethodOcaMap({dynamic hint});
 
   FlutterRustBridgeTaskConstMeta get kNewStaticMethodOcaMapConstMeta;
@@ -181,14 +448,14 @@ abstract class OcaDart {
 
   FlutterRustBridgeTaskConstMeta get kGetKeysMethodOcaMapConstMeta;
 
-  DropFnType get dropOpaqueMutexDynOverlay;
-  ShareFnType get shareOpaqueMutexDynOverlay;
-  OpaqueTypeFinalizer get MutexDynOverlayFinalizer;
-
   DropFnType get dropOpaqueMutexOcaAttrRaw;
   ShareFnType get shareOpaqueMutexOcaAttrRaw;
   OpaqueTypeFinalizer get MutexOcaAttrRawFinalizer;
 
+  DropFnType get dropOpaqueMutexOcaAttributeDescriptorRaw;
+  ShareFnType get shareOpaqueMutexOcaAttributeDescriptorRaw;
+  OpaqueTypeFinalizer get MutexOcaAttributeDescriptorRawFinalizer;
+
   DropFnType get dropOpaqueMutexOcaBoxRaw;
   ShareFnType get shareOpaqueMutexOcaBoxRaw;
   OpaqueTypeFinalizer get MutexOcaBoxRawFinalizer;
@@ -201,39 +468,48 @@ abstract class OcaDart {
   ShareFnType get shareOpaqueMutexOcaCaptureBaseRaw;
   OpaqueTypeFinalizer get MutexOcaCaptureBaseRawFinalizer;
 
+  DropFnType get dropOpaqueMutexOcaOverlayRaw;
+  ShareFnType get shareOpaqueMutexOcaOverlayRaw;
+  OpaqueTypeFinalizer get MutexOcaOverlayRawFinalizer;
+
   DropFnType get dropOpaqueMutexStringMap;
   ShareFnType get shareOpaqueMutexStringMap;
   OpaqueTypeFinalizer get MutexStringMapFinalizer;
+
+  DropFnType get dropOpaqueOcaRegistryRaw;
+  ShareFnType get shareOpaqueOcaRegistryRaw;
+  OpaqueTypeFinalizer get OcaRegistryRawFinalizer;
 }
 
 @sealed
-class MutexDynOverlay extends FrbOpaque {
+class MutexOcaAttrRaw extends FrbOpaque {
   final OcaDart bridge;
-  MutexDynOverlay.fromRaw(int ptr, int size, this.bridge)
+  MutexOcaAttrRaw.fromRaw(int ptr, int size, this.bridge)
       : super.unsafe(ptr, size);
   @override
-  DropFnType get dropFn => bridge.dropOpaqueMutexDynOverlay;
+  DropFnType get dropFn => bridge.dropOpaqueMutexOcaAttrRaw;
 
   @override
-  ShareFnType get shareFn => bridge.shareOpaqueMutexDynOverlay;
+  ShareFnType get shareFn => bridge.shareOpaqueMutexOcaAttrRaw;
 
   @override
-  OpaqueTypeFinalizer get staticFinalizer => bridge.MutexDynOverlayFinalizer;
+  OpaqueTypeFinalizer get staticFinalizer => bridge.MutexOcaAttrRawFinalizer;
 }
 
 @sealed
-class MutexOcaAttrRaw extends FrbOpaque {
+class MutexOcaAttributeDescriptorRaw extends FrbOpaque {
   final OcaDart bridge;
-  MutexOcaAttrRaw.fromRaw(int ptr, int size, this.bridge)
+  MutexOcaAttributeDescriptorRaw.fromRaw(int ptr, int size, this.bridge)
       : super.unsafe(ptr, size);
   @override
-  DropFnType get dropFn => bridge.dropOpaqueMutexOcaAttrRaw;
+  DropFnType get dropFn => bridge.dropOpaqueMutexOcaAttributeDescriptorRaw;
 
   @override
-  ShareFnType get shareFn => bridge.shareOpaqueMutexOcaAttrRaw;
+  ShareFnType get shareFn => bridge.shareOpaqueMutexOcaAttributeDescriptorRaw;
 
   @override
-  OpaqueTypeFinalizer get staticFinalizer => bridge.MutexOcaAttrRawFinalizer;
+  OpaqueTypeFinalizer get staticFinalizer =>
+      bridge.MutexOcaAttributeDescriptorRawFinalizer;
 }
 
 @sealed
@@ -282,6 +558,21 @@ class MutexOcaCaptureBaseRaw extends FrbOpaque {
       bridge.MutexOcaCaptureBaseRawFinalizer;
 }
 
+@sealed
+class MutexOcaOverlayRaw extends FrbOpaque {
+  final OcaDart bridge;
+  MutexOcaOverlayRaw.fromRaw(int ptr, int size, this.bridge)
+      : super.unsafe(ptr, size);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueMutexOcaOverlayRaw;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueMutexOcaOverlayRaw;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.MutexOcaOverlayRawFinalizer;
+}
+
 @sealed
 class MutexStringMap extends FrbOpaque {
   final OcaDart bridge;
@@ -297,6 +588,21 @@ class MutexStringMap extends FrbOpaque {
   OpaqueTypeFinalizer get staticFinalizer => bridge.MutexStringMapFinalizer;
 }
 
+@sealed
+class OcaRegistryRaw extends FrbOpaque {
+  final OcaDart bridge;
+  OcaRegistryRaw.fromRaw(int ptr, int size, this.bridge)
+      : super.unsafe(ptr, size);
+  @override
+  DropFnType get dropFn => bridge.dropOpaqueOcaRegistryRaw;
+
+  @override
+  ShareFnType get shareFn => bridge.shareOpaqueOcaRegistryRaw;
+
+  @override
+  OpaqueTypeFinalizer get staticFinalizer => bridge.OcaRegistryRawFinalizer;
+}
+
 class OcaAttr {
   final OcaDart bridge;
   final MutexOcaAttrRaw field0;
@@ -317,6 +623,16 @@ class OcaAttr {
         attrType: attrType,
       );
 
+  /// Sets the type by its OCAfile spelling, e.g. `refs:E...` or
+  /// `Array[refn:address]`, for types `OcaAttrType` has no variant for.
+  Future<void> setAttributeTypeSpelling(
+          {required String spelling, dynamic hint}) =>
+      bridge.setAttributeTypeSpellingMethodOcaAttr(
+        that: this,
+        spelling: spelling,
+      );
+
+  /// Lists the attribute in the sensitive overlay.
   Future<void> setFlagged({dynamic hint}) => bridge.setFlaggedMethodOcaAttr(
         that: this,
       );
@@ -362,12 +678,7 @@ class OcaAttr {
         entryCodes: entryCodes,
       );
 
-  Future<void> setEntryCodesSai({required String sai, dynamic hint}) =>
-      bridge.setEntryCodesSaiMethodOcaAttr(
-        that: this,
-        sai: sai,
-      );
-
+  /// `entries` maps entry codes to their labels in `lang`.
   Future<void> setEntry(
           {required String lang, required OcaMap entries, dynamic hint}) =>
       bridge.setEntryMethodOcaAttr(
@@ -376,14 +687,9 @@ class OcaAttr {
         entries: entries,
       );
 
-  Future<void> setUnitMetric({required OcaMetricUnit unit, dynamic hint}) =>
-      bridge.setUnitMetricMethodOcaAttr(
-        that: this,
-        unit: unit,
-      );
-
-  Future<void> setUnitImperial({required OcaImperialUnit unit, dynamic hint}) =>
-      bridge.setUnitImperialMethodOcaAttr(
+  /// A unit symbol such as `kg`, as listed by the unit overlay.
+  Future<void> setUnit({required String unit, dynamic hint}) =>
+      bridge.setUnitMethodOcaAttr(
         that: this,
         unit: unit,
       );
@@ -395,6 +701,8 @@ class OcaAttr {
       );
 }
 
+/// Value types and arrays of them. References are set with
+/// `OcaAttr::set_attribute_type_spelling`.
 enum OcaAttrType {
   Boolean,
   ArrayBoolean,
@@ -406,8 +714,93 @@ enum OcaAttrType {
   ArrayNumeric,
   DateTime,
   ArrayDateTime,
-  Reference,
-  ArrayReference,
+}
+
+class OcaAttributeDescriptor {
+  final OcaDart bridge;
+  final MutexOcaAttributeDescriptorRaw field0;
+
+  const OcaAttributeDescriptor({
+    required this.bridge,
+    required this.field0,
+  });
+
+  Future<String> name({dynamic hint}) =>
+      bridge.nameMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  /// The OCAfile spelling of the type, e.g. `Array[Text]`.
+  Future<String?> attributeType({dynamic hint}) =>
+      bridge.attributeTypeMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  /// Languages and the label in each.
+  Future<OcaMap> labels({dynamic hint}) =>
+      bridge.labelsMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  /// Languages and the information text in each.
+  Future<OcaMap> information({dynamic hint}) =>
+      bridge.informationMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  Future<String?> conformance({dynamic hint}) =>
+      bridge.conformanceMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  Future<String?> cardinality({dynamic hint}) =>
+      bridge.cardinalityMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  Future<String?> format({dynamic hint}) =>
+      bridge.formatMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  Future<String?> unit({dynamic hint}) =>
+      bridge.unitMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  Future<String?> characterEncoding({dynamic hint}) =>
+      bridge.characterEncodingMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  Future<String?> standard({dynamic hint}) =>
+      bridge.standardMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  /// The entry codes, unless they are given by reference to another
+  /// bundle.
+  Future<List<String>?> entryCodes({dynamic hint}) =>
+      bridge.entryCodesMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  /// The entry codes and their labels in `lang`.
+  Future<OcaMap?> entries({required String lang, dynamic hint}) =>
+      bridge.entriesMethodOcaAttributeDescriptor(
+        that: this,
+        lang: lang,
+      );
+
+  Future<bool> sensitive({dynamic hint}) =>
+      bridge.sensitiveMethodOcaAttributeDescriptor(
+        that: this,
+      );
+
+  Future<String> toJson({dynamic hint}) =>
+      bridge.toJsonMethodOcaAttributeDescriptor(
+        that: this,
+      );
 }
 
 class OcaBox {
@@ -440,21 +833,12 @@ class OcaBox {
         attr: attr,
       );
 
-  Future<OcaBundle> generateBundle({dynamic hint}) =>
+  /// Builds the bundle the same way as the equivalent OCAfile, so it gets
+  /// the same SAID.
+  Future<OcaBundle> generateBundle({OcaRegistry? registry, dynamic hint}) =>
       bridge.generateBundleMethodOcaBox(
         that: this,
-      );
-
-  Future<void> addFormLayout({required String layout, dynamic hint}) =>
-      bridge.addFormLayoutMethodOcaBox(
-        that: this,
-        layout: layout,
-      );
-
-  Future<void> addCredentialLayout({required String layout, dynamic hint}) =>
-      bridge.addCredentialLayoutMethodOcaBox(
-        that: this,
-        layout: layout,
+        registry: registry,
       );
 }
 
@@ -484,6 +868,68 @@ class OcaBundle {
       bridge.overlaysMethodOcaBundle(
         that: this,
       );
+
+  /// The languages of the overlays, in order of appearance.
+  Future<List<String>> languages({dynamic hint}) =>
+      bridge.languagesMethodOcaBundle(
+        that: this,
+      );
+
+  /// A copy of the bundle without the overlays in other languages than
+  /// `keep`. Overlays without a language are kept.
+  Future<OcaBundle> keepLanguages({required List<String> keep, dynamic hint}) =>
+      bridge.keepLanguagesMethodOcaBundle(
+        that: this,
+        keep: keep,
+      );
+
+  /// The overlays in `language` as a translation package, in JSON: the
+  /// `language`, the `capture_base` SAID and the `overlays` without SAIDs.
+  Future<String> extractTranslation({required String language, dynamic hint}) =>
+      bridge.extractTranslationMethodOcaBundle(
+        that: this,
+        language: language,
+      );
+
+  /// A copy of the bundle with the overlays of a translation package, as
+  /// returned by `extract_translation`, replacing those of the same type
+  /// in its language.
+  Future<OcaBundle> mergeTranslation(
+          {required String translation, dynamic hint}) =>
+      bridge.mergeTranslationMethodOcaBundle(
+        that: this,
+        translation: translation,
+      );
+
+  /// One descriptor per attribute of the capture base, in capture base
+  /// order, with what the overlays say about it.
+  Future<List<OcaAttributeDescriptor>> attributes({dynamic hint}) =>
+      bridge.attributesMethodOcaBundle(
+        that: this,
+      );
+
+  /// What a bundle loaded from the 1.x format lost in the upgrade, e.g.
+  /// its form layout. Empty for bundles that needed no upgrade.
+  Future<List<String>> upgradeNotes({dynamic hint}) =>
+      bridge.upgradeNotesMethodOcaBundle(
+        that: this,
+      );
+
+  /// Checks the overlays against the capture base and each other. Empty
+  /// when the bundle is consistent.
+  Future<List<OcaSemanticIssue>> validateSemantics({dynamic hint}) =>
+      bridge.validateSemanticsMethodOcaBundle(
+        that: this,
+      );
+
+  /// Checks a data record, given as a JSON object, against the attributes
+  /// and constraint overlays. Empty when the record is valid.
+  Future<List<OcaDataError>> validateData(
+          {required String record, dynamic hint}) =>
+      bridge.validateDataMethodOcaBundle(
+        that: this,
+        record: record,
+      );
 }
 
 class OcaCaptureBase {
@@ -506,29 +952,69 @@ class OcaCaptureBase {
       );
 }
 
+/// A problem with a data record, as found by `OcaBundle::validate_data`.
+class OcaDataError {
+  final String? attribute;
+  /// JSON pointer to the offending value within the record.
+  final String path;
+  final OcaDataRule rule;
+  /// What the rule expects, as JSON, e.g. the list of entry codes.
+  final String? expected;
+  /// The offending value as JSON.
+  final String? actual;
+  final String message;
+
+  const OcaDataError({
+    this.attribute,
+    required this.path,
+    required this.rule,
+    this.expected,
+    this.actual,
+    required this.message,
+  });
+}
+
+/// The constraint an `OcaDataError` refers to.
+enum OcaDataRule {
+  Type,
+  Conformance,
+  EntryCode,
+  Format,
+  Cardinality,
+  /// A referenced bundle could not be resolved.
+  Reference,
+}
+
 enum OcaEncoding {
   Base64,
   Utf8,
   Iso8859_1,
 }
 
-enum OcaImperialUnit {
-  Pound,
-  Ounce,
-  Gallon,
-  Quart,
-  Pint,
-  FluidOunce,
-  Inch,
-  Foot,
-  Yard,
-  Mile,
-  Celsius,
-  Fahrenheit,
-  Kelvin,
-  Percent,
-  Count,
-  Other,
+enum OcaErrorKind {
+  /// JSON, OCAfile or overlay file text that could not be parsed.
+  Parse,
+  /// A value the builder cannot take, e.g. a label for an unknown attribute.
+  Invalid,
+  /// A bundle that could not be built, loaded, upgraded or checked.
+  Bundle,
+  /// Something the bundle lacks, e.g. its SAID.
+  Missing,
+  /// An object left unusable by a panic in an earlier call.
+  Poisoned,
+  /// A panic in Rust, caught before it reached Dart.
+  Panic,
+}
+
+/// The error of every call, thrown in Dart as `OcaException`.
+class OcaException implements FrbException {
+  final OcaErrorKind kind;
+  final String message;
+
+  const OcaException({
+    required this.kind,
+    required this.message,
+  });
 }
 
 class OcaMap {
@@ -568,32 +1054,165 @@ class OcaMap {
       );
 }
 
-enum OcaMetricUnit {
-  Kilogram,
-  Gram,
-  Milligram,
-  Liter,
-  Milliliter,
-  Centimeter,
-  Millimeter,
-  Inch,
-  Foot,
-  Yard,
-  Mile,
-  Celsius,
-  Fahrenheit,
-  Kelvin,
-  Percent,
-  Count,
-  Other,
-}
-
 class OcaOverlay {
-  final MutexDynOverlay field0;
+  final OcaDart bridge;
+  final MutexOcaOverlayRaw field0;
 
   const OcaOverlay({
+    required this.bridge,
+    required this.field0,
+  });
+
+  /// The full type, e.g. `overlay/label/2.0.0`.
+  Future<String> overlayType({dynamic hint}) =>
+      bridge.overlayTypeMethodOcaOverlay(
+        that: this,
+      );
+
+  /// The short name of the type, e.g. `label`.
+  Future<String?> name({dynamic hint}) => bridge.nameMethodOcaOverlay(
+        that: this,
+      );
+
+  Future<String?> language({dynamic hint}) => bridge.languageMethodOcaOverlay(
+        that: this,
+      );
+
+  Future<String?> said({dynamic hint}) => bridge.saidMethodOcaOverlay(
+        that: this,
+      );
+
+  /// The SAID of the capture base the overlay belongs to.
+  Future<String?> captureBase({dynamic hint}) =>
+      bridge.captureBaseMethodOcaOverlay(
+        that: this,
+      );
+
+  Future<String> toJson({dynamic hint}) => bridge.toJsonMethodOcaOverlay(
+        that: this,
+      );
+
+  /// Attribute names and their labels.
+  Future<OcaMap> labels({dynamic hint}) => bridge.labelsMethodOcaOverlay(
+        that: this,
+      );
+
+  /// The entry codes and their labels for `attribute`, unless they are
+  /// given by reference to another bundle.
+  Future<OcaMap?> entries({required String attribute, dynamic hint}) =>
+      bridge.entriesMethodOcaOverlay(
+        that: this,
+        attribute: attribute,
+      );
+
+  /// The entry codes of `attribute`, unless they are given by reference
+  /// to another bundle.
+  Future<List<String>?> entryCodes({required String attribute, dynamic hint}) =>
+      bridge.entryCodesMethodOcaOverlay(
+        that: this,
+        attribute: attribute,
+      );
+
+  Future<OcaMap> formats({dynamic hint}) => bridge.formatsMethodOcaOverlay(
+        that: this,
+      );
+
+  Future<OcaMap> units({dynamic hint}) => bridge.unitsMethodOcaOverlay(
+        that: this,
+      );
+
+  Future<OcaMap> conformances({dynamic hint}) =>
+      bridge.conformancesMethodOcaOverlay(
+        that: this,
+      );
+
+  Future<OcaMap> cardinalities({dynamic hint}) =>
+      bridge.cardinalitiesMethodOcaOverlay(
+        that: this,
+      );
+
+  /// The meta fields, such as `name` and `description`. Values that are
+  /// not text are given as JSON.
+  Future<OcaMap> meta({dynamic hint}) => bridge.metaMethodOcaOverlay(
+        that: this,
+      );
+}
+
+/// The overlay definitions bundles are built and loaded against. Functions
+/// that take an optional registry fall back to the built-in one.
+class OcaRegistry {
+  final OcaDart bridge;
+  final OcaRegistryRaw field0;
+
+  const OcaRegistry({
+    required this.bridge,
     required this.field0,
   });
+
+  static Future<OcaRegistry> newOcaRegistry(
+          {required OcaDart bridge, dynamic hint}) =>
+      bridge.newStaticMethodOcaRegistry(hint: hint);
+
+  /// Parses the contents of one or more `.overlayfile`s.
+  static Future<OcaRegistry> fromOverlayFile(
+          {required OcaDart bridge,
+          required String overlayFile,
+          dynamic hint}) =>
+      bridge.fromOverlayFileStaticMethodOcaRegistry(
+          overlayFile: overlayFile, hint: hint);
+}
+
+/// The SAID of one object of a bundle, as checked by `verify_bundle`.
+class OcaSaidVerification {
+  /// `capture_base`, `overlays[<index>]` or `bundle`.
+  final String path;
+  final String? objectType;
+  final String? language;
+  /// The SAID found in the object.
+  final String? embedded;
+  /// The SAID recomputed from the object's content.
+  final String? computed;
+  final bool valid;
+  final String? message;
+
+  const OcaSaidVerification({
+    required this.path,
+    this.objectType,
+    this.language,
+    this.embedded,
+    this.computed,
+    required this.valid,
+    this.message,
+  });
+}
+
+enum OcaSemanticCategory {
+  /// The bundle could not be read at all.
+  Format,
+  /// An overlay points at a different capture base.
+  CaptureBaseMismatch,
+  /// An overlay mentions an attribute the capture base does not declare.
+  UnknownAttribute,
+  /// An entry overlay translates a code the entry code overlay lacks.
+  EntryCodeMismatch,
+  /// Reported by the SDK validator without further structure.
+  Other,
+}
+
+/// A problem with a bundle, as found by `OcaBundle::validate_semantics`.
+class OcaSemanticIssue {
+  final OcaSemanticCategory category;
+  /// The short name of the overlay concerned, e.g. `label`.
+  final String? overlay;
+  final String? attribute;
+  final String message;
+
+  const OcaSemanticIssue({
+    required this.category,
+    this.overlay,
+    this.attribute,
+    required this.message,
+  });
 }
 
 class OcaDartImpl implements OcaDart {
@@ -605,14 +1224,16 @@ class OcaDartImpl implements OcaDart {
   factory OcaDartImpl.wasm(FutureOr<WasmModule> module) =>
       OcaDartImpl(module as ExternalLibrary);
   OcaDartImpl.raw(this._platform);
-  Future<OcaBundle> loadOca({required String json, dynamic hint}) {
+  Future<OcaBundle> loadOca(
+      {required String json, OcaRegistry? registry, dynamic hint}) {
     var arg0 = _platform.api2wire_String(json);
+    var arg1 = _platform.api2wire_opt_box_autoadd_oca_registry(registry);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner.wire_load_oca(port_, arg0),
+      callFfi: (port_) => _platform.inner.wire_load_oca(port_, arg0, arg1),
       parseSuccessData: (d) => _wire2api_oca_bundle(d),
-      parseErrorData: _wire2api_FrbAnyhowException,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kLoadOcaConstMeta,
-      argValues: [json],
+      argValues: [json, registry],
       hint: hint,
     ));
   }
@@ -620,15 +1241,154 @@ class OcaDartImpl implements OcaDart {
   FlutterRustBridgeTaskConstMeta get kLoadOcaConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
         debugName: "load_oca",
+        argNames: ["json", "registry"],
+      );
+
+  Future<bool> isLegacyBundle({required String json, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(json);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_is_legacy_bundle(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kIsLegacyBundleConstMeta,
+      argValues: [json],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kIsLegacyBundleConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "is_legacy_bundle",
+        argNames: ["json"],
+      );
+
+  Future<String> parseOcafile(
+      {required String ocafile, OcaRegistry? registry, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(ocafile);
+    var arg1 = _platform.api2wire_opt_box_autoadd_oca_registry(registry);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_parse_ocafile(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kParseOcafileConstMeta,
+      argValues: [ocafile, registry],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kParseOcafileConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "parse_ocafile",
+        argNames: ["ocafile", "registry"],
+      );
+
+  Future<OcaBundle> buildFromOcafile(
+      {required String ocafile,
+      OcaRegistry? registry,
+      OcaBundle? base,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_String(ocafile);
+    var arg1 = _platform.api2wire_opt_box_autoadd_oca_registry(registry);
+    var arg2 = _platform.api2wire_opt_box_autoadd_oca_bundle(base);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_build_from_ocafile(port_, arg0, arg1, arg2),
+      parseSuccessData: (d) => _wire2api_oca_bundle(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kBuildFromOcafileConstMeta,
+      argValues: [ocafile, registry, base],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kBuildFromOcafileConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "build_from_ocafile",
+        argNames: ["ocafile", "registry", "base"],
+      );
+
+  Future<String> generateOcafile({required OcaBundle bundle, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_bundle(bundle);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_generate_ocafile(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kGenerateOcafileConstMeta,
+      argValues: [bundle],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGenerateOcafileConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "generate_ocafile",
+        argNames: ["bundle"],
+      );
+
+  Future<List<OcaSaidVerification>> verifyBundle(
+      {required String json, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(json);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner.wire_verify_bundle(port_, arg0),
+      parseSuccessData: _wire2api_list_oca_said_verification,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kVerifyBundleConstMeta,
+      argValues: [json],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kVerifyBundleConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "verify_bundle",
         argNames: ["json"],
       );
 
+  Future<OcaRegistry> newStaticMethodOcaRegistry({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_new__static_method__OcaRegistry(port_),
+      parseSuccessData: (d) => _wire2api_oca_registry(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kNewStaticMethodOcaRegistryConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNewStaticMethodOcaRegistryConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "new__static_method__OcaRegistry",
+        argNames: [],
+      );
+
+  Future<OcaRegistry> fromOverlayFileStaticMethodOcaRegistry(
+      {required String overlayFile, dynamic hint}) {
+    var arg0 = _platform.api2wire_String(overlayFile);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_from_overlay_file__static_method__OcaRegistry(port_, arg0),
+      parseSuccessData: (d) => _wire2api_oca_registry(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kFromOverlayFileStaticMethodOcaRegistryConstMeta,
+      argValues: [overlayFile],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kFromOverlayFileStaticMethodOcaRegistryConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "from_overlay_file__static_method__OcaRegistry",
+            argNames: ["overlayFile"],
+          );
+
   Future<OcaBox> newStaticMethodOcaBox({dynamic hint}) {
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) =>
           _platform.inner.wire_new__static_method__OcaBox(port_),
       parseSuccessData: (d) => _wire2api_oca_box(d),
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kNewStaticMethodOcaBoxConstMeta,
       argValues: [],
       hint: hint,
@@ -655,7 +1415,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) => _platform.inner
           .wire_add_meta__method__OcaBox(port_, arg0, arg1, arg2, arg3),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: _wire2api_FrbAnyhowException,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kAddMetaMethodOcaBoxConstMeta,
       argValues: [that, lang, name, value],
       hint: hint,
@@ -676,7 +1436,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) =>
           _platform.inner.wire_add_attribute__method__OcaBox(port_, arg0, arg1),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kAddAttributeMethodOcaBoxConstMeta,
       argValues: [that, attr],
       hint: hint,
@@ -690,15 +1450,16 @@ class OcaDartImpl implements OcaDart {
       );
 
   Future<OcaBundle> generateBundleMethodOcaBox(
-      {required OcaBox that, dynamic hint}) {
+      {required OcaBox that, OcaRegistry? registry, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_oca_box(that);
+    var arg1 = _platform.api2wire_opt_box_autoadd_oca_registry(registry);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_generate_bundle__method__OcaBox(port_, arg0),
+      callFfi: (port_) => _platform.inner
+          .wire_generate_bundle__method__OcaBox(port_, arg0, arg1),
       parseSuccessData: (d) => _wire2api_oca_bundle(d),
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kGenerateBundleMethodOcaBoxConstMeta,
-      argValues: [that],
+      argValues: [that, registry],
       hint: hint,
     ));
   }
@@ -706,59 +1467,16 @@ class OcaDartImpl implements OcaDart {
   FlutterRustBridgeTaskConstMeta get kGenerateBundleMethodOcaBoxConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
         debugName: "generate_bundle__method__OcaBox",
-        argNames: ["that"],
-      );
-
-  Future<void> addFormLayoutMethodOcaBox(
-      {required OcaBox that, required String layout, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_oca_box(that);
-    var arg1 = _platform.api2wire_String(layout);
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner
-          .wire_add_form_layout__method__OcaBox(port_, arg0, arg1),
-      parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
-      constMeta: kAddFormLayoutMethodOcaBoxConstMeta,
-      argValues: [that, layout],
-      hint: hint,
-    ));
-  }
-
-  FlutterRustBridgeTaskConstMeta get kAddFormLayoutMethodOcaBoxConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "add_form_layout__method__OcaBox",
-        argNames: ["that", "layout"],
+        argNames: ["that", "registry"],
       );
 
-  Future<void> addCredentialLayoutMethodOcaBox(
-      {required OcaBox that, required String layout, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_oca_box(that);
-    var arg1 = _platform.api2wire_String(layout);
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner
-          .wire_add_credential_layout__method__OcaBox(port_, arg0, arg1),
-      parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
-      constMeta: kAddCredentialLayoutMethodOcaBoxConstMeta,
-      argValues: [that, layout],
-      hint: hint,
-    ));
-  }
-
-  FlutterRustBridgeTaskConstMeta
-      get kAddCredentialLayoutMethodOcaBoxConstMeta =>
-          const FlutterRustBridgeTaskConstMeta(
-            debugName: "add_credential_layout__method__OcaBox",
-            argNames: ["that", "layout"],
-          );
-
   Future<OcaAttr> newStaticMethodOcaAttr({required String name, dynamic hint}) {
     var arg0 = _platform.api2wire_String(name);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) =>
           _platform.inner.wire_new__static_method__OcaAttr(port_, arg0),
       parseSuccessData: (d) => _wire2api_oca_attr(d),
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kNewStaticMethodOcaAttrConstMeta,
       argValues: [name],
       hint: hint,
@@ -779,7 +1497,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) => _platform.inner
           .wire_set_attribute_type__method__OcaAttr(port_, arg0, arg1),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kSetAttributeTypeMethodOcaAttrConstMeta,
       argValues: [that, attrType],
       hint: hint,
@@ -792,13 +1510,35 @@ class OcaDartImpl implements OcaDart {
         argNames: ["that", "attrType"],
       );
 
+  Future<void> setAttributeTypeSpellingMethodOcaAttr(
+      {required OcaAttr that, required String spelling, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attr(that);
+    var arg1 = _platform.api2wire_String(spelling);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_set_attribute_type_spelling__method__OcaAttr(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kSetAttributeTypeSpellingMethodOcaAttrConstMeta,
+      argValues: [that, spelling],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kSetAttributeTypeSpellingMethodOcaAttrConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "set_attribute_type_spelling__method__OcaAttr",
+            argNames: ["that", "spelling"],
+          );
+
   Future<void> setFlaggedMethodOcaAttr({required OcaAttr that, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_oca_attr(that);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) =>
           _platform.inner.wire_set_flagged__method__OcaAttr(port_, arg0),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kSetFlaggedMethodOcaAttrConstMeta,
       argValues: [that],
       hint: hint,
@@ -819,7 +1559,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) =>
           _platform.inner.wire_set_encoding__method__OcaAttr(port_, arg0, arg1),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kSetEncodingMethodOcaAttrConstMeta,
       argValues: [that, encoding],
       hint: hint,
@@ -840,7 +1580,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) => _platform.inner
           .wire_set_cardinality__method__OcaAttr(port_, arg0, arg1),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kSetCardinalityMethodOcaAttrConstMeta,
       argValues: [that, cardinality],
       hint: hint,
@@ -861,7 +1601,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) => _platform.inner
           .wire_set_conformance__method__OcaAttr(port_, arg0, arg1),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kSetConformanceMethodOcaAttrConstMeta,
       argValues: [that, conformance],
       hint: hint,
@@ -886,7 +1626,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) => _platform.inner
           .wire_set_label__method__OcaAttr(port_, arg0, arg1, arg2),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: _wire2api_FrbAnyhowException,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kSetLabelMethodOcaAttrConstMeta,
       argValues: [that, lang, label],
       hint: hint,
@@ -911,7 +1651,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) => _platform.inner
           .wire_set_information__method__OcaAttr(port_, arg0, arg1, arg2),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: _wire2api_FrbAnyhowException,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kSetInformationMethodOcaAttrConstMeta,
       argValues: [that, lang, information],
       hint: hint,
@@ -932,7 +1672,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) => _platform.inner
           .wire_set_entry_codes__method__OcaAttr(port_, arg0, arg1),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kSetEntryCodesMethodOcaAttrConstMeta,
       argValues: [that, entryCodes],
       hint: hint,
@@ -945,27 +1685,6 @@ class OcaDartImpl implements OcaDart {
         argNames: ["that", "entryCodes"],
       );
 
-  Future<void> setEntryCodesSaiMethodOcaAttr(
-      {required OcaAttr that, required String sai, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_oca_attr(that);
-    var arg1 = _platform.api2wire_String(sai);
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner
-          .wire_set_entry_codes_sai__method__OcaAttr(port_, arg0, arg1),
-      parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
-      constMeta: kSetEntryCodesSaiMethodOcaAttrConstMeta,
-      argValues: [that, sai],
-      hint: hint,
-    ));
-  }
-
-  FlutterRustBridgeTaskConstMeta get kSetEntryCodesSaiMethodOcaAttrConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "set_entry_codes_sai__method__OcaAttr",
-        argNames: ["that", "sai"],
-      );
-
   Future<void> setEntryMethodOcaAttr(
       {required OcaAttr that,
       required String lang,
@@ -978,7 +1697,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) => _platform.inner
           .wire_set_entry__method__OcaAttr(port_, arg0, arg1, arg2),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: _wire2api_FrbAnyhowException,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kSetEntryMethodOcaAttrConstMeta,
       argValues: [that, lang, entries],
       hint: hint,
@@ -991,45 +1710,24 @@ class OcaDartImpl implements OcaDart {
         argNames: ["that", "lang", "entries"],
       );
 
-  Future<void> setUnitMetricMethodOcaAttr(
-      {required OcaAttr that, required OcaMetricUnit unit, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_oca_attr(that);
-    var arg1 = api2wire_oca_metric_unit(unit);
-    return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner
-          .wire_set_unit_metric__method__OcaAttr(port_, arg0, arg1),
-      parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
-      constMeta: kSetUnitMetricMethodOcaAttrConstMeta,
-      argValues: [that, unit],
-      hint: hint,
-    ));
-  }
-
-  FlutterRustBridgeTaskConstMeta get kSetUnitMetricMethodOcaAttrConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "set_unit_metric__method__OcaAttr",
-        argNames: ["that", "unit"],
-      );
-
-  Future<void> setUnitImperialMethodOcaAttr(
-      {required OcaAttr that, required OcaImperialUnit unit, dynamic hint}) {
+  Future<void> setUnitMethodOcaAttr(
+      {required OcaAttr that, required String unit, dynamic hint}) {
     var arg0 = _platform.api2wire_box_autoadd_oca_attr(that);
-    var arg1 = api2wire_oca_imperial_unit(unit);
+    var arg1 = _platform.api2wire_String(unit);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) => _platform.inner
-          .wire_set_unit_imperial__method__OcaAttr(port_, arg0, arg1),
+      callFfi: (port_) =>
+          _platform.inner.wire_set_unit__method__OcaAttr(port_, arg0, arg1),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
-      constMeta: kSetUnitImperialMethodOcaAttrConstMeta,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kSetUnitMethodOcaAttrConstMeta,
       argValues: [that, unit],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kSetUnitImperialMethodOcaAttrConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kSetUnitMethodOcaAttrConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "set_unit_imperial__method__OcaAttr",
+        debugName: "set_unit__method__OcaAttr",
         argNames: ["that", "unit"],
       );
 
@@ -1041,7 +1739,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) =>
           _platform.inner.wire_set_format__method__OcaAttr(port_, arg0, arg1),
       parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kSetFormatMethodOcaAttrConstMeta,
       argValues: [that, format],
       hint: hint,
@@ -1061,7 +1759,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) =>
           _platform.inner.wire_to_json__method__OcaBundle(port_, arg0),
       parseSuccessData: _wire2api_String,
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kToJsonMethodOcaBundleConstMeta,
       argValues: [that],
       hint: hint,
@@ -1080,7 +1778,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) =>
           _platform.inner.wire_said__method__OcaBundle(port_, arg0),
       parseSuccessData: _wire2api_String,
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kSaidMethodOcaBundleConstMeta,
       argValues: [that],
       hint: hint,
@@ -1100,7 +1798,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) =>
           _platform.inner.wire_capture_base__method__OcaBundle(port_, arg0),
       parseSuccessData: (d) => _wire2api_oca_capture_base(d),
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kCaptureBaseMethodOcaBundleConstMeta,
       argValues: [that],
       hint: hint,
@@ -1120,7 +1818,7 @@ class OcaDartImpl implements OcaDart {
       callFfi: (port_) =>
           _platform.inner.wire_overlays__method__OcaBundle(port_, arg0),
       parseSuccessData: _wire2api_list_oca_overlay,
-      parseErrorData: null,
+      parseErrorData: _wire2api_oca_exception,
       constMeta: kOverlaysMethodOcaBundleConstMeta,
       argValues: [that],
       hint: hint,
@@ -1133,1198 +1831,2944 @@ class OcaDartImpl implements OcaDart {
         argNames: ["that"],
       );
 
-  Future<OcaMap> attributesMethodOcaCaptureBase(
-      {required OcaCaptureBase that, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_oca_capture_base(that);
+  Future<List<String>> languagesMethodOcaBundle(
+      {required OcaBundle that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_bundle(that);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) =>
-          _platform.inner.wire_attributes__method__OcaCaptureBase(port_, arg0),
-      parseSuccessData: (d) => _wire2api_oca_map(d),
-      parseErrorData: null,
-      constMeta: kAttributesMethodOcaCaptureBaseConstMeta,
+          _platform.inner.wire_languages__method__OcaBundle(port_, arg0),
+      parseSuccessData: _wire2api_StringList,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kLanguagesMethodOcaBundleConstMeta,
       argValues: [that],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kAttributesMethodOcaCaptureBaseConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kLanguagesMethodOcaBundleConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "attributes__method__OcaCaptureBase",
+        debugName: "languages__method__OcaBundle",
         argNames: ["that"],
       );
 
-  Future<List<String>> flaggedAttributesMethodOcaCaptureBase(
-      {required OcaCaptureBase that, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_oca_capture_base(that);
+  Future<OcaBundle> keepLanguagesMethodOcaBundle(
+      {required OcaBundle that, required List<String> keep, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_bundle(that);
+    var arg1 = _platform.api2wire_StringList(keep);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) => _platform.inner
-          .wire_flagged_attributes__method__OcaCaptureBase(port_, arg0),
-      parseSuccessData: _wire2api_StringList,
-      parseErrorData: null,
-      constMeta: kFlaggedAttributesMethodOcaCaptureBaseConstMeta,
-      argValues: [that],
+          .wire_keep_languages__method__OcaBundle(port_, arg0, arg1),
+      parseSuccessData: (d) => _wire2api_oca_bundle(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kKeepLanguagesMethodOcaBundleConstMeta,
+      argValues: [that, keep],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kKeepLanguagesMethodOcaBundleConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "keep_languages__method__OcaBundle",
+        argNames: ["that", "keep"],
+      );
+
+  Future<String> extractTranslationMethodOcaBundle(
+      {required OcaBundle that, required String language, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_bundle(that);
+    var arg1 = _platform.api2wire_String(language);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_extract_translation__method__OcaBundle(port_, arg0, arg1),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kExtractTranslationMethodOcaBundleConstMeta,
+      argValues: [that, language],
       hint: hint,
     ));
   }
 
   FlutterRustBridgeTaskConstMeta
-      get kFlaggedAttributesMethodOcaCaptureBaseConstMeta =>
+      get kExtractTranslationMethodOcaBundleConstMeta =>
           const FlutterRustBridgeTaskConstMeta(
-            debugName: "flagged_attributes__method__OcaCaptureBase",
-            argNames: ["that"],
+            debugName: "extract_translation__method__OcaBundle",
+            argNames: ["that", "language"],
           );
 
-  Future<OcaMap> newStaticMethodOcaMap({dynamic hint}) {
+  Future<OcaBundle> mergeTranslationMethodOcaBundle(
+      {required OcaBundle that, required String translation, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_bundle(that);
+    var arg1 = _platform.api2wire_String(translation);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_new__static_method__OcaMap(port_),
-      parseSuccessData: (d) => _wire2api_oca_map(d),
-      parseErrorData: null,
-      constMeta: kNewStaticMethodOcaMapConstMeta,
-      argValues: [],
+      callFfi: (port_) => _platform.inner
+          .wire_merge_translation__method__OcaBundle(port_, arg0, arg1),
+      parseSuccessData: (d) => _wire2api_oca_bundle(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kMergeTranslationMethodOcaBundleConstMeta,
+      argValues: [that, translation],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kNewStaticMethodOcaMapConstMeta =>
-      const FlutterRustBridgeTaskConstMeta(
-        debugName: "new__static_method__OcaMap",
-        argNames: [],
-      );
+  FlutterRustBridgeTaskConstMeta
+      get kMergeTranslationMethodOcaBundleConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "merge_translation__method__OcaBundle",
+            argNames: ["that", "translation"],
+          );
 
-  Future<void> insertMethodOcaMap(
-      {required OcaMap that,
-      required String key,
-      required String value,
-      dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_oca_map(that);
-    var arg1 = _platform.api2wire_String(key);
-    var arg2 = _platform.api2wire_String(value);
+  Future<List<OcaAttributeDescriptor>> attributesMethodOcaBundle(
+      {required OcaBundle that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_bundle(that);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) =>
-          _platform.inner.wire_insert__method__OcaMap(port_, arg0, arg1, arg2),
-      parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
-      constMeta: kInsertMethodOcaMapConstMeta,
-      argValues: [that, key, value],
+          _platform.inner.wire_attributes__method__OcaBundle(port_, arg0),
+      parseSuccessData: _wire2api_list_oca_attribute_descriptor,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kAttributesMethodOcaBundleConstMeta,
+      argValues: [that],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kInsertMethodOcaMapConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kAttributesMethodOcaBundleConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "insert__method__OcaMap",
-        argNames: ["that", "key", "value"],
+        debugName: "attributes__method__OcaBundle",
+        argNames: ["that"],
       );
 
-  Future<String?> getMethodOcaMap(
-      {required OcaMap that, required String key, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_oca_map(that);
-    var arg1 = _platform.api2wire_String(key);
+  Future<List<String>> upgradeNotesMethodOcaBundle(
+      {required OcaBundle that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_bundle(that);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) =>
-          _platform.inner.wire_get__method__OcaMap(port_, arg0, arg1),
-      parseSuccessData: _wire2api_opt_String,
-      parseErrorData: null,
-      constMeta: kGetMethodOcaMapConstMeta,
-      argValues: [that, key],
+          _platform.inner.wire_upgrade_notes__method__OcaBundle(port_, arg0),
+      parseSuccessData: _wire2api_StringList,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kUpgradeNotesMethodOcaBundleConstMeta,
+      argValues: [that],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kGetMethodOcaMapConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kUpgradeNotesMethodOcaBundleConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "get__method__OcaMap",
-        argNames: ["that", "key"],
+        debugName: "upgrade_notes__method__OcaBundle",
+        argNames: ["that"],
       );
 
-  Future<void> removeMethodOcaMap(
-      {required OcaMap that, required String key, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_oca_map(that);
-    var arg1 = _platform.api2wire_String(key);
+  Future<List<OcaSemanticIssue>> validateSemanticsMethodOcaBundle(
+      {required OcaBundle that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_bundle(that);
     return _platform.executeNormal(FlutterRustBridgeTask(
-      callFfi: (port_) =>
-          _platform.inner.wire_remove__method__OcaMap(port_, arg0, arg1),
-      parseSuccessData: _wire2api_unit,
-      parseErrorData: null,
-      constMeta: kRemoveMethodOcaMapConstMeta,
-      argValues: [that, key],
+      callFfi: (port_) => _platform.inner
+          .wire_validate_semantics__method__OcaBundle(port_, arg0),
+      parseSuccessData: _wire2api_list_oca_semantic_issue,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kValidateSemanticsMethodOcaBundleConstMeta,
+      argValues: [that],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kRemoveMethodOcaMapConstMeta =>
+  FlutterRustBridgeTaskConstMeta
+      get kValidateSemanticsMethodOcaBundleConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "validate_semantics__method__OcaBundle",
+            argNames: ["that"],
+          );
+
+  Future<List<OcaDataError>> validateDataMethodOcaBundle(
+      {required OcaBundle that, required String record, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_bundle(that);
+    var arg1 = _platform.api2wire_String(record);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_validate_data__method__OcaBundle(port_, arg0, arg1),
+      parseSuccessData: _wire2api_list_oca_data_error,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kValidateDataMethodOcaBundleConstMeta,
+      argValues: [that, record],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kValidateDataMethodOcaBundleConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "remove__method__OcaMap",
-        argNames: ["that", "key"],
+        debugName: "validate_data__method__OcaBundle",
+        argNames: ["that", "record"],
       );
 
-  Future<List<String>> getKeysMethodOcaMap(
-      {required OcaMap that, dynamic hint}) {
-    var arg0 = _platform.api2wire_box_autoadd_oca_map(that);
+  Future<OcaMap> attributesMethodOcaCaptureBase(
+      {required OcaCaptureBase that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_capture_base(that);
     return _platform.executeNormal(FlutterRustBridgeTask(
       callFfi: (port_) =>
-          _platform.inner.wire_get_keys__method__OcaMap(port_, arg0),
-      parseSuccessData: _wire2api_StringList,
-      parseErrorData: null,
-      constMeta: kGetKeysMethodOcaMapConstMeta,
+          _platform.inner.wire_attributes__method__OcaCaptureBase(port_, arg0),
+      parseSuccessData: (d) => _wire2api_oca_map(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kAttributesMethodOcaCaptureBaseConstMeta,
       argValues: [that],
       hint: hint,
     ));
   }
 
-  FlutterRustBridgeTaskConstMeta get kGetKeysMethodOcaMapConstMeta =>
+  FlutterRustBridgeTaskConstMeta get kAttributesMethodOcaCaptureBaseConstMeta =>
       const FlutterRustBridgeTaskConstMeta(
-        debugName: "get_keys__method__OcaMap",
+        debugName: "attributes__method__OcaCaptureBase",
         argNames: ["that"],
       );
 
-  DropFnType get dropOpaqueMutexDynOverlay =>
-      _platform.inner.drop_opaque_MutexDynOverlay;
-  ShareFnType get shareOpaqueMutexDynOverlay =>
-      _platform.inner.share_opaque_MutexDynOverlay;
-  OpaqueTypeFinalizer get MutexDynOverlayFinalizer =>
-      _platform.MutexDynOverlayFinalizer;
+  Future<List<String>> flaggedAttributesMethodOcaCaptureBase(
+      {required OcaCaptureBase that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_capture_base(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_flagged_attributes__method__OcaCaptureBase(port_, arg0),
+      parseSuccessData: _wire2api_StringList,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kFlaggedAttributesMethodOcaCaptureBaseConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
 
-  DropFnType get dropOpaqueMutexOcaAttrRaw =>
-      _platform.inner.drop_opaque_MutexOcaAttrRaw;
-  ShareFnType get shareOpaqueMutexOcaAttrRaw =>
-      _platform.inner.share_opaque_MutexOcaAttrRaw;
-  OpaqueTypeFinalizer get MutexOcaAttrRawFinalizer =>
-      _platform.MutexOcaAttrRawFinalizer;
+  FlutterRustBridgeTaskConstMeta
+      get kFlaggedAttributesMethodOcaCaptureBaseConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "flagged_attributes__method__OcaCaptureBase",
+            argNames: ["that"],
+          );
 
-  DropFnType get dropOpaqueMutexOcaBoxRaw =>
-      _platform.inner.drop_opaque_MutexOcaBoxRaw;
-  ShareFnType get shareOpaqueMutexOcaBoxRaw =>
-      _platform.inner.share_opaque_MutexOcaBoxRaw;
-  OpaqueTypeFinalizer get MutexOcaBoxRawFinalizer =>
-      _platform.MutexOcaBoxRawFinalizer;
+  Future<String> overlayTypeMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_overlay_type__method__OcaOverlay(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kOverlayTypeMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
 
-  DropFnType get dropOpaqueMutexOcaBundleRaw =>
-      _platform.inner.drop_opaque_MutexOcaBundleRaw;
-  ShareFnType get shareOpaqueMutexOcaBundleRaw =>
-      _platform.inner.share_opaque_MutexOcaBundleRaw;
-  OpaqueTypeFinalizer get MutexOcaBundleRawFinalizer =>
-      _platform.MutexOcaBundleRawFinalizer;
+  FlutterRustBridgeTaskConstMeta get kOverlayTypeMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "overlay_type__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-  DropFnType get dropOpaqueMutexOcaCaptureBaseRaw =>
-      _platform.inner.drop_opaque_MutexOcaCaptureBaseRaw;
-  ShareFnType get shareOpaqueMutexOcaCaptureBaseRaw =>
-      _platform.inner.share_opaque_MutexOcaCaptureBaseRaw;
-  OpaqueTypeFinalizer get MutexOcaCaptureBaseRawFinalizer =>
-      _platform.MutexOcaCaptureBaseRawFinalizer;
+  Future<String?> nameMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_name__method__OcaOverlay(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kNameMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
 
-  DropFnType get dropOpaqueMutexStringMap =>
-      _platform.inner.drop_opaque_MutexStringMap;
-  ShareFnType get shareOpaqueMutexStringMap =>
-      _platform.inner.share_opaque_MutexStringMap;
-  OpaqueTypeFinalizer get MutexStringMapFinalizer =>
-      _platform.MutexStringMapFinalizer;
+  FlutterRustBridgeTaskConstMeta get kNameMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "name__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-  void dispose() {
-    _platform.dispose();
+  Future<String?> languageMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_language__method__OcaOverlay(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kLanguageMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
-// Section: wire2api
 
-  FrbAnyhowException _wire2api_FrbAnyhowException(dynamic raw) {
-    return FrbAnyhowException(raw as String);
-  }
+  FlutterRustBridgeTaskConstMeta get kLanguageMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "language__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-  MutexDynOverlay _wire2api_MutexDynOverlay(dynamic raw) {
-    return MutexDynOverlay.fromRaw(raw[0], raw[1], this);
+  Future<String?> saidMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_said__method__OcaOverlay(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kSaidMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  MutexOcaAttrRaw _wire2api_MutexOcaAttrRaw(dynamic raw) {
-    return MutexOcaAttrRaw.fromRaw(raw[0], raw[1], this);
-  }
+  FlutterRustBridgeTaskConstMeta get kSaidMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "said__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-  MutexOcaBoxRaw _wire2api_MutexOcaBoxRaw(dynamic raw) {
-    return MutexOcaBoxRaw.fromRaw(raw[0], raw[1], this);
+  Future<String?> captureBaseMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_capture_base__method__OcaOverlay(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kCaptureBaseMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  MutexOcaBundleRaw _wire2api_MutexOcaBundleRaw(dynamic raw) {
-    return MutexOcaBundleRaw.fromRaw(raw[0], raw[1], this);
-  }
+  FlutterRustBridgeTaskConstMeta get kCaptureBaseMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "capture_base__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-  MutexOcaCaptureBaseRaw _wire2api_MutexOcaCaptureBaseRaw(dynamic raw) {
-    return MutexOcaCaptureBaseRaw.fromRaw(raw[0], raw[1], this);
+  Future<String> toJsonMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_to_json__method__OcaOverlay(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kToJsonMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  MutexStringMap _wire2api_MutexStringMap(dynamic raw) {
-    return MutexStringMap.fromRaw(raw[0], raw[1], this);
-  }
+  FlutterRustBridgeTaskConstMeta get kToJsonMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "to_json__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-  String _wire2api_String(dynamic raw) {
-    return raw as String;
+  Future<OcaMap> labelsMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_labels__method__OcaOverlay(port_, arg0),
+      parseSuccessData: (d) => _wire2api_oca_map(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kLabelsMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  List<String> _wire2api_StringList(dynamic raw) {
-    return (raw as List<dynamic>).cast<String>();
-  }
+  FlutterRustBridgeTaskConstMeta get kLabelsMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "labels__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-  List<OcaOverlay> _wire2api_list_oca_overlay(dynamic raw) {
-    return (raw as List<dynamic>).map(_wire2api_oca_overlay).toList();
+  Future<OcaMap?> entriesMethodOcaOverlay(
+      {required OcaOverlay that, required String attribute, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    var arg1 = _platform.api2wire_String(attribute);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_entries__method__OcaOverlay(port_, arg0, arg1),
+      parseSuccessData: _wire2api_opt_box_autoadd_oca_map,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kEntriesMethodOcaOverlayConstMeta,
+      argValues: [that, attribute],
+      hint: hint,
+    ));
   }
 
-  OcaAttr _wire2api_oca_attr(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return OcaAttr(
-      bridge: this,
-      field0: _wire2api_MutexOcaAttrRaw(arr[0]),
-    );
-  }
+  FlutterRustBridgeTaskConstMeta get kEntriesMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "entries__method__OcaOverlay",
+        argNames: ["that", "attribute"],
+      );
 
-  OcaBox _wire2api_oca_box(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return OcaBox(
-      bridge: this,
-      field0: _wire2api_MutexOcaBoxRaw(arr[0]),
-    );
+  Future<List<String>?> entryCodesMethodOcaOverlay(
+      {required OcaOverlay that, required String attribute, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    var arg1 = _platform.api2wire_String(attribute);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_entry_codes__method__OcaOverlay(port_, arg0, arg1),
+      parseSuccessData: _wire2api_opt_StringList,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kEntryCodesMethodOcaOverlayConstMeta,
+      argValues: [that, attribute],
+      hint: hint,
+    ));
   }
 
-  OcaBundle _wire2api_oca_bundle(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return OcaBundle(
-      bridge: this,
-      field0: _wire2api_MutexOcaBundleRaw(arr[0]),
-    );
-  }
+  FlutterRustBridgeTaskConstMeta get kEntryCodesMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "entry_codes__method__OcaOverlay",
+        argNames: ["that", "attribute"],
+      );
 
-  OcaCaptureBase _wire2api_oca_capture_base(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return OcaCaptureBase(
-      bridge: this,
-      field0: _wire2api_MutexOcaCaptureBaseRaw(arr[0]),
-    );
+  Future<OcaMap> formatsMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_formats__method__OcaOverlay(port_, arg0),
+      parseSuccessData: (d) => _wire2api_oca_map(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kFormatsMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  OcaMap _wire2api_oca_map(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return OcaMap(
-      bridge: this,
-      field0: _wire2api_MutexStringMap(arr[0]),
-    );
-  }
+  FlutterRustBridgeTaskConstMeta get kFormatsMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "formats__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-  OcaOverlay _wire2api_oca_overlay(dynamic raw) {
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return OcaOverlay(
-      field0: _wire2api_MutexDynOverlay(arr[0]),
-    );
+  Future<OcaMap> unitsMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_units__method__OcaOverlay(port_, arg0),
+      parseSuccessData: (d) => _wire2api_oca_map(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kUnitsMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  String? _wire2api_opt_String(dynamic raw) {
-    return raw == null ? null : _wire2api_String(raw);
-  }
+  FlutterRustBridgeTaskConstMeta get kUnitsMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "units__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-  int _wire2api_u8(dynamic raw) {
-    return raw as int;
+  Future<OcaMap> conformancesMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_conformances__method__OcaOverlay(port_, arg0),
+      parseSuccessData: (d) => _wire2api_oca_map(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kConformancesMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  Uint8List _wire2api_uint_8_list(dynamic raw) {
-    return raw as Uint8List;
-  }
+  FlutterRustBridgeTaskConstMeta get kConformancesMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "conformances__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-  void _wire2api_unit(dynamic raw) {
-    return;
+  Future<OcaMap> cardinalitiesMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_cardinalities__method__OcaOverlay(port_, arg0),
+      parseSuccessData: (d) => _wire2api_oca_map(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kCardinalitiesMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
-}
-
-// Section: api2wire
 
-@protected
-int api2wire_i32(int raw) {
-  return raw;
-}
+  FlutterRustBridgeTaskConstMeta get kCardinalitiesMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "cardinalities__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-@protected
-int api2wire_oca_attr_type(OcaAttrType raw) {
-  return api2wire_i32(raw.index);
-}
+  Future<OcaMap> metaMethodOcaOverlay(
+      {required OcaOverlay that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_overlay(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_meta__method__OcaOverlay(port_, arg0),
+      parseSuccessData: (d) => _wire2api_oca_map(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kMetaMethodOcaOverlayConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
 
-@protected
-int api2wire_oca_encoding(OcaEncoding raw) {
-  return api2wire_i32(raw.index);
-}
+  FlutterRustBridgeTaskConstMeta get kMetaMethodOcaOverlayConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "meta__method__OcaOverlay",
+        argNames: ["that"],
+      );
 
-@protected
-int api2wire_oca_imperial_unit(OcaImperialUnit raw) {
-  return api2wire_i32(raw.index);
-}
+  Future<String> nameMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_name__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kNameMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
 
-@protected
-int api2wire_oca_metric_unit(OcaMetricUnit raw) {
-  return api2wire_i32(raw.index);
-}
+  FlutterRustBridgeTaskConstMeta
+      get kNameMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "name__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
 
-@protected
-int api2wire_u8(int raw) {
-  return raw;
-}
+  Future<String?> attributeTypeMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_attribute_type__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kAttributeTypeMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
 
-// Section: finalizer
+  FlutterRustBridgeTaskConstMeta
+      get kAttributeTypeMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "attribute_type__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
 
-class OcaDartPlatform extends FlutterRustBridgeBase<OcaDartWire> {
-  OcaDartPlatform(ffi.DynamicLibrary dylib) : super(OcaDartWire(dylib));
+  Future<OcaMap> labelsMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_labels__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: (d) => _wire2api_oca_map(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kLabelsMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
 
-// Section: api2wire
+  FlutterRustBridgeTaskConstMeta
+      get kLabelsMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "labels__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
 
-  @protected
-  wire_MutexOcaAttrRaw api2wire_MutexOcaAttrRaw(MutexOcaAttrRaw raw) {
-    final ptr = inner.new_MutexOcaAttrRaw();
-    _api_fill_to_wire_MutexOcaAttrRaw(raw, ptr);
-    return ptr;
+  Future<OcaMap> informationMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_information__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: (d) => _wire2api_oca_map(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kInformationMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  @protected
-  wire_MutexOcaBoxRaw api2wire_MutexOcaBoxRaw(MutexOcaBoxRaw raw) {
-    final ptr = inner.new_MutexOcaBoxRaw();
-    _api_fill_to_wire_MutexOcaBoxRaw(raw, ptr);
-    return ptr;
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kInformationMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "information__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
 
-  @protected
-  wire_MutexOcaBundleRaw api2wire_MutexOcaBundleRaw(MutexOcaBundleRaw raw) {
-    final ptr = inner.new_MutexOcaBundleRaw();
-    _api_fill_to_wire_MutexOcaBundleRaw(raw, ptr);
-    return ptr;
+  Future<String?> conformanceMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_conformance__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kConformanceMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  @protected
-  wire_MutexOcaCaptureBaseRaw api2wire_MutexOcaCaptureBaseRaw(
-      MutexOcaCaptureBaseRaw raw) {
-    final ptr = inner.new_MutexOcaCaptureBaseRaw();
-    _api_fill_to_wire_MutexOcaCaptureBaseRaw(raw, ptr);
-    return ptr;
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kConformanceMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "conformance__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
 
-  @protected
-  wire_MutexStringMap api2wire_MutexStringMap(MutexStringMap raw) {
-    final ptr = inner.new_MutexStringMap();
-    _api_fill_to_wire_MutexStringMap(raw, ptr);
-    return ptr;
+  Future<String?> cardinalityMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_cardinality__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kCardinalityMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  @protected
-  ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
-    return api2wire_uint_8_list(utf8.encoder.convert(raw));
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kCardinalityMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "cardinality__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
 
-  @protected
-  ffi.Pointer<wire_StringList> api2wire_StringList(List<String> raw) {
-    final ans = inner.new_StringList_0(raw.length);
-    for (var i = 0; i < raw.length; i++) {
-      ans.ref.ptr[i] = api2wire_String(raw[i]);
-    }
-    return ans;
+  Future<String?> formatMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_format__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kFormatMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  @protected
-  ffi.Pointer<wire_OcaAttr> api2wire_box_autoadd_oca_attr(OcaAttr raw) {
-    final ptr = inner.new_box_autoadd_oca_attr_0();
-    _api_fill_to_wire_oca_attr(raw, ptr.ref);
-    return ptr;
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kFormatMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "format__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
 
-  @protected
-  ffi.Pointer<wire_OcaBox> api2wire_box_autoadd_oca_box(OcaBox raw) {
-    final ptr = inner.new_box_autoadd_oca_box_0();
-    _api_fill_to_wire_oca_box(raw, ptr.ref);
-    return ptr;
+  Future<String?> unitMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_unit__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kUnitMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  @protected
-  ffi.Pointer<wire_OcaBundle> api2wire_box_autoadd_oca_bundle(OcaBundle raw) {
-    final ptr = inner.new_box_autoadd_oca_bundle_0();
-    _api_fill_to_wire_oca_bundle(raw, ptr.ref);
-    return ptr;
-  }
+  FlutterRustBridgeTaskConstMeta
+      get kUnitMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "unit__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
 
-  @protected
-  ffi.Pointer<wire_OcaCaptureBase> api2wire_box_autoadd_oca_capture_base(
-      OcaCaptureBase raw) {
-    final ptr = inner.new_box_autoadd_oca_capture_base_0();
-    _api_fill_to_wire_oca_capture_base(raw, ptr.ref);
-    return ptr;
+  Future<String?> characterEncodingMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_character_encoding__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kCharacterEncodingMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
   }
 
-  @protected
-  ffi.Pointer<wire_OcaMap> api2wire_box_autoadd_oca_map(OcaMap raw) {
+  FlutterRustBridgeTaskConstMeta
+      get kCharacterEncodingMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "character_encoding__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
+
+  Future<String?> standardMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_standard__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kStandardMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kStandardMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "standard__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
+
+  Future<List<String>?> entryCodesMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_entry_codes__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: _wire2api_opt_StringList,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kEntryCodesMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kEntryCodesMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "entry_codes__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
+
+  Future<OcaMap?> entriesMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that,
+      required String lang,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    var arg1 = _platform.api2wire_String(lang);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_entries__method__OcaAttributeDescriptor(port_, arg0, arg1),
+      parseSuccessData: _wire2api_opt_box_autoadd_oca_map,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kEntriesMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that, lang],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kEntriesMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "entries__method__OcaAttributeDescriptor",
+            argNames: ["that", "lang"],
+          );
+
+  Future<bool> sensitiveMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_sensitive__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: _wire2api_bool,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kSensitiveMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kSensitiveMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "sensitive__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
+
+  Future<String> toJsonMethodOcaAttributeDescriptor(
+      {required OcaAttributeDescriptor that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_attribute_descriptor(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) => _platform.inner
+          .wire_to_json__method__OcaAttributeDescriptor(port_, arg0),
+      parseSuccessData: _wire2api_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kToJsonMethodOcaAttributeDescriptorConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta
+      get kToJsonMethodOcaAttributeDescriptorConstMeta =>
+          const FlutterRustBridgeTaskConstMeta(
+            debugName: "to_json__method__OcaAttributeDescriptor",
+            argNames: ["that"],
+          );
+
+  Future<OcaMap> newStaticMethodOcaMap({dynamic hint}) {
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_new__static_method__OcaMap(port_),
+      parseSuccessData: (d) => _wire2api_oca_map(d),
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kNewStaticMethodOcaMapConstMeta,
+      argValues: [],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kNewStaticMethodOcaMapConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "new__static_method__OcaMap",
+        argNames: [],
+      );
+
+  Future<void> insertMethodOcaMap(
+      {required OcaMap that,
+      required String key,
+      required String value,
+      dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_map(that);
+    var arg1 = _platform.api2wire_String(key);
+    var arg2 = _platform.api2wire_String(value);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_insert__method__OcaMap(port_, arg0, arg1, arg2),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kInsertMethodOcaMapConstMeta,
+      argValues: [that, key, value],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kInsertMethodOcaMapConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "insert__method__OcaMap",
+        argNames: ["that", "key", "value"],
+      );
+
+  Future<String?> getMethodOcaMap(
+      {required OcaMap that, required String key, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_map(that);
+    var arg1 = _platform.api2wire_String(key);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_get__method__OcaMap(port_, arg0, arg1),
+      parseSuccessData: _wire2api_opt_String,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kGetMethodOcaMapConstMeta,
+      argValues: [that, key],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGetMethodOcaMapConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "get__method__OcaMap",
+        argNames: ["that", "key"],
+      );
+
+  Future<void> removeMethodOcaMap(
+      {required OcaMap that, required String key, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_map(that);
+    var arg1 = _platform.api2wire_String(key);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_remove__method__OcaMap(port_, arg0, arg1),
+      parseSuccessData: _wire2api_unit,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kRemoveMethodOcaMapConstMeta,
+      argValues: [that, key],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kRemoveMethodOcaMapConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "remove__method__OcaMap",
+        argNames: ["that", "key"],
+      );
+
+  Future<List<String>> getKeysMethodOcaMap(
+      {required OcaMap that, dynamic hint}) {
+    var arg0 = _platform.api2wire_box_autoadd_oca_map(that);
+    return _platform.executeNormal(FlutterRustBridgeTask(
+      callFfi: (port_) =>
+          _platform.inner.wire_get_keys__method__OcaMap(port_, arg0),
+      parseSuccessData: _wire2api_StringList,
+      parseErrorData: _wire2api_oca_exception,
+      constMeta: kGetKeysMethodOcaMapConstMeta,
+      argValues: [that],
+      hint: hint,
+    ));
+  }
+
+  FlutterRustBridgeTaskConstMeta get kGetKeysMethodOcaMapConstMeta =>
+      const FlutterRustBridgeTaskConstMeta(
+        debugName: "get_keys__method__OcaMap",
+        argNames: ["that"],
+      );
+
+  DropFnType get dropOpaqueMutexOcaAttrRaw =>
+      _platform.inner.drop_opaque_MutexOcaAttrRaw;
+  ShareFnType get shareOpaqueMutexOcaAttrRaw =>
+      _platform.inner.share_opaque_MutexOcaAttrRaw;
+  OpaqueTypeFinalizer get MutexOcaAttrRawFinalizer =>
+      _platform.MutexOcaAttrRawFinalizer;
+
+  DropFnType get dropOpaqueMutexOcaAttributeDescriptorRaw =>
+      _platform.inner.drop_opaque_MutexOcaAttributeDescriptorRaw;
+  ShareFnType get shareOpaqueMutexOcaAttributeDescriptorRaw =>
+      _platform.inner.share_opaque_MutexOcaAttributeDescriptorRaw;
+  OpaqueTypeFinalizer get MutexOcaAttributeDescriptorRawFinalizer =>
+      _platform.MutexOcaAttributeDescriptorRawFinalizer;
+
+  DropFnType get dropOpaqueMutexOcaBoxRaw =>
+      _platform.inner.drop_opaque_MutexOcaBoxRaw;
+  ShareFnType get shareOpaqueMutexOcaBoxRaw =>
+      _platform.inner.share_opaque_MutexOcaBoxRaw;
+  OpaqueTypeFinalizer get MutexOcaBoxRawFinalizer =>
+      _platform.MutexOcaBoxRawFinalizer;
+
+  DropFnType get dropOpaqueMutexOcaBundleRaw =>
+      _platform.inner.drop_opaque_MutexOcaBundleRaw;
+  ShareFnType get shareOpaqueMutexOcaBundleRaw =>
+      _platform.inner.share_opaque_MutexOcaBundleRaw;
+  OpaqueTypeFinalizer get MutexOcaBundleRawFinalizer =>
+      _platform.MutexOcaBundleRawFinalizer;
+
+  DropFnType get dropOpaqueMutexOcaCaptureBaseRaw =>
+      _platform.inner.drop_opaque_MutexOcaCaptureBaseRaw;
+  ShareFnType get shareOpaqueMutexOcaCaptureBaseRaw =>
+      _platform.inner.share_opaque_MutexOcaCaptureBaseRaw;
+  OpaqueTypeFinalizer get MutexOcaCaptureBaseRawFinalizer =>
+      _platform.MutexOcaCaptureBaseRawFinalizer;
+
+  DropFnType get dropOpaqueMutexOcaOverlayRaw =>
+      _platform.inner.drop_opaque_MutexOcaOverlayRaw;
+  ShareFnType get shareOpaqueMutexOcaOverlayRaw =>
+      _platform.inner.share_opaque_MutexOcaOverlayRaw;
+  OpaqueTypeFinalizer get MutexOcaOverlayRawFinalizer =>
+      _platform.MutexOcaOverlayRawFinalizer;
+
+  DropFnType get dropOpaqueMutexStringMap =>
+      _platform.inner.drop_opaque_MutexStringMap;
+  ShareFnType get shareOpaqueMutexStringMap =>
+      _platform.inner.share_opaque_MutexStringMap;
+  OpaqueTypeFinalizer get MutexStringMapFinalizer =>
+      _platform.MutexStringMapFinalizer;
+
+  DropFnType get dropOpaqueOcaRegistryRaw =>
+      _platform.inner.drop_opaque_OcaRegistryRaw;
+  ShareFnType get shareOpaqueOcaRegistryRaw =>
+      _platform.inner.share_opaque_OcaRegistryRaw;
+  OpaqueTypeFinalizer get OcaRegistryRawFinalizer =>
+      _platform.OcaRegistryRawFinalizer;
+
+  void dispose() {
+    _platform.dispose();
+  }
+// Section: wire2api
+
+  MutexOcaAttrRaw _wire2api_MutexOcaAttrRaw(dynamic raw) {
+    return MutexOcaAttrRaw.fromRaw(raw[0], raw[1], this);
+  }
+
+  MutexOcaAttributeDescriptorRaw _wire2api_MutexOcaAttributeDescriptorRaw(dynamic raw) {
+    return MutexOcaAttributeDescriptorRaw.fromRaw(raw[0], raw[1], this);
+  }
+
+  MutexOcaBoxRaw _wire2api_MutexOcaBoxRaw(dynamic raw) {
+    return MutexOcaBoxRaw.fromRaw(raw[0], raw[1], this);
+  }
+
+  MutexOcaBundleRaw _wire2api_MutexOcaBundleRaw(dynamic raw) {
+    return MutexOcaBundleRaw.fromRaw(raw[0], raw[1], this);
+  }
+
+  MutexOcaCaptureBaseRaw _wire2api_MutexOcaCaptureBaseRaw(dynamic raw) {
+    return MutexOcaCaptureBaseRaw.fromRaw(raw[0], raw[1], this);
+  }
+
+  MutexOcaOverlayRaw _wire2api_MutexOcaOverlayRaw(dynamic raw) {
+    return MutexOcaOverlayRaw.fromRaw(raw[0], raw[1], this);
+  }
+
+  MutexStringMap _wire2api_MutexStringMap(dynamic raw) {
+    return MutexStringMap.fromRaw(raw[0], raw[1], this);
+  }
+
+  OcaRegistryRaw _wire2api_OcaRegistryRaw(dynamic raw) {
+    return OcaRegistryRaw.fromRaw(raw[0], raw[1], this);
+  }
+
+  String _wire2api_String(dynamic raw) {
+    return raw as String;
+  }
+
+  List<String> _wire2api_StringList(dynamic raw) {
+    return (raw as List<dynamic>).cast<String>();
+  }
+
+  bool _wire2api_bool(dynamic raw) {
+    return raw as bool;
+  }
+
+  OcaMap _wire2api_box_autoadd_oca_map(dynamic raw) {
+    return _wire2api_oca_map(raw);
+  }
+
+  int _wire2api_i32(dynamic raw) {
+    return raw as int;
+  }
+
+  List<OcaAttributeDescriptor> _wire2api_list_oca_attribute_descriptor(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_oca_attribute_descriptor).toList();
+  }
+
+  List<OcaDataError> _wire2api_list_oca_data_error(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_oca_data_error).toList();
+  }
+
+  List<OcaOverlay> _wire2api_list_oca_overlay(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_oca_overlay).toList();
+  }
+
+  List<OcaSaidVerification> _wire2api_list_oca_said_verification(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_oca_said_verification).toList();
+  }
+
+  List<OcaSemanticIssue> _wire2api_list_oca_semantic_issue(dynamic raw) {
+    return (raw as List<dynamic>).map(_wire2api_oca_semantic_issue).toList();
+  }
+
+  OcaAttr _wire2api_oca_attr(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return OcaAttr(
+      bridge: this,
+      field0: _wire2api_MutexOcaAttrRaw(arr[0]),
+    );
+  }
+
+  OcaAttributeDescriptor _wire2api_oca_attribute_descriptor(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return OcaAttributeDescriptor(
+      bridge: this,
+      field0: _wire2api_MutexOcaAttributeDescriptorRaw(arr[0]),
+    );
+  }
+
+  OcaBox _wire2api_oca_box(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return OcaBox(
+      bridge: this,
+      field0: _wire2api_MutexOcaBoxRaw(arr[0]),
+    );
+  }
+
+  OcaBundle _wire2api_oca_bundle(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return OcaBundle(
+      bridge: this,
+      field0: _wire2api_MutexOcaBundleRaw(arr[0]),
+    );
+  }
+
+  OcaCaptureBase _wire2api_oca_capture_base(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return OcaCaptureBase(
+      bridge: this,
+      field0: _wire2api_MutexOcaCaptureBaseRaw(arr[0]),
+    );
+  }
+
+  OcaDataError _wire2api_oca_data_error(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return OcaDataError(
+      attribute: _wire2api_opt_String(arr[0]),
+      path: _wire2api_String(arr[1]),
+      rule: _wire2api_oca_data_rule(arr[2]),
+      expected: _wire2api_opt_String(arr[3]),
+      actual: _wire2api_opt_String(arr[4]),
+      message: _wire2api_String(arr[5]),
+    );
+  }
+
+  OcaDataRule _wire2api_oca_data_rule(dynamic raw) {
+    return OcaDataRule.values[raw as int];
+  }
+
+  OcaErrorKind _wire2api_oca_error_kind(dynamic raw) {
+    return OcaErrorKind.values[raw as int];
+  }
+
+  OcaException _wire2api_oca_exception(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OcaException(
+      kind: _wire2api_oca_error_kind(arr[0]),
+      message: _wire2api_String(arr[1]),
+    );
+  }
+
+  OcaMap _wire2api_oca_map(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return OcaMap(
+      bridge: this,
+      field0: _wire2api_MutexStringMap(arr[0]),
+    );
+  }
+
+  OcaOverlay _wire2api_oca_overlay(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return OcaOverlay(
+      bridge: this,
+      field0: _wire2api_MutexOcaOverlayRaw(arr[0]),
+    );
+  }
+
+  OcaRegistry _wire2api_oca_registry(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return OcaRegistry(
+      bridge: this,
+      field0: _wire2api_OcaRegistryRaw(arr[0]),
+    );
+  }
+
+  OcaSaidVerification _wire2api_oca_said_verification(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return OcaSaidVerification(
+      path: _wire2api_String(arr[0]),
+      objectType: _wire2api_opt_String(arr[1]),
+      language: _wire2api_opt_String(arr[2]),
+      embedded: _wire2api_opt_String(arr[3]),
+      computed: _wire2api_opt_String(arr[4]),
+      valid: _wire2api_bool(arr[5]),
+      message: _wire2api_opt_String(arr[6]),
+    );
+  }
+
+  OcaSemanticCategory _wire2api_oca_semantic_category(dynamic raw) {
+    return OcaSemanticCategory.values[raw as int];
+  }
+
+  OcaSemanticIssue _wire2api_oca_semantic_issue(dynamic raw) {
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return OcaSemanticIssue(
+      category: _wire2api_oca_semantic_category(arr[0]),
+      overlay: _wire2api_opt_String(arr[1]),
+      attribute: _wire2api_opt_String(arr[2]),
+      message: _wire2api_String(arr[3]),
+    );
+  }
+
+  String? _wire2api_opt_String(dynamic raw) {
+    return raw == null ? null : _wire2api_String(raw);
+  }
+
+  List<String>? _wire2api_opt_StringList(dynamic raw) {
+    return raw == null ? null : _wire2api_StringList(raw);
+  }
+
+  OcaMap? _wire2api_opt_box_autoadd_oca_map(dynamic raw) {
+    return raw == null ? null : _wire2api_box_autoadd_oca_map(raw);
+  }
+
+  int _wire2api_u8(dynamic raw) {
+    return raw as int;
+  }
+
+  Uint8List _wire2api_uint_8_list(dynamic raw) {
+    return raw as Uint8List;
+  }
+
+  void _wire2api_unit(dynamic raw) {
+    return;
+  }
+}
+
+// Section: api2wire
+
+@protected
+int api2wire_i32(int raw) {
+  return raw;
+}
+
+@protected
+int api2wire_oca_attr_type(OcaAttrType raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_oca_encoding(OcaEncoding raw) {
+  return api2wire_i32(raw.index);
+}
+
+@protected
+int api2wire_u8(int raw) {
+  return raw;
+}
+
+// Section: finalizer
+
+class OcaDartPlatform extends FlutterRustBridgeBase<OcaDartWire> {
+  OcaDartPlatform(ffi.DynamicLibrary dylib) : super(OcaDartWire(dylib));
+
+// Section: api2wire
+
+  @protected
+  wire_MutexOcaAttrRaw api2wire_MutexOcaAttrRaw(MutexOcaAttrRaw raw) {
+    final ptr = inner.new_MutexOcaAttrRaw();
+    _api_fill_to_wire_MutexOcaAttrRaw(raw, ptr);
+    return ptr;
+  }
+
+  @protected
+  wire_MutexOcaAttributeDescriptorRaw api2wire_MutexOcaAttributeDescriptorRaw(
+      MutexOcaAttributeDescriptorRaw raw) {
+    final ptr = inner.new_MutexOcaAttributeDescriptorRaw();
+    _api_fill_to_wire_MutexOcaAttributeDescriptorRaw(raw, ptr);
+    return ptr;
+  }
+
+  @protected
+  wire_MutexOcaBoxRaw api2wire_MutexOcaBoxRaw(MutexOcaBoxRaw raw) {
+    final ptr = inner.new_MutexOcaBoxRaw();
+    _api_fill_to_wire_MutexOcaBoxRaw(raw, ptr);
+    return ptr;
+  }
+
+  @protected
+  wire_MutexOcaBundleRaw api2wire_MutexOcaBundleRaw(MutexOcaBundleRaw raw) {
+    final ptr = inner.new_MutexOcaBundleRaw();
+    _api_fill_to_wire_MutexOcaBundleRaw(raw, ptr);
+    return ptr;
+  }
+
+  @protected
+  wire_MutexOcaCaptureBaseRaw api2wire_MutexOcaCaptureBaseRaw(
+      MutexOcaCaptureBaseRaw raw) {
+    final ptr = inner.new_MutexOcaCaptureBaseRaw();
+    _api_fill_to_wire_MutexOcaCaptureBaseRaw(raw, ptr);
+    return ptr;
+  }
+
+  @protected
+  wire_MutexOcaOverlayRaw api2wire_MutexOcaOverlayRaw(MutexOcaOverlayRaw raw) {
+    final ptr = inner.new_MutexOcaOverlayRaw();
+    _api_fill_to_wire_MutexOcaOverlayRaw(raw, ptr);
+    return ptr;
+  }
+
+  @protected
+  wire_MutexStringMap api2wire_MutexStringMap(MutexStringMap raw) {
+    final ptr = inner.new_MutexStringMap();
+    _api_fill_to_wire_MutexStringMap(raw, ptr);
+    return ptr;
+  }
+
+  @protected
+  wire_OcaRegistryRaw api2wire_OcaRegistryRaw(OcaRegistryRaw raw) {
+    final ptr = inner.new_OcaRegistryRaw();
+    _api_fill_to_wire_OcaRegistryRaw(raw, ptr);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_String(String raw) {
+    return api2wire_uint_8_list(utf8.encoder.convert(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_StringList> api2wire_StringList(List<String> raw) {
+    final ans = inner.new_StringList_0(raw.length);
+    for (var i = 0; i < raw.length; i++) {
+      ans.ref.ptr[i] = api2wire_String(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_OcaAttr> api2wire_box_autoadd_oca_attr(OcaAttr raw) {
+    final ptr = inner.new_box_autoadd_oca_attr_0();
+    _api_fill_to_wire_oca_attr(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_OcaAttributeDescriptor> api2wire_box_autoadd_oca_attribute_descriptor(
+      OcaAttributeDescriptor raw) {
+    final ptr = inner.new_box_autoadd_oca_attribute_descriptor_0();
+    _api_fill_to_wire_oca_attribute_descriptor(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_OcaBox> api2wire_box_autoadd_oca_box(OcaBox raw) {
+    final ptr = inner.new_box_autoadd_oca_box_0();
+    _api_fill_to_wire_oca_box(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_OcaBundle> api2wire_box_autoadd_oca_bundle(OcaBundle raw) {
+    final ptr = inner.new_box_autoadd_oca_bundle_0();
+    _api_fill_to_wire_oca_bundle(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_OcaCaptureBase> api2wire_box_autoadd_oca_capture_base(
+      OcaCaptureBase raw) {
+    final ptr = inner.new_box_autoadd_oca_capture_base_0();
+    _api_fill_to_wire_oca_capture_base(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_OcaMap> api2wire_box_autoadd_oca_map(OcaMap raw) {
     final ptr = inner.new_box_autoadd_oca_map_0();
     _api_fill_to_wire_oca_map(raw, ptr.ref);
     return ptr;
   }
 
-  @protected
-  ffi.Pointer<wire_uint_8_list> api2wire_uint_8_list(Uint8List raw) {
-    final ans = inner.new_uint_8_list_0(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
+  @protected
+  ffi.Pointer<wire_OcaOverlay> api2wire_box_autoadd_oca_overlay(
+      OcaOverlay raw) {
+    final ptr = inner.new_box_autoadd_oca_overlay_0();
+    _api_fill_to_wire_oca_overlay(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_OcaRegistry> api2wire_box_autoadd_oca_registry(
+      OcaRegistry raw) {
+    final ptr = inner.new_box_autoadd_oca_registry_0();
+    _api_fill_to_wire_oca_registry(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_OcaBundle> api2wire_opt_box_autoadd_oca_bundle(
+      OcaBundle? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_oca_bundle(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_OcaRegistry> api2wire_opt_box_autoadd_oca_registry(
+      OcaRegistry? raw) {
+    return raw == null ? ffi.nullptr : api2wire_box_autoadd_oca_registry(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_uint_8_list> api2wire_uint_8_list(Uint8List raw) {
+    final ans = inner.new_uint_8_list_0(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+// Section: finalizer
+
+  late final OpaqueTypeFinalizer _MutexOcaAttrRawFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_MutexOcaAttrRawPtr);
+  OpaqueTypeFinalizer get MutexOcaAttrRawFinalizer => _MutexOcaAttrRawFinalizer;
+  late final OpaqueTypeFinalizer _MutexOcaAttributeDescriptorRawFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_MutexOcaAttributeDescriptorRawPtr);
+  OpaqueTypeFinalizer get MutexOcaAttributeDescriptorRawFinalizer =>
+      _MutexOcaAttributeDescriptorRawFinalizer;
+  late final OpaqueTypeFinalizer _MutexOcaBoxRawFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_MutexOcaBoxRawPtr);
+  OpaqueTypeFinalizer get MutexOcaBoxRawFinalizer => _MutexOcaBoxRawFinalizer;
+  late final OpaqueTypeFinalizer _MutexOcaBundleRawFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_MutexOcaBundleRawPtr);
+  OpaqueTypeFinalizer get MutexOcaBundleRawFinalizer =>
+      _MutexOcaBundleRawFinalizer;
+  late final OpaqueTypeFinalizer _MutexOcaCaptureBaseRawFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_MutexOcaCaptureBaseRawPtr);
+  OpaqueTypeFinalizer get MutexOcaCaptureBaseRawFinalizer =>
+      _MutexOcaCaptureBaseRawFinalizer;
+  late final OpaqueTypeFinalizer _MutexOcaOverlayRawFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_MutexOcaOverlayRawPtr);
+  OpaqueTypeFinalizer get MutexOcaOverlayRawFinalizer =>
+      _MutexOcaOverlayRawFinalizer;
+  late final OpaqueTypeFinalizer _MutexStringMapFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_MutexStringMapPtr);
+  OpaqueTypeFinalizer get MutexStringMapFinalizer => _MutexStringMapFinalizer;
+  late final OpaqueTypeFinalizer _OcaRegistryRawFinalizer =
+      OpaqueTypeFinalizer(inner._drop_opaque_OcaRegistryRawPtr);
+  OpaqueTypeFinalizer get OcaRegistryRawFinalizer => _OcaRegistryRawFinalizer;
+// Section: api_fill_to_wire
+
+  void _api_fill_to_wire_MutexOcaAttrRaw(
+      MutexOcaAttrRaw apiObj, wire_MutexOcaAttrRaw wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
+  void _api_fill_to_wire_MutexOcaAttributeDescriptorRaw(
+      MutexOcaAttributeDescriptorRaw apiObj, wire_MutexOcaAttributeDescriptorRaw wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
+  void _api_fill_to_wire_MutexOcaBoxRaw(
+      MutexOcaBoxRaw apiObj, wire_MutexOcaBoxRaw wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
+  void _api_fill_to_wire_MutexOcaBundleRaw(
+      MutexOcaBundleRaw apiObj, wire_MutexOcaBundleRaw wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
+  void _api_fill_to_wire_MutexOcaCaptureBaseRaw(
+      MutexOcaCaptureBaseRaw apiObj, wire_MutexOcaCaptureBaseRaw wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
+  void _api_fill_to_wire_MutexOcaOverlayRaw(
+      MutexOcaOverlayRaw apiObj, wire_MutexOcaOverlayRaw wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
+  void _api_fill_to_wire_MutexStringMap(
+      MutexStringMap apiObj, wire_MutexStringMap wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
+  void _api_fill_to_wire_OcaRegistryRaw(
+      OcaRegistryRaw apiObj, wire_OcaRegistryRaw wireObj) {
+    wireObj.ptr = apiObj.shareOrMove();
+  }
+
+  void _api_fill_to_wire_box_autoadd_oca_attr(
+      OcaAttr apiObj, ffi.Pointer<wire_OcaAttr> wireObj) {
+    _api_fill_to_wire_oca_attr(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_oca_attribute_descriptor(
+      OcaAttributeDescriptor apiObj, ffi.Pointer<wire_OcaAttributeDescriptor> wireObj) {
+    _api_fill_to_wire_oca_attribute_descriptor(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_oca_box(
+      OcaBox apiObj, ffi.Pointer<wire_OcaBox> wireObj) {
+    _api_fill_to_wire_oca_box(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_oca_bundle(
+      OcaBundle apiObj, ffi.Pointer<wire_OcaBundle> wireObj) {
+    _api_fill_to_wire_oca_bundle(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_oca_capture_base(
+      OcaCaptureBase apiObj, ffi.Pointer<wire_OcaCaptureBase> wireObj) {
+    _api_fill_to_wire_oca_capture_base(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_oca_map(
+      OcaMap apiObj, ffi.Pointer<wire_OcaMap> wireObj) {
+    _api_fill_to_wire_oca_map(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_oca_overlay(
+      OcaOverlay apiObj, ffi.Pointer<wire_OcaOverlay> wireObj) {
+    _api_fill_to_wire_oca_overlay(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_box_autoadd_oca_registry(
+      OcaRegistry apiObj, ffi.Pointer<wire_OcaRegistry> wireObj) {
+    _api_fill_to_wire_oca_registry(apiObj, wireObj.ref);
+  }
+
+  void _api_fill_to_wire_oca_attr(OcaAttr apiObj, wire_OcaAttr wireObj) {
+    wireObj.field0 = api2wire_MutexOcaAttrRaw(apiObj.field0);
+  }
+
+  void _api_fill_to_wire_oca_attribute_descriptor(
+      OcaAttributeDescriptor apiObj, wire_OcaAttributeDescriptor wireObj) {
+    wireObj.field0 = api2wire_MutexOcaAttributeDescriptorRaw(apiObj.field0);
+  }
+
+  void _api_fill_to_wire_oca_box(OcaBox apiObj, wire_OcaBox wireObj) {
+    wireObj.field0 = api2wire_MutexOcaBoxRaw(apiObj.field0);
+  }
+
+  void _api_fill_to_wire_oca_bundle(OcaBundle apiObj, wire_OcaBundle wireObj) {
+    wireObj.field0 = api2wire_MutexOcaBundleRaw(apiObj.field0);
+  }
+
+  void _api_fill_to_wire_oca_capture_base(
+      OcaCaptureBase apiObj, wire_OcaCaptureBase wireObj) {
+    wireObj.field0 = api2wire_MutexOcaCaptureBaseRaw(apiObj.field0);
+  }
+
+  void _api_fill_to_wire_oca_map(OcaMap apiObj, wire_OcaMap wireObj) {
+    wireObj.field0 = api2wire_MutexStringMap(apiObj.field0);
+  }
+
+  void _api_fill_to_wire_oca_overlay(
+      OcaOverlay apiObj, wire_OcaOverlay wireObj) {
+    wireObj.field0 = api2wire_MutexOcaOverlayRaw(apiObj.field0);
+  }
+
+  void _api_fill_to_wire_oca_registry(
+      OcaRegistry apiObj, wire_OcaRegistry wireObj) {
+    wireObj.field0 = api2wire_OcaRegistryRaw(apiObj.field0);
+  }
+}
+
+// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+
+// AUTO GENERATED FILE, DO NOT EDIT.
+//
+// Generated by `package:ffigen`.
+// ignore_for_file: type=lint
+
+/// generated by flutter_rust_bridge
+class OcaDartWire implements FlutterRustBridgeWireBase {
+  @internal
+  late final dartApi = DartApiDl(init_frb_dart_api_dl);
+
+  /// Holds the symbol lookup function.
+  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+      _lookup;
+
+  /// The symbols are looked up in [dynamicLibrary].
+  OcaDartWire(ffi.DynamicLibrary dynamicLibrary)
+      : _lookup = dynamicLibrary.lookup;
+
+  /// The symbols are looked up with [lookup].
+  OcaDartWire.fromLookup(
+      ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+          lookup)
+      : _lookup = lookup;
+
+  void store_dart_post_cobject(
+    DartPostCObjectFnType ptr,
+  ) {
+    return _store_dart_post_cobject(
+      ptr,
+    );
+  }
+
+  late final _store_dart_post_cobjectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
+          'store_dart_post_cobject');
+  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
+      .asFunction<void Function(DartPostCObjectFnType)>();
+
+  Object get_dart_object(
+    int ptr,
+  ) {
+    return _get_dart_object(
+      ptr,
+    );
+  }
+
+  late final _get_dart_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Handle Function(ffi.UintPtr)>>(
+          'get_dart_object');
+  late final _get_dart_object =
+      _get_dart_objectPtr.asFunction<Object Function(int)>();
+
+  void drop_dart_object(
+    int ptr,
+  ) {
+    return _drop_dart_object(
+      ptr,
+    );
+  }
+
+  late final _drop_dart_objectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>(
+          'drop_dart_object');
+  late final _drop_dart_object =
+      _drop_dart_objectPtr.asFunction<void Function(int)>();
+
+  int new_dart_opaque(
+    Object handle,
+  ) {
+    return _new_dart_opaque(
+      handle,
+    );
+  }
+
+  late final _new_dart_opaquePtr =
+      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.Handle)>>(
+          'new_dart_opaque');
+  late final _new_dart_opaque =
+      _new_dart_opaquePtr.asFunction<int Function(Object)>();
+
+  int init_frb_dart_api_dl(
+    ffi.Pointer<ffi.Void> obj,
+  ) {
+    return _init_frb_dart_api_dl(
+      obj,
+    );
+  }
+
+  late final _init_frb_dart_api_dlPtr =
+      _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>(
+          'init_frb_dart_api_dl');
+  late final _init_frb_dart_api_dl = _init_frb_dart_api_dlPtr
+      .asFunction<int Function(ffi.Pointer<ffi.Void>)>();
+
+  void wire_load_oca(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> json,
+    ffi.Pointer<wire_OcaRegistry> registry,
+  ) {
+    return _wire_load_oca(
+      port_,
+      json,
+      registry,
+    );
+  }
+
+  late final _wire_load_ocaPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_OcaRegistry>)>>('wire_load_oca');
+  late final _wire_load_oca = _wire_load_ocaPtr.asFunction<
+      void Function(
+          int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_OcaRegistry>)>();
+
+  void wire_is_legacy_bundle(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> json,
+  ) {
+    return _wire_is_legacy_bundle(
+      port_,
+      json,
+    );
+  }
+
+  late final _wire_is_legacy_bundlePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_is_legacy_bundle');
+  late final _wire_is_legacy_bundle = _wire_is_legacy_bundlePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_parse_ocafile(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> ocafile,
+    ffi.Pointer<wire_OcaRegistry> registry,
+  ) {
+    return _wire_parse_ocafile(
+      port_,
+      ocafile,
+      registry,
+    );
+  }
+
+  late final _wire_parse_ocafilePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_OcaRegistry>)>>('wire_parse_ocafile');
+  late final _wire_parse_ocafile = _wire_parse_ocafilePtr.asFunction<
+      void Function(
+          int, ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_OcaRegistry>)>();
+
+  void wire_build_from_ocafile(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> ocafile,
+    ffi.Pointer<wire_OcaRegistry> registry,
+    ffi.Pointer<wire_OcaBundle> base,
+  ) {
+    return _wire_build_from_ocafile(
+      port_,
+      ocafile,
+      registry,
+      base,
+    );
+  }
+
+  late final _wire_build_from_ocafilePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_OcaRegistry>,
+              ffi.Pointer<wire_OcaBundle>)>>('wire_build_from_ocafile');
+  late final _wire_build_from_ocafile = _wire_build_from_ocafilePtr.asFunction<
+      void Function(int, ffi.Pointer<wire_uint_8_list>,
+          ffi.Pointer<wire_OcaRegistry>, ffi.Pointer<wire_OcaBundle>)>();
+
+  void wire_generate_ocafile(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> bundle,
+  ) {
+    return _wire_generate_ocafile(
+      port_,
+      bundle,
+    );
+  }
+
+  late final _wire_generate_ocafilePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_OcaBundle>)>>('wire_generate_ocafile');
+  late final _wire_generate_ocafile = _wire_generate_ocafilePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
+
+  void wire_verify_bundle(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> json,
+  ) {
+    return _wire_verify_bundle(
+      port_,
+      json,
+    );
+  }
+
+  late final _wire_verify_bundlePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_verify_bundle');
+  late final _wire_verify_bundle = _wire_verify_bundlePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_new__static_method__OcaRegistry(
+    int port_,
+  ) {
+    return _wire_new__static_method__OcaRegistry(
+      port_,
+    );
+  }
+
+  late final _wire_new__static_method__OcaRegistryPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_new__static_method__OcaRegistry');
+  late final _wire_new__static_method__OcaRegistry =
+      _wire_new__static_method__OcaRegistryPtr.asFunction<void Function(int)>();
+
+  void wire_from_overlay_file__static_method__OcaRegistry(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> overlay_file,
+  ) {
+    return _wire_from_overlay_file__static_method__OcaRegistry(
+      port_,
+      overlay_file,
+    );
+  }
+
+  late final _wire_from_overlay_file__static_method__OcaRegistryPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_from_overlay_file__static_method__OcaRegistry');
+  late final _wire_from_overlay_file__static_method__OcaRegistry =
+      _wire_from_overlay_file__static_method__OcaRegistryPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_new__static_method__OcaBox(
+    int port_,
+  ) {
+    return _wire_new__static_method__OcaBox(
+      port_,
+    );
+  }
+
+  late final _wire_new__static_method__OcaBoxPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+          'wire_new__static_method__OcaBox');
+  late final _wire_new__static_method__OcaBox =
+      _wire_new__static_method__OcaBoxPtr.asFunction<void Function(int)>();
+
+  void wire_add_meta__method__OcaBox(
+    int port_,
+    ffi.Pointer<wire_OcaBox> that,
+    ffi.Pointer<wire_uint_8_list> lang,
+    ffi.Pointer<wire_uint_8_list> name,
+    ffi.Pointer<wire_uint_8_list> value,
+  ) {
+    return _wire_add_meta__method__OcaBox(
+      port_,
+      that,
+      lang,
+      name,
+      value,
+    );
+  }
+
+  late final _wire_add_meta__method__OcaBoxPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<wire_OcaBox>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>>('wire_add_meta__method__OcaBox');
+  late final _wire_add_meta__method__OcaBox =
+      _wire_add_meta__method__OcaBoxPtr.asFunction<
+          void Function(
+              int,
+              ffi.Pointer<wire_OcaBox>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_add_attribute__method__OcaBox(
+    int port_,
+    ffi.Pointer<wire_OcaBox> that,
+    ffi.Pointer<wire_OcaAttr> attr,
+  ) {
+    return _wire_add_attribute__method__OcaBox(
+      port_,
+      that,
+      attr,
+    );
+  }
+
+  late final _wire_add_attribute__method__OcaBoxPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBox>,
+                  ffi.Pointer<wire_OcaAttr>)>>(
+      'wire_add_attribute__method__OcaBox');
+  late final _wire_add_attribute__method__OcaBox =
+      _wire_add_attribute__method__OcaBoxPtr.asFunction<
+          void Function(
+              int, ffi.Pointer<wire_OcaBox>, ffi.Pointer<wire_OcaAttr>)>();
+
+  void wire_generate_bundle__method__OcaBox(
+    int port_,
+    ffi.Pointer<wire_OcaBox> that,
+    ffi.Pointer<wire_OcaRegistry> registry,
+  ) {
+    return _wire_generate_bundle__method__OcaBox(
+      port_,
+      that,
+      registry,
+    );
+  }
+
+  late final _wire_generate_bundle__method__OcaBoxPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBox>,
+                  ffi.Pointer<wire_OcaRegistry>)>>(
+      'wire_generate_bundle__method__OcaBox');
+  late final _wire_generate_bundle__method__OcaBox =
+      _wire_generate_bundle__method__OcaBoxPtr.asFunction<
+          void Function(
+              int, ffi.Pointer<wire_OcaBox>, ffi.Pointer<wire_OcaRegistry>)>();
+
+  void wire_new__static_method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_uint_8_list> name,
+  ) {
+    return _wire_new__static_method__OcaAttr(
+      port_,
+      name,
+    );
+  }
+
+  late final _wire_new__static_method__OcaAttrPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_new__static_method__OcaAttr');
+  late final _wire_new__static_method__OcaAttr =
+      _wire_new__static_method__OcaAttrPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_set_attribute_type__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+    int attr_type,
+  ) {
+    return _wire_set_attribute_type__method__OcaAttr(
+      port_,
+      that,
+      attr_type,
+    );
+  }
+
+  late final _wire_set_attribute_type__method__OcaAttrPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
+              ffi.Int32)>>('wire_set_attribute_type__method__OcaAttr');
+  late final _wire_set_attribute_type__method__OcaAttr =
+      _wire_set_attribute_type__method__OcaAttrPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaAttr>, int)>();
+
+  void wire_set_attribute_type_spelling__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+    ffi.Pointer<wire_uint_8_list> spelling,
+  ) {
+    return _wire_set_attribute_type_spelling__method__OcaAttr(
+      port_,
+      that,
+      spelling,
+    );
+  }
+
+  late final _wire_set_attribute_type_spelling__method__OcaAttrPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_set_attribute_type_spelling__method__OcaAttr');
+  late final _wire_set_attribute_type_spelling__method__OcaAttr =
+      _wire_set_attribute_type_spelling__method__OcaAttrPtr.asFunction<
+          void Function(
+              int, ffi.Pointer<wire_OcaAttr>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_set_flagged__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+  ) {
+    return _wire_set_flagged__method__OcaAttr(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire_set_flagged__method__OcaAttrPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_OcaAttr>)>>('wire_set_flagged__method__OcaAttr');
+  late final _wire_set_flagged__method__OcaAttr =
+      _wire_set_flagged__method__OcaAttrPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaAttr>)>();
+
+  void wire_set_encoding__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+    int encoding,
+  ) {
+    return _wire_set_encoding__method__OcaAttr(
+      port_,
+      that,
+      encoding,
+    );
+  }
+
+  late final _wire_set_encoding__method__OcaAttrPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
+              ffi.Int32)>>('wire_set_encoding__method__OcaAttr');
+  late final _wire_set_encoding__method__OcaAttr =
+      _wire_set_encoding__method__OcaAttrPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaAttr>, int)>();
+
+  void wire_set_cardinality__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+    ffi.Pointer<wire_uint_8_list> cardinality,
+  ) {
+    return _wire_set_cardinality__method__OcaAttr(
+      port_,
+      that,
+      cardinality,
+    );
+  }
+
+  late final _wire_set_cardinality__method__OcaAttrPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_set_cardinality__method__OcaAttr');
+  late final _wire_set_cardinality__method__OcaAttr =
+      _wire_set_cardinality__method__OcaAttrPtr.asFunction<
+          void Function(
+              int, ffi.Pointer<wire_OcaAttr>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_set_conformance__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+    ffi.Pointer<wire_uint_8_list> conformance,
+  ) {
+    return _wire_set_conformance__method__OcaAttr(
+      port_,
+      that,
+      conformance,
+    );
+  }
+
+  late final _wire_set_conformance__method__OcaAttrPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_set_conformance__method__OcaAttr');
+  late final _wire_set_conformance__method__OcaAttr =
+      _wire_set_conformance__method__OcaAttrPtr.asFunction<
+          void Function(
+              int, ffi.Pointer<wire_OcaAttr>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_set_label__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+    ffi.Pointer<wire_uint_8_list> lang,
+    ffi.Pointer<wire_uint_8_list> label,
+  ) {
+    return _wire_set_label__method__OcaAttr(
+      port_,
+      that,
+      lang,
+      label,
+    );
+  }
+
+  late final _wire_set_label__method__OcaAttrPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_OcaAttr>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_set_label__method__OcaAttr');
+  late final _wire_set_label__method__OcaAttr =
+      _wire_set_label__method__OcaAttrPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttr>,
+              ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_set_information__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+    ffi.Pointer<wire_uint_8_list> lang,
+    ffi.Pointer<wire_uint_8_list> information,
+  ) {
+    return _wire_set_information__method__OcaAttr(
+      port_,
+      that,
+      lang,
+      information,
+    );
+  }
+
+  late final _wire_set_information__method__OcaAttrPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_OcaAttr>,
+                  ffi.Pointer<wire_uint_8_list>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_set_information__method__OcaAttr');
+  late final _wire_set_information__method__OcaAttr =
+      _wire_set_information__method__OcaAttrPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttr>,
+              ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+
+  void wire_set_entry_codes__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+    ffi.Pointer<wire_StringList> entry_codes,
+  ) {
+    return _wire_set_entry_codes__method__OcaAttr(
+      port_,
+      that,
+      entry_codes,
+    );
+  }
+
+  late final _wire_set_entry_codes__method__OcaAttrPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
+                  ffi.Pointer<wire_StringList>)>>(
+      'wire_set_entry_codes__method__OcaAttr');
+  late final _wire_set_entry_codes__method__OcaAttr =
+      _wire_set_entry_codes__method__OcaAttrPtr.asFunction<
+          void Function(
+              int, ffi.Pointer<wire_OcaAttr>, ffi.Pointer<wire_StringList>)>();
+
+  void wire_set_entry__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+    ffi.Pointer<wire_uint_8_list> lang,
+    ffi.Pointer<wire_OcaMap> entries,
+  ) {
+    return _wire_set_entry__method__OcaAttr(
+      port_,
+      that,
+      lang,
+      entries,
+    );
+  }
+
+  late final _wire_set_entry__method__OcaAttrPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(
+              ffi.Int64,
+              ffi.Pointer<wire_OcaAttr>,
+              ffi.Pointer<wire_uint_8_list>,
+              ffi.Pointer<wire_OcaMap>)>>('wire_set_entry__method__OcaAttr');
+  late final _wire_set_entry__method__OcaAttr =
+      _wire_set_entry__method__OcaAttrPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttr>,
+              ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_OcaMap>)>();
+
+  void wire_set_unit__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+    ffi.Pointer<wire_uint_8_list> unit,
+  ) {
+    return _wire_set_unit__method__OcaAttr(
+      port_,
+      that,
+      unit,
+    );
   }
-// Section: finalizer
 
-  late final OpaqueTypeFinalizer _MutexDynOverlayFinalizer =
-      OpaqueTypeFinalizer(inner._drop_opaque_MutexDynOverlayPtr);
-  OpaqueTypeFinalizer get MutexDynOverlayFinalizer => _MutexDynOverlayFinalizer;
-  late final OpaqueTypeFinalizer _MutexOcaAttrRawFinalizer =
-      OpaqueTypeFinalizer(inner._drop_opaque_MutexOcaAttrRawPtr);
-  OpaqueTypeFinalizer get MutexOcaAttrRawFinalizer => _MutexOcaAttrRawFinalizer;
-  late final OpaqueTypeFinalizer _MutexOcaBoxRawFinalizer =
-      OpaqueTypeFinalizer(inner._drop_opaque_MutexOcaBoxRawPtr);
-  OpaqueTypeFinalizer get MutexOcaBoxRawFinalizer => _MutexOcaBoxRawFinalizer;
-  late final OpaqueTypeFinalizer _MutexOcaBundleRawFinalizer =
-      OpaqueTypeFinalizer(inner._drop_opaque_MutexOcaBundleRawPtr);
-  OpaqueTypeFinalizer get MutexOcaBundleRawFinalizer =>
-      _MutexOcaBundleRawFinalizer;
-  late final OpaqueTypeFinalizer _MutexOcaCaptureBaseRawFinalizer =
-      OpaqueTypeFinalizer(inner._drop_opaque_MutexOcaCaptureBaseRawPtr);
-  OpaqueTypeFinalizer get MutexOcaCaptureBaseRawFinalizer =>
-      _MutexOcaCaptureBaseRawFinalizer;
-  late final OpaqueTypeFinalizer _MutexStringMapFinalizer =
-      OpaqueTypeFinalizer(inner._drop_opaque_MutexStringMapPtr);
-  OpaqueTypeFinalizer get MutexStringMapFinalizer => _MutexStringMapFinalizer;
-// Section: api_fill_to_wire
+  late final _wire_set_unit__method__OcaAttrPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_set_unit__method__OcaAttr');
+  late final _wire_set_unit__method__OcaAttr =
+      _wire_set_unit__method__OcaAttrPtr.asFunction<
+          void Function(
+              int, ffi.Pointer<wire_OcaAttr>, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_MutexOcaAttrRaw(
-      MutexOcaAttrRaw apiObj, wire_MutexOcaAttrRaw wireObj) {
-    wireObj.ptr = apiObj.shareOrMove();
+  void wire_set_format__method__OcaAttr(
+    int port_,
+    ffi.Pointer<wire_OcaAttr> that,
+    ffi.Pointer<wire_uint_8_list> format,
+  ) {
+    return _wire_set_format__method__OcaAttr(
+      port_,
+      that,
+      format,
+    );
   }
 
-  void _api_fill_to_wire_MutexOcaBoxRaw(
-      MutexOcaBoxRaw apiObj, wire_MutexOcaBoxRaw wireObj) {
-    wireObj.ptr = apiObj.shareOrMove();
-  }
+  late final _wire_set_format__method__OcaAttrPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_set_format__method__OcaAttr');
+  late final _wire_set_format__method__OcaAttr =
+      _wire_set_format__method__OcaAttrPtr.asFunction<
+          void Function(
+              int, ffi.Pointer<wire_OcaAttr>, ffi.Pointer<wire_uint_8_list>)>();
 
-  void _api_fill_to_wire_MutexOcaBundleRaw(
-      MutexOcaBundleRaw apiObj, wire_MutexOcaBundleRaw wireObj) {
-    wireObj.ptr = apiObj.shareOrMove();
+  void wire_to_json__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
+  ) {
+    return _wire_to_json__method__OcaBundle(
+      port_,
+      that,
+    );
   }
 
-  void _api_fill_to_wire_MutexOcaCaptureBaseRaw(
-      MutexOcaCaptureBaseRaw apiObj, wire_MutexOcaCaptureBaseRaw wireObj) {
-    wireObj.ptr = apiObj.shareOrMove();
-  }
+  late final _wire_to_json__method__OcaBundlePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_OcaBundle>)>>('wire_to_json__method__OcaBundle');
+  late final _wire_to_json__method__OcaBundle =
+      _wire_to_json__method__OcaBundlePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
 
-  void _api_fill_to_wire_MutexStringMap(
-      MutexStringMap apiObj, wire_MutexStringMap wireObj) {
-    wireObj.ptr = apiObj.shareOrMove();
+  void wire_said__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
+  ) {
+    return _wire_said__method__OcaBundle(
+      port_,
+      that,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_oca_attr(
-      OcaAttr apiObj, ffi.Pointer<wire_OcaAttr> wireObj) {
-    _api_fill_to_wire_oca_attr(apiObj, wireObj.ref);
-  }
+  late final _wire_said__method__OcaBundlePtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_OcaBundle>)>>('wire_said__method__OcaBundle');
+  late final _wire_said__method__OcaBundle = _wire_said__method__OcaBundlePtr
+      .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
 
-  void _api_fill_to_wire_box_autoadd_oca_box(
-      OcaBox apiObj, ffi.Pointer<wire_OcaBox> wireObj) {
-    _api_fill_to_wire_oca_box(apiObj, wireObj.ref);
+  void wire_capture_base__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
+  ) {
+    return _wire_capture_base__method__OcaBundle(
+      port_,
+      that,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_oca_bundle(
-      OcaBundle apiObj, ffi.Pointer<wire_OcaBundle> wireObj) {
-    _api_fill_to_wire_oca_bundle(apiObj, wireObj.ref);
-  }
+  late final _wire_capture_base__method__OcaBundlePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>)>>(
+      'wire_capture_base__method__OcaBundle');
+  late final _wire_capture_base__method__OcaBundle =
+      _wire_capture_base__method__OcaBundlePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
 
-  void _api_fill_to_wire_box_autoadd_oca_capture_base(
-      OcaCaptureBase apiObj, ffi.Pointer<wire_OcaCaptureBase> wireObj) {
-    _api_fill_to_wire_oca_capture_base(apiObj, wireObj.ref);
+  void wire_overlays__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
+  ) {
+    return _wire_overlays__method__OcaBundle(
+      port_,
+      that,
+    );
   }
 
-  void _api_fill_to_wire_box_autoadd_oca_map(
-      OcaMap apiObj, ffi.Pointer<wire_OcaMap> wireObj) {
-    _api_fill_to_wire_oca_map(apiObj, wireObj.ref);
-  }
+  late final _wire_overlays__method__OcaBundlePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>)>>(
+      'wire_overlays__method__OcaBundle');
+  late final _wire_overlays__method__OcaBundle =
+      _wire_overlays__method__OcaBundlePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
 
-  void _api_fill_to_wire_oca_attr(OcaAttr apiObj, wire_OcaAttr wireObj) {
-    wireObj.field0 = api2wire_MutexOcaAttrRaw(apiObj.field0);
+  void wire_languages__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
+  ) {
+    return _wire_languages__method__OcaBundle(
+      port_,
+      that,
+    );
   }
 
-  void _api_fill_to_wire_oca_box(OcaBox apiObj, wire_OcaBox wireObj) {
-    wireObj.field0 = api2wire_MutexOcaBoxRaw(apiObj.field0);
-  }
+  late final _wire_languages__method__OcaBundlePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>)>>(
+      'wire_languages__method__OcaBundle');
+  late final _wire_languages__method__OcaBundle =
+      _wire_languages__method__OcaBundlePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
 
-  void _api_fill_to_wire_oca_bundle(OcaBundle apiObj, wire_OcaBundle wireObj) {
-    wireObj.field0 = api2wire_MutexOcaBundleRaw(apiObj.field0);
+  void wire_keep_languages__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
+    ffi.Pointer<wire_StringList> keep,
+  ) {
+    return _wire_keep_languages__method__OcaBundle(
+      port_,
+      that,
+      keep,
+    );
   }
 
-  void _api_fill_to_wire_oca_capture_base(
-      OcaCaptureBase apiObj, wire_OcaCaptureBase wireObj) {
-    wireObj.field0 = api2wire_MutexOcaCaptureBaseRaw(apiObj.field0);
-  }
+  late final _wire_keep_languages__method__OcaBundlePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>,
+                  ffi.Pointer<wire_StringList>)>>(
+      'wire_keep_languages__method__OcaBundle');
+  late final _wire_keep_languages__method__OcaBundle =
+      _wire_keep_languages__method__OcaBundlePtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaBundle>,
+              ffi.Pointer<wire_StringList>)>();
 
-  void _api_fill_to_wire_oca_map(OcaMap apiObj, wire_OcaMap wireObj) {
-    wireObj.field0 = api2wire_MutexStringMap(apiObj.field0);
+  void wire_extract_translation__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
+    ffi.Pointer<wire_uint_8_list> language,
+  ) {
+    return _wire_extract_translation__method__OcaBundle(
+      port_,
+      that,
+      language,
+    );
   }
-}
 
-// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+  late final _wire_extract_translation__method__OcaBundlePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_extract_translation__method__OcaBundle');
+  late final _wire_extract_translation__method__OcaBundle =
+      _wire_extract_translation__method__OcaBundlePtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaBundle>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-// AUTO GENERATED FILE, DO NOT EDIT.
-//
-// Generated by `package:ffigen`.
-// ignore_for_file: type=lint
+  void wire_merge_translation__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
+    ffi.Pointer<wire_uint_8_list> translation,
+  ) {
+    return _wire_merge_translation__method__OcaBundle(
+      port_,
+      that,
+      translation,
+    );
+  }
 
-/// generated by flutter_rust_bridge
-class OcaDartWire implements FlutterRustBridgeWireBase {
-  @internal
-  late final dartApi = DartApiDl(init_frb_dart_api_dl);
+  late final _wire_merge_translation__method__OcaBundlePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_merge_translation__method__OcaBundle');
+  late final _wire_merge_translation__method__OcaBundle =
+      _wire_merge_translation__method__OcaBundlePtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaBundle>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
+  void wire_attributes__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
+  ) {
+    return _wire_attributes__method__OcaBundle(
+      port_,
+      that,
+    );
+  }
 
-  /// The symbols are looked up in [dynamicLibrary].
-  OcaDartWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
+  late final _wire_attributes__method__OcaBundlePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>)>>(
+      'wire_attributes__method__OcaBundle');
+  late final _wire_attributes__method__OcaBundle =
+      _wire_attributes__method__OcaBundlePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
 
-  /// The symbols are looked up with [lookup].
-  OcaDartWire.fromLookup(
-      ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-          lookup)
-      : _lookup = lookup;
+  void wire_upgrade_notes__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
+  ) {
+    return _wire_upgrade_notes__method__OcaBundle(
+      port_,
+      that,
+    );
+  }
 
-  void store_dart_post_cobject(
-    DartPostCObjectFnType ptr,
+  late final _wire_upgrade_notes__method__OcaBundlePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>)>>(
+      'wire_upgrade_notes__method__OcaBundle');
+  late final _wire_upgrade_notes__method__OcaBundle =
+      _wire_upgrade_notes__method__OcaBundlePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
+
+  void wire_validate_semantics__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
   ) {
-    return _store_dart_post_cobject(
-      ptr,
+    return _wire_validate_semantics__method__OcaBundle(
+      port_,
+      that,
     );
   }
 
-  late final _store_dart_post_cobjectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
-          'store_dart_post_cobject');
-  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
-      .asFunction<void Function(DartPostCObjectFnType)>();
+  late final _wire_validate_semantics__method__OcaBundlePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>)>>(
+      'wire_validate_semantics__method__OcaBundle');
+  late final _wire_validate_semantics__method__OcaBundle =
+      _wire_validate_semantics__method__OcaBundlePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
 
-  Object get_dart_object(
-    int ptr,
+  void wire_validate_data__method__OcaBundle(
+    int port_,
+    ffi.Pointer<wire_OcaBundle> that,
+    ffi.Pointer<wire_uint_8_list> record,
   ) {
-    return _get_dart_object(
-      ptr,
+    return _wire_validate_data__method__OcaBundle(
+      port_,
+      that,
+      record,
     );
   }
 
-  late final _get_dart_objectPtr =
-      _lookup<ffi.NativeFunction<ffi.Handle Function(ffi.UintPtr)>>(
-          'get_dart_object');
-  late final _get_dart_object =
-      _get_dart_objectPtr.asFunction<Object Function(int)>();
+  late final _wire_validate_data__method__OcaBundlePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_validate_data__method__OcaBundle');
+  late final _wire_validate_data__method__OcaBundle =
+      _wire_validate_data__method__OcaBundlePtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaBundle>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  void drop_dart_object(
-    int ptr,
+  void wire_attributes__method__OcaCaptureBase(
+    int port_,
+    ffi.Pointer<wire_OcaCaptureBase> that,
   ) {
-    return _drop_dart_object(
-      ptr,
+    return _wire_attributes__method__OcaCaptureBase(
+      port_,
+      that,
     );
   }
 
-  late final _drop_dart_objectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.UintPtr)>>(
-          'drop_dart_object');
-  late final _drop_dart_object =
-      _drop_dart_objectPtr.asFunction<void Function(int)>();
+  late final _wire_attributes__method__OcaCaptureBasePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaCaptureBase>)>>(
+      'wire_attributes__method__OcaCaptureBase');
+  late final _wire_attributes__method__OcaCaptureBase =
+      _wire_attributes__method__OcaCaptureBasePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaCaptureBase>)>();
 
-  int new_dart_opaque(
-    Object handle,
+  void wire_flagged_attributes__method__OcaCaptureBase(
+    int port_,
+    ffi.Pointer<wire_OcaCaptureBase> that,
   ) {
-    return _new_dart_opaque(
-      handle,
+    return _wire_flagged_attributes__method__OcaCaptureBase(
+      port_,
+      that,
     );
   }
 
-  late final _new_dart_opaquePtr =
-      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.Handle)>>(
-          'new_dart_opaque');
-  late final _new_dart_opaque =
-      _new_dart_opaquePtr.asFunction<int Function(Object)>();
+  late final _wire_flagged_attributes__method__OcaCaptureBasePtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaCaptureBase>)>>(
+      'wire_flagged_attributes__method__OcaCaptureBase');
+  late final _wire_flagged_attributes__method__OcaCaptureBase =
+      _wire_flagged_attributes__method__OcaCaptureBasePtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaCaptureBase>)>();
 
-  int init_frb_dart_api_dl(
-    ffi.Pointer<ffi.Void> obj,
+  void wire_overlay_type__method__OcaOverlay(
+    int port_,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _init_frb_dart_api_dl(
-      obj,
+    return _wire_overlay_type__method__OcaOverlay(
+      port_,
+      that,
     );
   }
 
-  late final _init_frb_dart_api_dlPtr =
-      _lookup<ffi.NativeFunction<ffi.IntPtr Function(ffi.Pointer<ffi.Void>)>>(
-          'init_frb_dart_api_dl');
-  late final _init_frb_dart_api_dl = _init_frb_dart_api_dlPtr
-      .asFunction<int Function(ffi.Pointer<ffi.Void>)>();
+  late final _wire_overlay_type__method__OcaOverlayPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaOverlay>)>>(
+      'wire_overlay_type__method__OcaOverlay');
+  late final _wire_overlay_type__method__OcaOverlay =
+      _wire_overlay_type__method__OcaOverlayPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_load_oca(
+  void wire_name__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_uint_8_list> json,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _wire_load_oca(
+    return _wire_name__method__OcaOverlay(
       port_,
-      json,
+      that,
     );
   }
 
-  late final _wire_load_ocaPtr = _lookup<
+  late final _wire_name__method__OcaOverlayPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>('wire_load_oca');
-  late final _wire_load_oca = _wire_load_ocaPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_OcaOverlay>)>>('wire_name__method__OcaOverlay');
+  late final _wire_name__method__OcaOverlay = _wire_name__method__OcaOverlayPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_new__static_method__OcaBox(
+  void wire_language__method__OcaOverlay(
     int port_,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _wire_new__static_method__OcaBox(
+    return _wire_language__method__OcaOverlay(
       port_,
+      that,
     );
   }
 
-  late final _wire_new__static_method__OcaBoxPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-          'wire_new__static_method__OcaBox');
-  late final _wire_new__static_method__OcaBox =
-      _wire_new__static_method__OcaBoxPtr.asFunction<void Function(int)>();
+  late final _wire_language__method__OcaOverlayPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaOverlay>)>>(
+      'wire_language__method__OcaOverlay');
+  late final _wire_language__method__OcaOverlay =
+      _wire_language__method__OcaOverlayPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_add_meta__method__OcaBox(
+  void wire_said__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_OcaBox> that,
-    ffi.Pointer<wire_uint_8_list> lang,
-    ffi.Pointer<wire_uint_8_list> name,
-    ffi.Pointer<wire_uint_8_list> value,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _wire_add_meta__method__OcaBox(
+    return _wire_said__method__OcaOverlay(
       port_,
       that,
-      lang,
-      name,
-      value,
     );
   }
 
-  late final _wire_add_meta__method__OcaBoxPtr = _lookup<
+  late final _wire_said__method__OcaOverlayPtr = _lookup<
       ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64,
-              ffi.Pointer<wire_OcaBox>,
-              ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_uint_8_list>)>>('wire_add_meta__method__OcaBox');
-  late final _wire_add_meta__method__OcaBox =
-      _wire_add_meta__method__OcaBoxPtr.asFunction<
-          void Function(
-              int,
-              ffi.Pointer<wire_OcaBox>,
-              ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_uint_8_list>)>();
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_OcaOverlay>)>>('wire_said__method__OcaOverlay');
+  late final _wire_said__method__OcaOverlay = _wire_said__method__OcaOverlayPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_add_attribute__method__OcaBox(
+  void wire_capture_base__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_OcaBox> that,
-    ffi.Pointer<wire_OcaAttr> attr,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _wire_add_attribute__method__OcaBox(
+    return _wire_capture_base__method__OcaOverlay(
       port_,
       that,
-      attr,
     );
   }
 
-  late final _wire_add_attribute__method__OcaBoxPtr = _lookup<
+  late final _wire_capture_base__method__OcaOverlayPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBox>,
-                  ffi.Pointer<wire_OcaAttr>)>>(
-      'wire_add_attribute__method__OcaBox');
-  late final _wire_add_attribute__method__OcaBox =
-      _wire_add_attribute__method__OcaBoxPtr.asFunction<
-          void Function(
-              int, ffi.Pointer<wire_OcaBox>, ffi.Pointer<wire_OcaAttr>)>();
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaOverlay>)>>(
+      'wire_capture_base__method__OcaOverlay');
+  late final _wire_capture_base__method__OcaOverlay =
+      _wire_capture_base__method__OcaOverlayPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_generate_bundle__method__OcaBox(
+  void wire_to_json__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_OcaBox> that,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _wire_generate_bundle__method__OcaBox(
+    return _wire_to_json__method__OcaOverlay(
       port_,
       that,
     );
   }
 
-  late final _wire_generate_bundle__method__OcaBoxPtr = _lookup<
+  late final _wire_to_json__method__OcaOverlayPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBox>)>>(
-      'wire_generate_bundle__method__OcaBox');
-  late final _wire_generate_bundle__method__OcaBox =
-      _wire_generate_bundle__method__OcaBoxPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_OcaBox>)>();
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaOverlay>)>>(
+      'wire_to_json__method__OcaOverlay');
+  late final _wire_to_json__method__OcaOverlay =
+      _wire_to_json__method__OcaOverlayPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_add_form_layout__method__OcaBox(
+  void wire_labels__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_OcaBox> that,
-    ffi.Pointer<wire_uint_8_list> layout,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _wire_add_form_layout__method__OcaBox(
+    return _wire_labels__method__OcaOverlay(
       port_,
       that,
-      layout,
     );
   }
 
-  late final _wire_add_form_layout__method__OcaBoxPtr = _lookup<
+  late final _wire_labels__method__OcaOverlayPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBox>,
-                  ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_add_form_layout__method__OcaBox');
-  late final _wire_add_form_layout__method__OcaBox =
-      _wire_add_form_layout__method__OcaBoxPtr.asFunction<
-          void Function(
-              int, ffi.Pointer<wire_OcaBox>, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaOverlay>)>>(
+      'wire_labels__method__OcaOverlay');
+  late final _wire_labels__method__OcaOverlay =
+      _wire_labels__method__OcaOverlayPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_add_credential_layout__method__OcaBox(
+  void wire_entries__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_OcaBox> that,
-    ffi.Pointer<wire_uint_8_list> layout,
+    ffi.Pointer<wire_OcaOverlay> that,
+    ffi.Pointer<wire_uint_8_list> attribute,
   ) {
-    return _wire_add_credential_layout__method__OcaBox(
+    return _wire_entries__method__OcaOverlay(
       port_,
       that,
-      layout,
+      attribute,
     );
   }
 
-  late final _wire_add_credential_layout__method__OcaBoxPtr = _lookup<
+  late final _wire_entries__method__OcaOverlayPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBox>,
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaOverlay>,
                   ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_add_credential_layout__method__OcaBox');
-  late final _wire_add_credential_layout__method__OcaBox =
-      _wire_add_credential_layout__method__OcaBoxPtr.asFunction<
-          void Function(
-              int, ffi.Pointer<wire_OcaBox>, ffi.Pointer<wire_uint_8_list>)>();
+      'wire_entries__method__OcaOverlay');
+  late final _wire_entries__method__OcaOverlay =
+      _wire_entries__method__OcaOverlayPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaOverlay>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_new__static_method__OcaAttr(
+  void wire_entry_codes__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_uint_8_list> name,
+    ffi.Pointer<wire_OcaOverlay> that,
+    ffi.Pointer<wire_uint_8_list> attribute,
   ) {
-    return _wire_new__static_method__OcaAttr(
+    return _wire_entry_codes__method__OcaOverlay(
       port_,
-      name,
+      that,
+      attribute,
     );
   }
 
-  late final _wire_new__static_method__OcaAttrPtr = _lookup<
+  late final _wire_entry_codes__method__OcaOverlayPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_new__static_method__OcaAttr');
-  late final _wire_new__static_method__OcaAttr =
-      _wire_new__static_method__OcaAttrPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaOverlay>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_entry_codes__method__OcaOverlay');
+  late final _wire_entry_codes__method__OcaOverlay =
+      _wire_entry_codes__method__OcaOverlayPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaOverlay>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_set_attribute_type__method__OcaAttr(
+  void wire_formats__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    int attr_type,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _wire_set_attribute_type__method__OcaAttr(
+    return _wire_formats__method__OcaOverlay(
       port_,
       that,
-      attr_type,
     );
   }
 
-  late final _wire_set_attribute_type__method__OcaAttrPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
-              ffi.Int32)>>('wire_set_attribute_type__method__OcaAttr');
-  late final _wire_set_attribute_type__method__OcaAttr =
-      _wire_set_attribute_type__method__OcaAttrPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_OcaAttr>, int)>();
+  late final _wire_formats__method__OcaOverlayPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaOverlay>)>>(
+      'wire_formats__method__OcaOverlay');
+  late final _wire_formats__method__OcaOverlay =
+      _wire_formats__method__OcaOverlayPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_set_flagged__method__OcaAttr(
+  void wire_units__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _wire_set_flagged__method__OcaAttr(
+    return _wire_units__method__OcaOverlay(
       port_,
       that,
     );
   }
 
-  late final _wire_set_flagged__method__OcaAttrPtr = _lookup<
+  late final _wire_units__method__OcaOverlayPtr = _lookup<
       ffi.NativeFunction<
           ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_OcaAttr>)>>('wire_set_flagged__method__OcaAttr');
-  late final _wire_set_flagged__method__OcaAttr =
-      _wire_set_flagged__method__OcaAttrPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_OcaAttr>)>();
+              ffi.Pointer<wire_OcaOverlay>)>>('wire_units__method__OcaOverlay');
+  late final _wire_units__method__OcaOverlay =
+      _wire_units__method__OcaOverlayPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_set_encoding__method__OcaAttr(
+  void wire_conformances__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    int encoding,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _wire_set_encoding__method__OcaAttr(
+    return _wire_conformances__method__OcaOverlay(
       port_,
       that,
-      encoding,
     );
   }
 
-  late final _wire_set_encoding__method__OcaAttrPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
-              ffi.Int32)>>('wire_set_encoding__method__OcaAttr');
-  late final _wire_set_encoding__method__OcaAttr =
-      _wire_set_encoding__method__OcaAttrPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_OcaAttr>, int)>();
+  late final _wire_conformances__method__OcaOverlayPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaOverlay>)>>(
+      'wire_conformances__method__OcaOverlay');
+  late final _wire_conformances__method__OcaOverlay =
+      _wire_conformances__method__OcaOverlayPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_set_cardinality__method__OcaAttr(
+  void wire_cardinalities__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    ffi.Pointer<wire_uint_8_list> cardinality,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _wire_set_cardinality__method__OcaAttr(
+    return _wire_cardinalities__method__OcaOverlay(
       port_,
       that,
-      cardinality,
     );
   }
 
-  late final _wire_set_cardinality__method__OcaAttrPtr = _lookup<
+  late final _wire_cardinalities__method__OcaOverlayPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
-                  ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_set_cardinality__method__OcaAttr');
-  late final _wire_set_cardinality__method__OcaAttr =
-      _wire_set_cardinality__method__OcaAttrPtr.asFunction<
-          void Function(
-              int, ffi.Pointer<wire_OcaAttr>, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaOverlay>)>>(
+      'wire_cardinalities__method__OcaOverlay');
+  late final _wire_cardinalities__method__OcaOverlay =
+      _wire_cardinalities__method__OcaOverlayPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_set_conformance__method__OcaAttr(
+  void wire_meta__method__OcaOverlay(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    ffi.Pointer<wire_uint_8_list> conformance,
+    ffi.Pointer<wire_OcaOverlay> that,
   ) {
-    return _wire_set_conformance__method__OcaAttr(
+    return _wire_meta__method__OcaOverlay(
       port_,
       that,
-      conformance,
     );
   }
 
-  late final _wire_set_conformance__method__OcaAttrPtr = _lookup<
-          ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
-                  ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_set_conformance__method__OcaAttr');
-  late final _wire_set_conformance__method__OcaAttr =
-      _wire_set_conformance__method__OcaAttrPtr.asFunction<
-          void Function(
-              int, ffi.Pointer<wire_OcaAttr>, ffi.Pointer<wire_uint_8_list>)>();
+  late final _wire_meta__method__OcaOverlayPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64,
+              ffi.Pointer<wire_OcaOverlay>)>>('wire_meta__method__OcaOverlay');
+  late final _wire_meta__method__OcaOverlay = _wire_meta__method__OcaOverlayPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_OcaOverlay>)>();
 
-  void wire_set_label__method__OcaAttr(
+  void wire_name__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    ffi.Pointer<wire_uint_8_list> lang,
-    ffi.Pointer<wire_uint_8_list> label,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_set_label__method__OcaAttr(
+    return _wire_name__method__OcaAttributeDescriptor(
       port_,
       that,
-      lang,
-      label,
     );
   }
 
-  late final _wire_set_label__method__OcaAttrPtr = _lookup<
+  late final _wire_name__method__OcaAttributeDescriptorPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(
-                  ffi.Int64,
-                  ffi.Pointer<wire_OcaAttr>,
-                  ffi.Pointer<wire_uint_8_list>,
-                  ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_set_label__method__OcaAttr');
-  late final _wire_set_label__method__OcaAttr =
-      _wire_set_label__method__OcaAttrPtr.asFunction<
-          void Function(int, ffi.Pointer<wire_OcaAttr>,
-              ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_name__method__OcaAttributeDescriptor');
+  late final _wire_name__method__OcaAttributeDescriptor =
+      _wire_name__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_set_information__method__OcaAttr(
+  void wire_attribute_type__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    ffi.Pointer<wire_uint_8_list> lang,
-    ffi.Pointer<wire_uint_8_list> information,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_set_information__method__OcaAttr(
+    return _wire_attribute_type__method__OcaAttributeDescriptor(
       port_,
       that,
-      lang,
-      information,
     );
   }
 
-  late final _wire_set_information__method__OcaAttrPtr = _lookup<
+  late final _wire_attribute_type__method__OcaAttributeDescriptorPtr = _lookup<
           ffi.NativeFunction<
               ffi.Void Function(
-                  ffi.Int64,
-                  ffi.Pointer<wire_OcaAttr>,
-                  ffi.Pointer<wire_uint_8_list>,
-                  ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_set_information__method__OcaAttr');
-  late final _wire_set_information__method__OcaAttr =
-      _wire_set_information__method__OcaAttrPtr.asFunction<
-          void Function(int, ffi.Pointer<wire_OcaAttr>,
-              ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_uint_8_list>)>();
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_attribute_type__method__OcaAttributeDescriptor');
+  late final _wire_attribute_type__method__OcaAttributeDescriptor =
+      _wire_attribute_type__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_set_entry_codes__method__OcaAttr(
+  void wire_labels__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    ffi.Pointer<wire_StringList> entry_codes,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_set_entry_codes__method__OcaAttr(
+    return _wire_labels__method__OcaAttributeDescriptor(
       port_,
       that,
-      entry_codes,
     );
   }
 
-  late final _wire_set_entry_codes__method__OcaAttrPtr = _lookup<
+  late final _wire_labels__method__OcaAttributeDescriptorPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
-                  ffi.Pointer<wire_StringList>)>>(
-      'wire_set_entry_codes__method__OcaAttr');
-  late final _wire_set_entry_codes__method__OcaAttr =
-      _wire_set_entry_codes__method__OcaAttrPtr.asFunction<
-          void Function(
-              int, ffi.Pointer<wire_OcaAttr>, ffi.Pointer<wire_StringList>)>();
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_labels__method__OcaAttributeDescriptor');
+  late final _wire_labels__method__OcaAttributeDescriptor =
+      _wire_labels__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_set_entry_codes_sai__method__OcaAttr(
+  void wire_information__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    ffi.Pointer<wire_uint_8_list> sai,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_set_entry_codes_sai__method__OcaAttr(
+    return _wire_information__method__OcaAttributeDescriptor(
       port_,
       that,
-      sai,
     );
   }
 
-  late final _wire_set_entry_codes_sai__method__OcaAttrPtr = _lookup<
+  late final _wire_information__method__OcaAttributeDescriptorPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
-                  ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_set_entry_codes_sai__method__OcaAttr');
-  late final _wire_set_entry_codes_sai__method__OcaAttr =
-      _wire_set_entry_codes_sai__method__OcaAttrPtr.asFunction<
-          void Function(
-              int, ffi.Pointer<wire_OcaAttr>, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_information__method__OcaAttributeDescriptor');
+  late final _wire_information__method__OcaAttributeDescriptor =
+      _wire_information__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_set_entry__method__OcaAttr(
+  void wire_conformance__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    ffi.Pointer<wire_uint_8_list> lang,
-    ffi.Pointer<wire_OcaMap> entries,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_set_entry__method__OcaAttr(
+    return _wire_conformance__method__OcaAttributeDescriptor(
       port_,
       that,
-      lang,
-      entries,
     );
   }
 
-  late final _wire_set_entry__method__OcaAttrPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(
-              ffi.Int64,
-              ffi.Pointer<wire_OcaAttr>,
-              ffi.Pointer<wire_uint_8_list>,
-              ffi.Pointer<wire_OcaMap>)>>('wire_set_entry__method__OcaAttr');
-  late final _wire_set_entry__method__OcaAttr =
-      _wire_set_entry__method__OcaAttrPtr.asFunction<
-          void Function(int, ffi.Pointer<wire_OcaAttr>,
-              ffi.Pointer<wire_uint_8_list>, ffi.Pointer<wire_OcaMap>)>();
+  late final _wire_conformance__method__OcaAttributeDescriptorPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_conformance__method__OcaAttributeDescriptor');
+  late final _wire_conformance__method__OcaAttributeDescriptor =
+      _wire_conformance__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_set_unit_metric__method__OcaAttr(
+  void wire_cardinality__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    int unit,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_set_unit_metric__method__OcaAttr(
+    return _wire_cardinality__method__OcaAttributeDescriptor(
       port_,
       that,
-      unit,
     );
   }
 
-  late final _wire_set_unit_metric__method__OcaAttrPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
-              ffi.Int32)>>('wire_set_unit_metric__method__OcaAttr');
-  late final _wire_set_unit_metric__method__OcaAttr =
-      _wire_set_unit_metric__method__OcaAttrPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_OcaAttr>, int)>();
+  late final _wire_cardinality__method__OcaAttributeDescriptorPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_cardinality__method__OcaAttributeDescriptor');
+  late final _wire_cardinality__method__OcaAttributeDescriptor =
+      _wire_cardinality__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_set_unit_imperial__method__OcaAttr(
+  void wire_format__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    int unit,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_set_unit_imperial__method__OcaAttr(
+    return _wire_format__method__OcaAttributeDescriptor(
       port_,
       that,
-      unit,
     );
   }
 
-  late final _wire_set_unit_imperial__method__OcaAttrPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
-              ffi.Int32)>>('wire_set_unit_imperial__method__OcaAttr');
-  late final _wire_set_unit_imperial__method__OcaAttr =
-      _wire_set_unit_imperial__method__OcaAttrPtr
-          .asFunction<void Function(int, ffi.Pointer<wire_OcaAttr>, int)>();
+  late final _wire_format__method__OcaAttributeDescriptorPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_format__method__OcaAttributeDescriptor');
+  late final _wire_format__method__OcaAttributeDescriptor =
+      _wire_format__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_set_format__method__OcaAttr(
+  void wire_unit__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaAttr> that,
-    ffi.Pointer<wire_uint_8_list> format,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_set_format__method__OcaAttr(
+    return _wire_unit__method__OcaAttributeDescriptor(
       port_,
       that,
-      format,
     );
   }
 
-  late final _wire_set_format__method__OcaAttrPtr = _lookup<
+  late final _wire_unit__method__OcaAttributeDescriptorPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaAttr>,
-                  ffi.Pointer<wire_uint_8_list>)>>(
-      'wire_set_format__method__OcaAttr');
-  late final _wire_set_format__method__OcaAttr =
-      _wire_set_format__method__OcaAttrPtr.asFunction<
-          void Function(
-              int, ffi.Pointer<wire_OcaAttr>, ffi.Pointer<wire_uint_8_list>)>();
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_unit__method__OcaAttributeDescriptor');
+  late final _wire_unit__method__OcaAttributeDescriptor =
+      _wire_unit__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_to_json__method__OcaBundle(
+  void wire_character_encoding__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaBundle> that,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_to_json__method__OcaBundle(
+    return _wire_character_encoding__method__OcaAttributeDescriptor(
       port_,
       that,
     );
   }
 
-  late final _wire_to_json__method__OcaBundlePtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_OcaBundle>)>>('wire_to_json__method__OcaBundle');
-  late final _wire_to_json__method__OcaBundle =
-      _wire_to_json__method__OcaBundlePtr
-          .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
+  late final _wire_character_encoding__method__OcaAttributeDescriptorPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_character_encoding__method__OcaAttributeDescriptor');
+  late final _wire_character_encoding__method__OcaAttributeDescriptor =
+      _wire_character_encoding__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_said__method__OcaBundle(
+  void wire_standard__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaBundle> that,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_said__method__OcaBundle(
+    return _wire_standard__method__OcaAttributeDescriptor(
       port_,
       that,
     );
   }
 
-  late final _wire_said__method__OcaBundlePtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64,
-              ffi.Pointer<wire_OcaBundle>)>>('wire_said__method__OcaBundle');
-  late final _wire_said__method__OcaBundle = _wire_said__method__OcaBundlePtr
-      .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
+  late final _wire_standard__method__OcaAttributeDescriptorPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_standard__method__OcaAttributeDescriptor');
+  late final _wire_standard__method__OcaAttributeDescriptor =
+      _wire_standard__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_capture_base__method__OcaBundle(
+  void wire_entry_codes__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaBundle> that,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_capture_base__method__OcaBundle(
+    return _wire_entry_codes__method__OcaAttributeDescriptor(
       port_,
       that,
     );
   }
 
-  late final _wire_capture_base__method__OcaBundlePtr = _lookup<
+  late final _wire_entry_codes__method__OcaAttributeDescriptorPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>)>>(
-      'wire_capture_base__method__OcaBundle');
-  late final _wire_capture_base__method__OcaBundle =
-      _wire_capture_base__method__OcaBundlePtr
-          .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_entry_codes__method__OcaAttributeDescriptor');
+  late final _wire_entry_codes__method__OcaAttributeDescriptor =
+      _wire_entry_codes__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_overlays__method__OcaBundle(
+  void wire_entries__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaBundle> that,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
+    ffi.Pointer<wire_uint_8_list> lang,
   ) {
-    return _wire_overlays__method__OcaBundle(
+    return _wire_entries__method__OcaAttributeDescriptor(
       port_,
       that,
+      lang,
     );
   }
 
-  late final _wire_overlays__method__OcaBundlePtr = _lookup<
+  late final _wire_entries__method__OcaAttributeDescriptorPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaBundle>)>>(
-      'wire_overlays__method__OcaBundle');
-  late final _wire_overlays__method__OcaBundle =
-      _wire_overlays__method__OcaBundlePtr
-          .asFunction<void Function(int, ffi.Pointer<wire_OcaBundle>)>();
+              ffi.Void Function(
+                  ffi.Int64,
+                  ffi.Pointer<wire_OcaAttributeDescriptor>,
+                  ffi.Pointer<wire_uint_8_list>)>>(
+      'wire_entries__method__OcaAttributeDescriptor');
+  late final _wire_entries__method__OcaAttributeDescriptor =
+      _wire_entries__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>,
+              ffi.Pointer<wire_uint_8_list>)>();
 
-  void wire_attributes__method__OcaCaptureBase(
+  void wire_sensitive__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaCaptureBase> that,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_attributes__method__OcaCaptureBase(
+    return _wire_sensitive__method__OcaAttributeDescriptor(
       port_,
       that,
     );
   }
 
-  late final _wire_attributes__method__OcaCaptureBasePtr = _lookup<
+  late final _wire_sensitive__method__OcaAttributeDescriptorPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaCaptureBase>)>>(
-      'wire_attributes__method__OcaCaptureBase');
-  late final _wire_attributes__method__OcaCaptureBase =
-      _wire_attributes__method__OcaCaptureBasePtr
-          .asFunction<void Function(int, ffi.Pointer<wire_OcaCaptureBase>)>();
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_sensitive__method__OcaAttributeDescriptor');
+  late final _wire_sensitive__method__OcaAttributeDescriptor =
+      _wire_sensitive__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
-  void wire_flagged_attributes__method__OcaCaptureBase(
+  void wire_to_json__method__OcaAttributeDescriptor(
     int port_,
-    ffi.Pointer<wire_OcaCaptureBase> that,
+    ffi.Pointer<wire_OcaAttributeDescriptor> that,
   ) {
-    return _wire_flagged_attributes__method__OcaCaptureBase(
+    return _wire_to_json__method__OcaAttributeDescriptor(
       port_,
       that,
     );
   }
 
-  late final _wire_flagged_attributes__method__OcaCaptureBasePtr = _lookup<
+  late final _wire_to_json__method__OcaAttributeDescriptorPtr = _lookup<
           ffi.NativeFunction<
-              ffi.Void Function(ffi.Int64, ffi.Pointer<wire_OcaCaptureBase>)>>(
-      'wire_flagged_attributes__method__OcaCaptureBase');
-  late final _wire_flagged_attributes__method__OcaCaptureBase =
-      _wire_flagged_attributes__method__OcaCaptureBasePtr
-          .asFunction<void Function(int, ffi.Pointer<wire_OcaCaptureBase>)>();
+              ffi.Void Function(
+                  ffi.Int64, ffi.Pointer<wire_OcaAttributeDescriptor>)>>(
+      'wire_to_json__method__OcaAttributeDescriptor');
+  late final _wire_to_json__method__OcaAttributeDescriptor =
+      _wire_to_json__method__OcaAttributeDescriptorPtr.asFunction<
+          void Function(int, ffi.Pointer<wire_OcaAttributeDescriptor>)>();
 
   void wire_new__static_method__OcaMap(
     int port_,
@@ -2435,6 +4879,17 @@ class OcaDartWire implements FlutterRustBridgeWireBase {
   late final _new_MutexOcaAttrRaw =
       _new_MutexOcaAttrRawPtr.asFunction<wire_MutexOcaAttrRaw Function()>();
 
+  wire_MutexOcaAttributeDescriptorRaw new_MutexOcaAttributeDescriptorRaw() {
+    return _new_MutexOcaAttributeDescriptorRaw();
+  }
+
+  late final _new_MutexOcaAttributeDescriptorRawPtr = _lookup<
+          ffi.NativeFunction<wire_MutexOcaAttributeDescriptorRaw Function()>>(
+      'new_MutexOcaAttributeDescriptorRaw');
+  late final _new_MutexOcaAttributeDescriptorRaw =
+      _new_MutexOcaAttributeDescriptorRawPtr
+          .asFunction<wire_MutexOcaAttributeDescriptorRaw Function()>();
+
   wire_MutexOcaBoxRaw new_MutexOcaBoxRaw() {
     return _new_MutexOcaBoxRaw();
   }
@@ -2465,6 +4920,16 @@ class OcaDartWire implements FlutterRustBridgeWireBase {
   late final _new_MutexOcaCaptureBaseRaw = _new_MutexOcaCaptureBaseRawPtr
       .asFunction<wire_MutexOcaCaptureBaseRaw Function()>();
 
+  wire_MutexOcaOverlayRaw new_MutexOcaOverlayRaw() {
+    return _new_MutexOcaOverlayRaw();
+  }
+
+  late final _new_MutexOcaOverlayRawPtr =
+      _lookup<ffi.NativeFunction<wire_MutexOcaOverlayRaw Function()>>(
+          'new_MutexOcaOverlayRaw');
+  late final _new_MutexOcaOverlayRaw = _new_MutexOcaOverlayRawPtr
+      .asFunction<wire_MutexOcaOverlayRaw Function()>();
+
   wire_MutexStringMap new_MutexStringMap() {
     return _new_MutexStringMap();
   }
@@ -2475,6 +4940,16 @@ class OcaDartWire implements FlutterRustBridgeWireBase {
   late final _new_MutexStringMap =
       _new_MutexStringMapPtr.asFunction<wire_MutexStringMap Function()>();
 
+  wire_OcaRegistryRaw new_OcaRegistryRaw() {
+    return _new_OcaRegistryRaw();
+  }
+
+  late final _new_OcaRegistryRawPtr =
+      _lookup<ffi.NativeFunction<wire_OcaRegistryRaw Function()>>(
+          'new_OcaRegistryRaw');
+  late final _new_OcaRegistryRaw =
+      _new_OcaRegistryRawPtr.asFunction<wire_OcaRegistryRaw Function()>();
+
   ffi.Pointer<wire_StringList> new_StringList_0(
     int len,
   ) {
@@ -2499,6 +4974,18 @@ class OcaDartWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_oca_attr_0 = _new_box_autoadd_oca_attr_0Ptr
       .asFunction<ffi.Pointer<wire_OcaAttr> Function()>();
 
+  ffi.Pointer<wire_OcaAttributeDescriptor> new_box_autoadd_oca_attribute_descriptor_0() {
+    return _new_box_autoadd_oca_attribute_descriptor_0();
+  }
+
+  late final _new_box_autoadd_oca_attribute_descriptor_0Ptr = _lookup<
+          ffi
+          .NativeFunction<ffi.Pointer<wire_OcaAttributeDescriptor> Function()>>(
+      'new_box_autoadd_oca_attribute_descriptor_0');
+  late final _new_box_autoadd_oca_attribute_descriptor_0 =
+      _new_box_autoadd_oca_attribute_descriptor_0Ptr
+          .asFunction<ffi.Pointer<wire_OcaAttributeDescriptor> Function()>();
+
   ffi.Pointer<wire_OcaBox> new_box_autoadd_oca_box_0() {
     return _new_box_autoadd_oca_box_0();
   }
@@ -2540,6 +5027,27 @@ class OcaDartWire implements FlutterRustBridgeWireBase {
   late final _new_box_autoadd_oca_map_0 = _new_box_autoadd_oca_map_0Ptr
       .asFunction<ffi.Pointer<wire_OcaMap> Function()>();
 
+  ffi.Pointer<wire_OcaOverlay> new_box_autoadd_oca_overlay_0() {
+    return _new_box_autoadd_oca_overlay_0();
+  }
+
+  late final _new_box_autoadd_oca_overlay_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_OcaOverlay> Function()>>(
+          'new_box_autoadd_oca_overlay_0');
+  late final _new_box_autoadd_oca_overlay_0 = _new_box_autoadd_oca_overlay_0Ptr
+      .asFunction<ffi.Pointer<wire_OcaOverlay> Function()>();
+
+  ffi.Pointer<wire_OcaRegistry> new_box_autoadd_oca_registry_0() {
+    return _new_box_autoadd_oca_registry_0();
+  }
+
+  late final _new_box_autoadd_oca_registry_0Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_OcaRegistry> Function()>>(
+          'new_box_autoadd_oca_registry_0');
+  late final _new_box_autoadd_oca_registry_0 =
+      _new_box_autoadd_oca_registry_0Ptr
+          .asFunction<ffi.Pointer<wire_OcaRegistry> Function()>();
+
   ffi.Pointer<wire_uint_8_list> new_uint_8_list_0(
     int len,
   ) {
@@ -2555,63 +5063,65 @@ class OcaDartWire implements FlutterRustBridgeWireBase {
   late final _new_uint_8_list_0 = _new_uint_8_list_0Ptr
       .asFunction<ffi.Pointer<wire_uint_8_list> Function(int)>();
 
-  void drop_opaque_MutexDynOverlay(
+  void drop_opaque_MutexOcaAttrRaw(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _drop_opaque_MutexDynOverlay(
+    return _drop_opaque_MutexOcaAttrRaw(
       ptr,
     );
   }
 
-  late final _drop_opaque_MutexDynOverlayPtr =
+  late final _drop_opaque_MutexOcaAttrRawPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'drop_opaque_MutexDynOverlay');
-  late final _drop_opaque_MutexDynOverlay = _drop_opaque_MutexDynOverlayPtr
+          'drop_opaque_MutexOcaAttrRaw');
+  late final _drop_opaque_MutexOcaAttrRaw = _drop_opaque_MutexOcaAttrRawPtr
       .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  ffi.Pointer<ffi.Void> share_opaque_MutexDynOverlay(
+  ffi.Pointer<ffi.Void> share_opaque_MutexOcaAttrRaw(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _share_opaque_MutexDynOverlay(
+    return _share_opaque_MutexOcaAttrRaw(
       ptr,
     );
   }
 
-  late final _share_opaque_MutexDynOverlayPtr = _lookup<
+  late final _share_opaque_MutexOcaAttrRawPtr = _lookup<
       ffi.NativeFunction<
           ffi.Pointer<ffi.Void> Function(
-              ffi.Pointer<ffi.Void>)>>('share_opaque_MutexDynOverlay');
-  late final _share_opaque_MutexDynOverlay = _share_opaque_MutexDynOverlayPtr
+              ffi.Pointer<ffi.Void>)>>('share_opaque_MutexOcaAttrRaw');
+  late final _share_opaque_MutexOcaAttrRaw = _share_opaque_MutexOcaAttrRawPtr
       .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
-  void drop_opaque_MutexOcaAttrRaw(
+  void drop_opaque_MutexOcaAttributeDescriptorRaw(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _drop_opaque_MutexOcaAttrRaw(
+    return _drop_opaque_MutexOcaAttributeDescriptorRaw(
       ptr,
     );
   }
 
-  late final _drop_opaque_MutexOcaAttrRawPtr =
+  late final _drop_opaque_MutexOcaAttributeDescriptorRawPtr =
       _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
-          'drop_opaque_MutexOcaAttrRaw');
-  late final _drop_opaque_MutexOcaAttrRaw = _drop_opaque_MutexOcaAttrRawPtr
-      .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+          'drop_opaque_MutexOcaAttributeDescriptorRaw');
+  late final _drop_opaque_MutexOcaAttributeDescriptorRaw =
+      _drop_opaque_MutexOcaAttributeDescriptorRawPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
-  ffi.Pointer<ffi.Void> share_opaque_MutexOcaAttrRaw(
+  ffi.Pointer<ffi.Void> share_opaque_MutexOcaAttributeDescriptorRaw(
     ffi.Pointer<ffi.Void> ptr,
   ) {
-    return _share_opaque_MutexOcaAttrRaw(
+    return _share_opaque_MutexOcaAttributeDescriptorRaw(
       ptr,
     );
   }
 
-  late final _share_opaque_MutexOcaAttrRawPtr = _lookup<
-      ffi.NativeFunction<
-          ffi.Pointer<ffi.Void> Function(
-              ffi.Pointer<ffi.Void>)>>('share_opaque_MutexOcaAttrRaw');
-  late final _share_opaque_MutexOcaAttrRaw = _share_opaque_MutexOcaAttrRawPtr
-      .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+  late final _share_opaque_MutexOcaAttributeDescriptorRawPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>>(
+      'share_opaque_MutexOcaAttributeDescriptorRaw');
+  late final _share_opaque_MutexOcaAttributeDescriptorRaw =
+      _share_opaque_MutexOcaAttributeDescriptorRawPtr
+          .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
   void drop_opaque_MutexOcaBoxRaw(
     ffi.Pointer<ffi.Void> ptr,
@@ -2703,6 +5213,37 @@ class OcaDartWire implements FlutterRustBridgeWireBase {
       _share_opaque_MutexOcaCaptureBaseRawPtr
           .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  void drop_opaque_MutexOcaOverlayRaw(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_MutexOcaOverlayRaw(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_MutexOcaOverlayRawPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'drop_opaque_MutexOcaOverlayRaw');
+  late final _drop_opaque_MutexOcaOverlayRaw =
+      _drop_opaque_MutexOcaOverlayRawPtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_MutexOcaOverlayRaw(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_MutexOcaOverlayRaw(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_MutexOcaOverlayRawPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<ffi.Void> Function(
+              ffi.Pointer<ffi.Void>)>>('share_opaque_MutexOcaOverlayRaw');
+  late final _share_opaque_MutexOcaOverlayRaw =
+      _share_opaque_MutexOcaOverlayRawPtr
+          .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void drop_opaque_MutexStringMap(
     ffi.Pointer<ffi.Void> ptr,
   ) {
@@ -2732,6 +5273,35 @@ class OcaDartWire implements FlutterRustBridgeWireBase {
   late final _share_opaque_MutexStringMap = _share_opaque_MutexStringMapPtr
       .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
 
+  void drop_opaque_OcaRegistryRaw(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _drop_opaque_OcaRegistryRaw(
+      ptr,
+    );
+  }
+
+  late final _drop_opaque_OcaRegistryRawPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'drop_opaque_OcaRegistryRaw');
+  late final _drop_opaque_OcaRegistryRaw = _drop_opaque_OcaRegistryRawPtr
+      .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
+
+  ffi.Pointer<ffi.Void> share_opaque_OcaRegistryRaw(
+    ffi.Pointer<ffi.Void> ptr,
+  ) {
+    return _share_opaque_OcaRegistryRaw(
+      ptr,
+    );
+  }
+
+  late final _share_opaque_OcaRegistryRawPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<ffi.Void> Function(
+              ffi.Pointer<ffi.Void>)>>('share_opaque_OcaRegistryRaw');
+  late final _share_opaque_OcaRegistryRaw = _share_opaque_OcaRegistryRawPtr
+      .asFunction<ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>)>();
+
   void free_WireSyncReturn(
     WireSyncReturn ptr,
   ) {
@@ -2756,6 +5326,22 @@ final class wire_uint_8_list extends ffi.Struct {
   external int len;
 }
 
+final class wire_OcaRegistryRaw extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+final class wire_OcaRegistry extends ffi.Struct {
+  external wire_OcaRegistryRaw field0;
+}
+
+final class wire_MutexOcaBundleRaw extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+final class wire_OcaBundle extends ffi.Struct {
+  external wire_MutexOcaBundleRaw field0;
+}
+
 final class wire_MutexOcaBoxRaw extends ffi.Struct {
   external ffi.Pointer<ffi.Void> ptr;
 }
@@ -2787,20 +5373,28 @@ final class wire_OcaMap extends ffi.Struct {
   external wire_MutexStringMap field0;
 }
 
-final class wire_MutexOcaBundleRaw extends ffi.Struct {
+final class wire_MutexOcaCaptureBaseRaw extends ffi.Struct {
   external ffi.Pointer<ffi.Void> ptr;
 }
 
-final class wire_OcaBundle extends ffi.Struct {
-  external wire_MutexOcaBundleRaw field0;
+final class wire_OcaCaptureBase extends ffi.Struct {
+  external wire_MutexOcaCaptureBaseRaw field0;
 }
 
-final class wire_MutexOcaCaptureBaseRaw extends ffi.Struct {
+final class wire_MutexOcaOverlayRaw extends ffi.Struct {
   external ffi.Pointer<ffi.Void> ptr;
 }
 
-final class wire_OcaCaptureBase extends ffi.Struct {
-  external wire_MutexOcaCaptureBaseRaw field0;
+final class wire_OcaOverlay extends ffi.Struct {
+  external wire_MutexOcaOverlayRaw field0;
+}
+
+final class wire_MutexOcaAttributeDescriptorRaw extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ptr;
+}
+
+final class wire_OcaAttributeDescriptor extends ffi.Struct {
+  external wire_MutexOcaAttributeDescriptorRaw field0;
 }
 
 typedef DartPostCObjectFnType = ffi.Pointer<
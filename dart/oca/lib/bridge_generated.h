@@ -14,6 +14,22 @@ typedef struct wire_uint_8_list {
   int32_t len;
 } wire_uint_8_list;
 
+typedef struct wire_OcaRegistryRaw {
+  const void *ptr;
+} wire_OcaRegistryRaw;
+
+typedef struct wire_OcaRegistry {
+  struct wire_OcaRegistryRaw field0;
+} wire_OcaRegistry;
+
+typedef struct wire_MutexOcaBundleRaw {
+  const void *ptr;
+} wire_MutexOcaBundleRaw;
+
+typedef struct wire_OcaBundle {
+  struct wire_MutexOcaBundleRaw field0;
+} wire_OcaBundle;
+
 typedef struct wire_MutexOcaBoxRaw {
   const void *ptr;
 } wire_MutexOcaBoxRaw;
@@ -43,14 +59,6 @@ typedef struct wire_OcaMap {
   struct wire_MutexStringMap field0;
 } wire_OcaMap;
 
-typedef struct wire_MutexOcaBundleRaw {
-  const void *ptr;
-} wire_MutexOcaBundleRaw;
-
-typedef struct wire_OcaBundle {
-  struct wire_MutexOcaBundleRaw field0;
-} wire_OcaBundle;
-
 typedef struct wire_MutexOcaCaptureBaseRaw {
   const void *ptr;
 } wire_MutexOcaCaptureBaseRaw;
@@ -59,6 +67,22 @@ typedef struct wire_OcaCaptureBase {
   struct wire_MutexOcaCaptureBaseRaw field0;
 } wire_OcaCaptureBase;
 
+typedef struct wire_MutexOcaOverlayRaw {
+  const void *ptr;
+} wire_MutexOcaOverlayRaw;
+
+typedef struct wire_OcaOverlay {
+  struct wire_MutexOcaOverlayRaw field0;
+} wire_OcaOverlay;
+
+typedef struct wire_MutexOcaAttributeDescriptorRaw {
+  const void *ptr;
+} wire_MutexOcaAttributeDescriptorRaw;
+
+typedef struct wire_OcaAttributeDescriptor {
+  struct wire_MutexOcaAttributeDescriptorRaw field0;
+} wire_OcaAttributeDescriptor;
+
 typedef struct DartCObject *WireSyncReturn;
 
 void store_dart_post_cobject(DartPostCObjectFnType ptr);
@@ -71,7 +95,27 @@ uintptr_t new_dart_opaque(Dart_Handle handle);
 
 intptr_t init_frb_dart_api_dl(void *obj);
 
-void wire_load_oca(int64_t port_, struct wire_uint_8_list *json);
+void wire_load_oca(int64_t port_, struct wire_uint_8_list *json, struct wire_OcaRegistry *registry);
+
+void wire_is_legacy_bundle(int64_t port_, struct wire_uint_8_list *json);
+
+void wire_parse_ocafile(int64_t port_,
+                        struct wire_uint_8_list *ocafile,
+                        struct wire_OcaRegistry *registry);
+
+void wire_build_from_ocafile(int64_t port_,
+                             struct wire_uint_8_list *ocafile,
+                             struct wire_OcaRegistry *registry,
+                             struct wire_OcaBundle *base);
+
+void wire_generate_ocafile(int64_t port_, struct wire_OcaBundle *bundle);
+
+void wire_verify_bundle(int64_t port_, struct wire_uint_8_list *json);
+
+void wire_new__static_method__OcaRegistry(int64_t port_);
+
+void wire_from_overlay_file__static_method__OcaRegistry(int64_t port_,
+                                                        struct wire_uint_8_list *overlay_file);
 
 void wire_new__static_method__OcaBox(int64_t port_);
 
@@ -85,15 +129,9 @@ void wire_add_attribute__method__OcaBox(int64_t port_,
                                         struct wire_OcaBox *that,
                                         struct wire_OcaAttr *attr);
 
-void wire_generate_bundle__method__OcaBox(int64_t port_, struct wire_OcaBox *that);
-
-void wire_add_form_layout__method__OcaBox(int64_t port_,
+void wire_generate_bundle__method__OcaBox(int64_t port_,
                                           struct wire_OcaBox *that,
-                                          struct wire_uint_8_list *layout);
-
-void wire_add_credential_layout__method__OcaBox(int64_t port_,
-                                                struct wire_OcaBox *that,
-                                                struct wire_uint_8_list *layout);
+                                          struct wire_OcaRegistry *registry);
 
 void wire_new__static_method__OcaAttr(int64_t port_, struct wire_uint_8_list *name);
 
@@ -101,6 +139,10 @@ void wire_set_attribute_type__method__OcaAttr(int64_t port_,
                                               struct wire_OcaAttr *that,
                                               int32_t attr_type);
 
+void wire_set_attribute_type_spelling__method__OcaAttr(int64_t port_,
+                                                       struct wire_OcaAttr *that,
+                                                       struct wire_uint_8_list *spelling);
+
 void wire_set_flagged__method__OcaAttr(int64_t port_, struct wire_OcaAttr *that);
 
 void wire_set_encoding__method__OcaAttr(int64_t port_, struct wire_OcaAttr *that, int32_t encoding);
@@ -127,20 +169,14 @@ void wire_set_entry_codes__method__OcaAttr(int64_t port_,
                                            struct wire_OcaAttr *that,
                                            struct wire_StringList *entry_codes);
 
-void wire_set_entry_codes_sai__method__OcaAttr(int64_t port_,
-                                               struct wire_OcaAttr *that,
-                                               struct wire_uint_8_list *sai);
-
 void wire_set_entry__method__OcaAttr(int64_t port_,
                                      struct wire_OcaAttr *that,
                                      struct wire_uint_8_list *lang,
                                      struct wire_OcaMap *entries);
 
-void wire_set_unit_metric__method__OcaAttr(int64_t port_, struct wire_OcaAttr *that, int32_t unit);
-
-void wire_set_unit_imperial__method__OcaAttr(int64_t port_,
-                                             struct wire_OcaAttr *that,
-                                             int32_t unit);
+void wire_set_unit__method__OcaAttr(int64_t port_,
+                                    struct wire_OcaAttr *that,
+                                    struct wire_uint_8_list *unit);
 
 void wire_set_format__method__OcaAttr(int64_t port_,
                                       struct wire_OcaAttr *that,
@@ -154,11 +190,110 @@ void wire_capture_base__method__OcaBundle(int64_t port_, struct wire_OcaBundle *
 
 void wire_overlays__method__OcaBundle(int64_t port_, struct wire_OcaBundle *that);
 
+void wire_languages__method__OcaBundle(int64_t port_, struct wire_OcaBundle *that);
+
+void wire_keep_languages__method__OcaBundle(int64_t port_,
+                                            struct wire_OcaBundle *that,
+                                            struct wire_StringList *keep);
+
+void wire_extract_translation__method__OcaBundle(int64_t port_,
+                                                 struct wire_OcaBundle *that,
+                                                 struct wire_uint_8_list *language);
+
+void wire_merge_translation__method__OcaBundle(int64_t port_,
+                                               struct wire_OcaBundle *that,
+                                               struct wire_uint_8_list *translation);
+
+void wire_attributes__method__OcaBundle(int64_t port_, struct wire_OcaBundle *that);
+
+void wire_upgrade_notes__method__OcaBundle(int64_t port_, struct wire_OcaBundle *that);
+
+void wire_validate_semantics__method__OcaBundle(int64_t port_, struct wire_OcaBundle *that);
+
+void wire_validate_data__method__OcaBundle(int64_t port_,
+                                           struct wire_OcaBundle *that,
+                                           struct wire_uint_8_list *record);
+
 void wire_attributes__method__OcaCaptureBase(int64_t port_, struct wire_OcaCaptureBase *that);
 
 void wire_flagged_attributes__method__OcaCaptureBase(int64_t port_,
                                                      struct wire_OcaCaptureBase *that);
 
+void wire_overlay_type__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_name__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_language__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_said__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_capture_base__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_to_json__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_labels__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_entries__method__OcaOverlay(int64_t port_,
+                                      struct wire_OcaOverlay *that,
+                                      struct wire_uint_8_list *attribute);
+
+void wire_entry_codes__method__OcaOverlay(int64_t port_,
+                                          struct wire_OcaOverlay *that,
+                                          struct wire_uint_8_list *attribute);
+
+void wire_formats__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_units__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_conformances__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_cardinalities__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_meta__method__OcaOverlay(int64_t port_, struct wire_OcaOverlay *that);
+
+void wire_name__method__OcaAttributeDescriptor(int64_t port_,
+                                               struct wire_OcaAttributeDescriptor *that);
+
+void wire_attribute_type__method__OcaAttributeDescriptor(int64_t port_,
+                                                         struct wire_OcaAttributeDescriptor *that);
+
+void wire_labels__method__OcaAttributeDescriptor(int64_t port_,
+                                                 struct wire_OcaAttributeDescriptor *that);
+
+void wire_information__method__OcaAttributeDescriptor(int64_t port_,
+                                                      struct wire_OcaAttributeDescriptor *that);
+
+void wire_conformance__method__OcaAttributeDescriptor(int64_t port_,
+                                                      struct wire_OcaAttributeDescriptor *that);
+
+void wire_cardinality__method__OcaAttributeDescriptor(int64_t port_,
+                                                      struct wire_OcaAttributeDescriptor *that);
+
+void wire_format__method__OcaAttributeDescriptor(int64_t port_,
+                                                 struct wire_OcaAttributeDescriptor *that);
+
+void wire_unit__method__OcaAttributeDescriptor(int64_t port_,
+                                               struct wire_OcaAttributeDescriptor *that);
+
+void wire_character_encoding__method__OcaAttributeDescriptor(int64_t port_,
+                                                             struct wire_OcaAttributeDescriptor *that);
+
+void wire_standard__method__OcaAttributeDescriptor(int64_t port_,
+                                                   struct wire_OcaAttributeDescriptor *that);
+
+void wire_entry_codes__method__OcaAttributeDescriptor(int64_t port_,
+                                                      struct wire_OcaAttributeDescriptor *that);
+
+void wire_entries__method__OcaAttributeDescriptor(int64_t port_,
+                                                  struct wire_OcaAttributeDescriptor *that,
+                                                  struct wire_uint_8_list *lang);
+
+void wire_sensitive__method__OcaAttributeDescriptor(int64_t port_,
+                                                    struct wire_OcaAttributeDescriptor *that);
+
+void wire_to_json__method__OcaAttributeDescriptor(int64_t port_,
+                                                  struct wire_OcaAttributeDescriptor *that);
+
 void wire_new__static_method__OcaMap(int64_t port_);
 
 void wire_insert__method__OcaMap(int64_t port_,
@@ -178,18 +313,26 @@ void wire_get_keys__method__OcaMap(int64_t port_, struct wire_OcaMap *that);
 
 struct wire_MutexOcaAttrRaw new_MutexOcaAttrRaw(void);
 
+struct wire_MutexOcaAttributeDescriptorRaw new_MutexOcaAttributeDescriptorRaw(void);
+
 struct wire_MutexOcaBoxRaw new_MutexOcaBoxRaw(void);
 
 struct wire_MutexOcaBundleRaw new_MutexOcaBundleRaw(void);
 
 struct wire_MutexOcaCaptureBaseRaw new_MutexOcaCaptureBaseRaw(void);
 
+struct wire_MutexOcaOverlayRaw new_MutexOcaOverlayRaw(void);
+
 struct wire_MutexStringMap new_MutexStringMap(void);
 
+struct wire_OcaRegistryRaw new_OcaRegistryRaw(void);
+
 struct wire_StringList *new_StringList_0(int32_t len);
 
 struct wire_OcaAttr *new_box_autoadd_oca_attr_0(void);
 
+struct wire_OcaAttributeDescriptor *new_box_autoadd_oca_attribute_descriptor_0(void);
+
 struct wire_OcaBox *new_box_autoadd_oca_box_0(void);
 
 struct wire_OcaBundle *new_box_autoadd_oca_bundle_0(void);
@@ -198,16 +341,20 @@ struct wire_OcaCaptureBase *new_box_autoadd_oca_capture_base_0(void);
 
 struct wire_OcaMap *new_box_autoadd_oca_map_0(void);
 
-struct wire_uint_8_list *new_uint_8_list_0(int32_t len);
+struct wire_OcaOverlay *new_box_autoadd_oca_overlay_0(void);
 
-void drop_opaque_MutexDynOverlay(const void *ptr);
+struct wire_OcaRegistry *new_box_autoadd_oca_registry_0(void);
 
-const void *share_opaque_MutexDynOverlay(const void *ptr);
+struct wire_uint_8_list *new_uint_8_list_0(int32_t len);
 
 void drop_opaque_MutexOcaAttrRaw(const void *ptr);
 
 const void *share_opaque_MutexOcaAttrRaw(const void *ptr);
 
+void drop_opaque_MutexOcaAttributeDescriptorRaw(const void *ptr);
+
+const void *share_opaque_MutexOcaAttributeDescriptorRaw(const void *ptr);
+
 void drop_opaque_MutexOcaBoxRaw(const void *ptr);
 
 const void *share_opaque_MutexOcaBoxRaw(const void *ptr);
@@ -220,23 +367,37 @@ void drop_opaque_MutexOcaCaptureBaseRaw(const void *ptr);
 
 const void *share_opaque_MutexOcaCaptureBaseRaw(const void *ptr);
 
+void drop_opaque_MutexOcaOverlayRaw(const void *ptr);
+
+const void *share_opaque_MutexOcaOverlayRaw(const void *ptr);
+
 void drop_opaque_MutexStringMap(const void *ptr);
 
 const void *share_opaque_MutexStringMap(const void *ptr);
 
+void drop_opaque_OcaRegistryRaw(const void *ptr);
+
+const void *share_opaque_OcaRegistryRaw(const void *ptr);
+
 void free_WireSyncReturn(WireSyncReturn ptr);
 
 static int64_t dummy_method_to_enforce_bundling(void) {
     int64_t dummy_var = 0;
     dummy_var ^= ((int64_t) (void*) wire_load_oca);
+    dummy_var ^= ((int64_t) (void*) wire_is_legacy_bundle);
+    dummy_var ^= ((int64_t) (void*) wire_parse_ocafile);
+    dummy_var ^= ((int64_t) (void*) wire_build_from_ocafile);
+    dummy_var ^= ((int64_t) (void*) wire_generate_ocafile);
+    dummy_var ^= ((int64_t) (void*) wire_verify_bundle);
+    dummy_var ^= ((int64_t) (void*) wire_new__static_method__OcaRegistry);
+    dummy_var ^= ((int64_t) (void*) wire_from_overlay_file__static_method__OcaRegistry);
     dummy_var ^= ((int64_t) (void*) wire_new__static_method__OcaBox);
     dummy_var ^= ((int64_t) (void*) wire_add_meta__method__OcaBox);
     dummy_var ^= ((int64_t) (void*) wire_add_attribute__method__OcaBox);
     dummy_var ^= ((int64_t) (void*) wire_generate_bundle__method__OcaBox);
-    dummy_var ^= ((int64_t) (void*) wire_add_form_layout__method__OcaBox);
-    dummy_var ^= ((int64_t) (void*) wire_add_credential_layout__method__OcaBox);
     dummy_var ^= ((int64_t) (void*) wire_new__static_method__OcaAttr);
     dummy_var ^= ((int64_t) (void*) wire_set_attribute_type__method__OcaAttr);
+    dummy_var ^= ((int64_t) (void*) wire_set_attribute_type_spelling__method__OcaAttr);
     dummy_var ^= ((int64_t) (void*) wire_set_flagged__method__OcaAttr);
     dummy_var ^= ((int64_t) (void*) wire_set_encoding__method__OcaAttr);
     dummy_var ^= ((int64_t) (void*) wire_set_cardinality__method__OcaAttr);
@@ -244,46 +405,90 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) wire_set_label__method__OcaAttr);
     dummy_var ^= ((int64_t) (void*) wire_set_information__method__OcaAttr);
     dummy_var ^= ((int64_t) (void*) wire_set_entry_codes__method__OcaAttr);
-    dummy_var ^= ((int64_t) (void*) wire_set_entry_codes_sai__method__OcaAttr);
     dummy_var ^= ((int64_t) (void*) wire_set_entry__method__OcaAttr);
-    dummy_var ^= ((int64_t) (void*) wire_set_unit_metric__method__OcaAttr);
-    dummy_var ^= ((int64_t) (void*) wire_set_unit_imperial__method__OcaAttr);
+    dummy_var ^= ((int64_t) (void*) wire_set_unit__method__OcaAttr);
     dummy_var ^= ((int64_t) (void*) wire_set_format__method__OcaAttr);
     dummy_var ^= ((int64_t) (void*) wire_to_json__method__OcaBundle);
     dummy_var ^= ((int64_t) (void*) wire_said__method__OcaBundle);
     dummy_var ^= ((int64_t) (void*) wire_capture_base__method__OcaBundle);
     dummy_var ^= ((int64_t) (void*) wire_overlays__method__OcaBundle);
+    dummy_var ^= ((int64_t) (void*) wire_languages__method__OcaBundle);
+    dummy_var ^= ((int64_t) (void*) wire_keep_languages__method__OcaBundle);
+    dummy_var ^= ((int64_t) (void*) wire_extract_translation__method__OcaBundle);
+    dummy_var ^= ((int64_t) (void*) wire_merge_translation__method__OcaBundle);
+    dummy_var ^= ((int64_t) (void*) wire_attributes__method__OcaBundle);
+    dummy_var ^= ((int64_t) (void*) wire_upgrade_notes__method__OcaBundle);
+    dummy_var ^= ((int64_t) (void*) wire_validate_semantics__method__OcaBundle);
+    dummy_var ^= ((int64_t) (void*) wire_validate_data__method__OcaBundle);
     dummy_var ^= ((int64_t) (void*) wire_attributes__method__OcaCaptureBase);
     dummy_var ^= ((int64_t) (void*) wire_flagged_attributes__method__OcaCaptureBase);
+    dummy_var ^= ((int64_t) (void*) wire_overlay_type__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_name__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_language__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_said__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_capture_base__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_to_json__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_labels__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_entries__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_entry_codes__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_formats__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_units__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_conformances__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_cardinalities__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_meta__method__OcaOverlay);
+    dummy_var ^= ((int64_t) (void*) wire_name__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_attribute_type__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_labels__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_information__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_conformance__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_cardinality__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_format__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_unit__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_character_encoding__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_standard__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_entry_codes__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_entries__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_sensitive__method__OcaAttributeDescriptor);
+    dummy_var ^= ((int64_t) (void*) wire_to_json__method__OcaAttributeDescriptor);
     dummy_var ^= ((int64_t) (void*) wire_new__static_method__OcaMap);
     dummy_var ^= ((int64_t) (void*) wire_insert__method__OcaMap);
     dummy_var ^= ((int64_t) (void*) wire_get__method__OcaMap);
     dummy_var ^= ((int64_t) (void*) wire_remove__method__OcaMap);
     dummy_var ^= ((int64_t) (void*) wire_get_keys__method__OcaMap);
     dummy_var ^= ((int64_t) (void*) new_MutexOcaAttrRaw);
+    dummy_var ^= ((int64_t) (void*) new_MutexOcaAttributeDescriptorRaw);
     dummy_var ^= ((int64_t) (void*) new_MutexOcaBoxRaw);
     dummy_var ^= ((int64_t) (void*) new_MutexOcaBundleRaw);
     dummy_var ^= ((int64_t) (void*) new_MutexOcaCaptureBaseRaw);
+    dummy_var ^= ((int64_t) (void*) new_MutexOcaOverlayRaw);
     dummy_var ^= ((int64_t) (void*) new_MutexStringMap);
+    dummy_var ^= ((int64_t) (void*) new_OcaRegistryRaw);
     dummy_var ^= ((int64_t) (void*) new_StringList_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_oca_attr_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_oca_attribute_descriptor_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_oca_box_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_oca_bundle_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_oca_capture_base_0);
     dummy_var ^= ((int64_t) (void*) new_box_autoadd_oca_map_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_oca_overlay_0);
+    dummy_var ^= ((int64_t) (void*) new_box_autoadd_oca_registry_0);
     dummy_var ^= ((int64_t) (void*) new_uint_8_list_0);
-    dummy_var ^= ((int64_t) (void*) drop_opaque_MutexDynOverlay);
-    dummy_var ^= ((int64_t) (void*) share_opaque_MutexDynOverlay);
     dummy_var ^= ((int64_t) (void*) drop_opaque_MutexOcaAttrRaw);
     dummy_var ^= ((int64_t) (void*) share_opaque_MutexOcaAttrRaw);
+    dummy_var ^= ((int64_t) (void*) drop_opaque_MutexOcaAttributeDescriptorRaw);
+    dummy_var ^= ((int64_t) (void*) share_opaque_MutexOcaAttributeDescriptorRaw);
     dummy_var ^= ((int64_t) (void*) drop_opaque_MutexOcaBoxRaw);
     dummy_var ^= ((int64_t) (void*) share_opaque_MutexOcaBoxRaw);
     dummy_var ^= ((int64_t) (void*) drop_opaque_MutexOcaBundleRaw);
     dummy_var ^= ((int64_t) (void*) share_opaque_MutexOcaBundleRaw);
     dummy_var ^= ((int64_t) (void*) drop_opaque_MutexOcaCaptureBaseRaw);
     dummy_var ^= ((int64_t) (void*) share_opaque_MutexOcaCaptureBaseRaw);
+    dummy_var ^= ((int64_t) (void*) drop_opaque_MutexOcaOverlayRaw);
+    dummy_var ^= ((int64_t) (void*) share_opaque_MutexOcaOverlayRaw);
     dummy_var ^= ((int64_t) (void*) drop_opaque_MutexStringMap);
     dummy_var ^= ((int64_t) (void*) share_opaque_MutexStringMap);
+    dummy_var ^= ((int64_t) (void*) drop_opaque_OcaRegistryRaw);
+    dummy_var ^= ((int64_t) (void*) share_opaque_OcaRegistryRaw);
     dummy_var ^= ((int64_t) (void*) free_WireSyncReturn);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
     dummy_var ^= ((int64_t) (void*) get_dart_object);
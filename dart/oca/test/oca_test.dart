@@ -1,80 +1,310 @@
+import 'dart:convert';
 import 'dart:ffi';
+import 'dart:io';
 
 import 'package:oca/oca.dart';
 import 'package:test/test.dart';
 
 void main() {
+  final dylib = DynamicLibrary.open("../target/debug/libocadart.so");
+  late final api = OcaDartImpl(dylib);
+  final overlayFile =
+      File("test/assets/semantic.overlayfile").readAsStringSync();
+
   test('it works', () async {
-    final dylib = DynamicLibrary.open("../target/debug/libocadart.so");
-
-    late final api = OcaDartImpl(dylib);
-
-    final ocaBox = await OcaBox.newOcaBox(bridge: api)
-      ..addMeta(name: "name", value: "value", lang: "en")
-      ..addMeta(name: "description", value: "Test case OCA", lang: "en")
-      ..addFormLayout(layout: """
-        elements:
-          - type: "test"
-        """)
-      ..addCredentialLayout(layout: """
-        version: "1.0"
-        pages:
-            - config:
-                name: "test"
-              elements:
-                - type: "test"
-        """);
-
-    final attr1 = await OcaAttr.newOcaAttr(bridge: api, name: "name")
-      ..setAttributeType(attrType: OcaAttrType.Text)
-      ..setFlagged()
-      ..setEncoding(encoding: OcaEncoding.Utf8)
-      ..setCardinality(cardinality: "1")
-      ..setConformance(conformance: "0")
-      ..setLabel(lang: "en", label: "Name")
-      ..setInformation(lang: "en", information: "name information")
-      ..setEntryCodes(entryCodes: ["a", "b"])
-      ..setEntry(
-          lang: "en",
-          entries: await OcaMap.newOcaMap(bridge: api)
-            ..insert(key: "a", value: "Option A")
-            ..insert(key: "b", value: "Option B"))
-      ..setUnitMetric(unit: OcaMetricUnit.Kilogram)
-      ..setFormat(format: "^[a-zA-Z]*\$");
+    final registry = await OcaRegistry.fromOverlayFile(
+        bridge: api, overlayFile: overlayFile);
+
+    final ocaBox = await OcaBox.newOcaBox(bridge: api);
+
+    final entries = await OcaMap.newOcaMap(bridge: api);
+    await entries.insert(key: "a", value: "Option A");
+    await entries.insert(key: "b", value: "Option B");
+
+    final attr1 = await OcaAttr.newOcaAttr(bridge: api, name: "name");
+    await attr1.setAttributeType(attrType: OcaAttrType.Text);
+    await attr1.setFlagged();
+    await attr1.setEncoding(encoding: OcaEncoding.Utf8);
+    await attr1.setCardinality(cardinality: "1");
+    await attr1.setConformance(conformance: "O");
+    await attr1.setLabel(lang: "eng", label: "Name");
+    await attr1.setEntryCodes(entryCodes: ["a", "b"]);
+    await attr1.setEntry(lang: "eng", entries: entries);
+    await attr1.setFormat(format: "^[a-zA-Z]*\$");
     await ocaBox.addAttribute(attr: attr1);
 
-    final attr2 = await OcaAttr.newOcaAttr(bridge: api, name: "age")
-      ..setAttributeType(attrType: OcaAttrType.Numeric)
-      ..setFlagged()
-      ..setEncoding(encoding: OcaEncoding.Utf8)
-      ..setConformance(conformance: "M")
-      ..setCardinality(cardinality: "2")
-      ..setLabel(lang: "en", label: "Age")
-      ..setInformation(lang: "en", information: "age information")
-      ..setEntryCodes(entryCodes: ["a", "b"])
-      ..setEntry(
-          lang: "en",
-          entries: await OcaMap.newOcaMap(bridge: api)
-            ..insert(key: "a", value: "Option A")
-            ..insert(key: "b", value: "Option B"))
-      ..setUnitMetric(unit: OcaMetricUnit.Kilogram)
-      ..setFormat(format: "^[a-zA-Z]*\$");
+    final attr2 = await OcaAttr.newOcaAttr(bridge: api, name: "age");
+    await attr2.setAttributeType(attrType: OcaAttrType.Numeric);
+    await attr2.setFlagged();
+    await attr2.setEncoding(encoding: OcaEncoding.Utf8);
+    await attr2.setConformance(conformance: "M");
+    await attr2.setCardinality(cardinality: "2");
+    await attr2.setLabel(lang: "eng", label: "Age");
+    await attr2.setUnit(unit: "kg");
     await ocaBox.addAttribute(attr: attr2);
 
-    final ocaBundle = await ocaBox.generateBundle();
+    await ocaBox.addMeta(name: "name", value: "value", lang: "eng");
+    await ocaBox.addMeta(
+        name: "description", value: "Test case OCA", lang: "eng");
+
+    final ocaBundle = await ocaBox.generateBundle(registry: registry);
 
-    print(await ocaBundle.toJson());
-    print(await ocaBundle.said());
     final capBase = await ocaBundle.captureBase();
     final attrs = await capBase.attributes();
     expect((await attrs.getKeys()).length, 2);
+    expect(await attrs.get(key: "age"), "Numeric");
     expect((await capBase.flaggedAttributes()).length, 2);
-    expect((await ocaBundle.overlays()).length, 12);
+    expect((await ocaBundle.overlays()).length, 10);
+
+    final overlays = {
+      for (final overlay in await ocaBundle.overlays())
+        (await overlay.name())!: overlay
+    };
+    final label = overlays["label"]!;
+    expect(await label.overlayType(), startsWith("overlay/label/"));
+    expect(await label.language(), "eng");
+    expect(await label.captureBase(), isNotNull);
+    expect(await label.said(), isNotNull);
+    expect(await (await label.labels()).get(key: "age"), "Age");
+    final nameEntries = await overlays["entry"]!.entries(attribute: "name");
+    expect(await nameEntries!.get(key: "b"), "Option B");
+    expect(await overlays["entry_code"]!.entryCodes(attribute: "name"),
+        ["a", "b"]);
+    expect(await overlays["entry_code"]!.entryCodes(attribute: "age"), isNull);
+    expect(await (await overlays["unit"]!.units()).get(key: "age"), "kg");
+    expect(await (await overlays["format"]!.formats()).get(key: "name"),
+        "^[a-zA-Z]*\$");
+    expect(
+        await (await overlays["conformance"]!.conformances()).get(key: "age"),
+        "M");
+    expect(
+        await (await overlays["cardinality"]!.cardinalities())
+            .get(key: "name"),
+        "1");
+    final meta = await overlays["meta"]!.meta();
+    expect(await meta.get(key: "description"), "Test case OCA");
+    expect(await (await label.meta()).getKeys(), isEmpty);
+    expect(await overlays["meta"]!.toJson(), contains("Test case OCA"));
 
     final json = await ocaBundle.toJson();
-    print(json);
-    final ocaBundle2 = await api.loadOca(json: json);
+    final ocaBundle2 = await api.loadOca(json: json, registry: registry);
+
+    expect((await ocaBundle2.overlays()).length, 10);
+    expect(await ocaBundle2.said(), await ocaBundle.said());
+    expect(await ocaBundle2.upgradeNotes(), isEmpty);
+  });
+
+  test('it upgrades 1.x bundles', () async {
+    final registry = await OcaRegistry.fromOverlayFile(
+        bridge: api, overlayFile: overlayFile);
+    final json = File("test/assets/legacy.json").readAsStringSync();
+
+    expect(await api.isLegacyBundle(json: json), isTrue);
+    final ocaBundle = await api.loadOca(json: json, registry: registry);
+
+    final capBase = await ocaBundle.captureBase();
+    final attrs = await capBase.attributes();
+    expect(await attrs.get(key: "tags"), "Array[Text]");
+    expect(await capBase.flaggedAttributes(), ["name"]);
+    expect((await ocaBundle.overlays()).length, 7);
+    expect(await ocaBundle.upgradeNotes(), ["form_layout overlay"]);
+
+    final upgraded = await ocaBundle.toJson();
+    expect(await api.isLegacyBundle(json: upgraded), isFalse);
+  });
+
+  test('it builds OCAfiles', () async {
+    final registry = await OcaRegistry.fromOverlayFile(
+        bridge: api, overlayFile: overlayFile);
+    const ocafile = """ADD Attribute name=Text age=Numeric
+
+ADD OVERLAY Label
+  language="eng"
+  attribute_labels
+    name="Name"
+    age="Age"
+""";
+
+    final ast = jsonDecode(
+        await api.parseOcafile(ocafile: ocafile, registry: registry));
+    expect((ast["commands"] as List).length, 2);
+
+    final ocaBundle =
+        await api.buildFromOcafile(ocafile: ocafile, registry: registry);
+    final attrs = await (await ocaBundle.captureBase()).attributes();
+    expect(await attrs.get(key: "age"), "Numeric");
+    expect((await ocaBundle.overlays()).length, 1);
+
+    final generated = await api.generateOcafile(bundle: ocaBundle);
+    final rebuilt =
+        await api.buildFromOcafile(ocafile: generated, registry: registry);
+    expect(await rebuilt.said(), await ocaBundle.said());
+
+    final extended = await api.buildFromOcafile(
+        ocafile: """ADD OVERLAY Conformance
+  attribute_conformances
+    name="M"
+""",
+        registry: registry,
+        base: ocaBundle);
+    expect((await extended.overlays()).length, 2);
+    expect(await extended.said(), isNot(await ocaBundle.said()));
+  });
+
+  test('it moves languages in and out', () async {
+    final registry = await OcaRegistry.fromOverlayFile(
+        bridge: api, overlayFile: overlayFile);
+    final ocaBundle = await api.buildFromOcafile(ocafile: """
+ADD Attribute name=Text
+
+ADD OVERLAY Label
+  language="eng"
+  attribute_labels
+    name="Name"
+
+ADD OVERLAY Label
+  language="fra"
+  attribute_labels
+    name="Nom"
+""", registry: registry);
+    expect(await ocaBundle.languages(), ["eng", "fra"]);
+
+    final english = await ocaBundle.keepLanguages(keep: ["eng"]);
+    expect(await english.languages(), ["eng"]);
+    expect(await english.said(), isNot(await ocaBundle.said()));
+
+    final translation =
+        jsonDecode(await ocaBundle.extractTranslation(language: "fra"));
+    expect(translation["language"], "fra");
+    expect((translation["overlays"] as List).length, 1);
+    translation["overlays"][0]["attribute_labels"]["name"] = "Nom complet";
+
+    final merged = await ocaBundle.mergeTranslation(
+        translation: jsonEncode(translation));
+    final labels = await (await merged.attributes())[0].labels();
+    expect(await labels.get(key: "fra"), "Nom complet");
+    expect(await labels.get(key: "eng"), "Name");
+
+    expect(
+        () => ocaBundle.extractTranslation(language: "deu"),
+        throwsA(isA<OcaException>()
+            .having((e) => e.kind, "kind", OcaErrorKind.Missing)));
+  });
+
+  test('it verifies SAIDs', () async {
+    final registry = await OcaRegistry.fromOverlayFile(
+        bridge: api, overlayFile: overlayFile);
+    final ocaBundle = await api.buildFromOcafile(ocafile: """
+ADD Attribute name=Text
+
+ADD OVERLAY Label
+  language="eng"
+  attribute_labels
+    name="Name"
+""", registry: registry);
+    final json = await ocaBundle.toJson();
+
+    final objects = await api.verifyBundle(json: json);
+    expect(objects.map((o) => o.path),
+        ["capture_base", "overlays[0]", "bundle"]);
+    expect(objects.every((o) => o.valid), isTrue);
+    expect(objects.last.embedded, await ocaBundle.said());
+
+    final tampered = await api.verifyBundle(
+        json: json.replaceFirst('"Name"', '"Full name"'));
+    final label = tampered.firstWhere((o) => o.path == "overlays[0]");
+    expect(label.valid, isFalse);
+    expect(label.computed, isNot(label.embedded));
+  });
+
+  test('it describes attributes', () async {
+    final registry = await OcaRegistry.fromOverlayFile(
+        bridge: api, overlayFile: overlayFile);
+    final ocaBundle = await api.buildFromOcafile(ocafile: """
+ADD Attribute name=Text tags=Array[Text] sex=Text
+
+ADD OVERLAY Label
+  language="eng"
+  attribute_labels
+    name="Name"
+
+ADD OVERLAY Conformance
+  attribute_conformances
+    name="M"
+
+ADD OVERLAY ENTRY_CODE
+  attribute_entry_codes
+    sex=["M", "F"]
+""", registry: registry);
+
+    final descriptors = await ocaBundle.attributes();
+    expect(descriptors.length, 3);
+
+    final name = descriptors[0];
+    expect(await name.name(), "name");
+    expect(await name.attributeType(), "Text");
+    expect(await (await name.labels()).get(key: "eng"), "Name");
+    expect(await name.conformance(), "M");
+    expect(await name.entryCodes(), isNull);
+    expect(await name.sensitive(), isFalse);
+
+    expect(await descriptors[1].attributeType(), "Array[Text]");
+    expect(await descriptors[1].conformance(), isNull);
+    expect(await descriptors[2].entryCodes(), ["M", "F"]);
+  });
+
+  test('it validates bundles and records', () async {
+    final registry = await OcaRegistry.fromOverlayFile(
+        bridge: api, overlayFile: overlayFile);
+    final ocaBundle = await api.buildFromOcafile(ocafile: """--name=person
+ADD Attribute name=Text age=Numeric sex=Text
+
+ADD OVERLAY ENTRY_CODE
+  attribute_entry_codes
+    sex=["M", "F"]
+""", registry: registry);
+
+    expect(await ocaBundle.validateSemantics(), isEmpty);
+
+    expect(
+        await ocaBundle.validateData(
+            record: jsonEncode({"name": "Alice", "age": 42, "sex": "F"})),
+        isEmpty);
+
+    final errors = await ocaBundle.validateData(
+        record: jsonEncode({"name": "Alice", "age": "old", "sex": "Q"}));
+    final age = errors.firstWhere((e) => e.attribute == "age");
+    expect(age.path, "/age");
+    expect(age.rule, OcaDataRule.Type);
+    expect(age.expected, '"Numeric"');
+    expect(age.actual, '"old"');
+    final sex = errors.firstWhere((e) => e.attribute == "sex");
+    expect(sex.path, "/sex");
+    expect(sex.rule, OcaDataRule.EntryCode);
+
+    expect(
+        () => ocaBundle.validateData(record: "{"),
+        throwsA(isA<OcaException>()
+            .having((e) => e.kind, "kind", OcaErrorKind.Parse)));
+  });
+
+  test('it throws typed errors', () async {
+    final attr = await OcaAttr.newOcaAttr(bridge: api, name: "name");
+    expect(
+        () => attr.setAttributeTypeSpelling(spelling: "Nonsense"),
+        throwsA(isA<OcaException>()
+            .having((e) => e.kind, "kind", OcaErrorKind.Invalid)));
+
+    final ocaBox = await OcaBox.newOcaBox(bridge: api);
+    expect(
+        () => ocaBox.addAttribute(attr: attr),
+        throwsA(isA<OcaException>()
+            .having((e) => e.kind, "kind", OcaErrorKind.Invalid)));
 
-    expect((await ocaBundle2.overlays()).length, 12);
+    expect(() => api.loadOca(json: "not json"),
+        throwsA(isA<OcaException>()));
+    expect(() => api.buildFromOcafile(ocafile: "ADD NONSENSE"),
+        throwsA(isA<OcaException>()
+            .having((e) => e.kind, "kind", OcaErrorKind.Parse)));
   });
 }
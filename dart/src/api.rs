@@ -1,157 +1,350 @@
+use std::collections::BTreeMap;
 pub(crate) use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
 pub(crate) use std::sync::Mutex;
+use std::sync::MutexGuard;
 
-use anyhow::{anyhow, Context, Result};
-use flutter_rust_bridge::{frb, RustOpaque};
-pub(crate) use oca_bundle::state::{
-    attribute::{Attribute as OcaAttrRaw, AttributeType as OcaAttrType},
-    encoding::Encoding as OcaEncoding,
-    entries::EntriesElement,
-    entry_codes::EntryCodes,
-    oca::{
-        capture_base::CaptureBase as OcaCaptureBaseRaw,
-        overlay::{
-            self,
-            unit::{ImperialUnit as OcaImperialUnit, MetricUnit as OcaMetricUnit},
-        },
-        DynOverlay, OCABox as OcaBoxRaw, OCABundle as OcaBundleRaw,
-    },
+use flutter_rust_bridge::RustOpaque;
+pub(crate) use oca_bindings_common::attributes::AttributeDescriptor as OcaAttributeDescriptorRaw;
+pub(crate) use oca_bindings_common::builder::BundleBuilder as OcaBoxRaw;
+use oca_bindings_common::builder::Rendered;
+use oca_bindings_common::data::{self, DataError, Rule};
+use oca_bindings_common::languages::{self, Translation};
+use oca_bindings_common::said::{self, ObjectVerification};
+use oca_bindings_common::semantics::{self, Category, SemanticIssue};
+use oca_bindings_common::types::AttrType;
+use oca_bindings_common::{attributes, bundle, legacy, version};
+use oca_sdk_rs::oca::bundle::{
+    validate_semantics, OCABundle, OCABundleModel, SemanticValidationStatus,
 };
+use oca_sdk_rs::oca::file::{self as ocafile, OCAAst};
+pub(crate) use oca_sdk_rs::oca::overlay_file::OverlayLocalRegistry as OcaRegistryRaw;
+use oca_sdk_rs::oca::validator::{validate_data, DataValidationStatus};
+pub(crate) use serde_json::Value as OcaOverlayRaw;
+
+/// The error of every call, thrown in Dart as `OcaException`.
+pub struct OcaException {
+    pub kind: OcaErrorKind,
+    pub message: String,
+}
+
+pub enum OcaErrorKind {
+    /// JSON, OCAfile or overlay file text that could not be parsed.
+    Parse,
+    /// A value the builder cannot take, e.g. a label for an unknown attribute.
+    Invalid,
+    /// A bundle that could not be built, loaded, upgraded or checked.
+    Bundle,
+    /// Something the bundle lacks, e.g. its SAID.
+    Missing,
+    /// An object left unusable by a panic in an earlier call.
+    Poisoned,
+    /// A panic in Rust, caught before it reached Dart.
+    Panic,
+}
+
+impl OcaErrorKind {
+    fn error(self, message: impl Into<String>) -> OcaException {
+        OcaException {
+            kind: self,
+            message: message.into(),
+        }
+    }
+}
+
+/// Runs the body of an exported function, turning a panic into an
+/// `OcaException` rather than letting it unwind into Dart.
+fn guard<T>(body: impl FnOnce() -> Result<T, OcaException>) -> Result<T, OcaException> {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "Rust panicked".to_string(),
+            },
+        };
+        Err(OcaErrorKind::Panic.error(message))
+    })
+}
+
+/// Fails rather than panics when an earlier call panicked holding the lock.
+fn lock<T>(mutex: &Mutex<T>) -> Result<MutexGuard<'_, T>, OcaException> {
+    mutex
+        .lock()
+        .map_err(|_| OcaErrorKind::Poisoned.error("Unusable after a panic in an earlier call"))
+}
+
+/// The overlay definitions bundles are built and loaded against. Functions
+/// that take an optional registry fall back to the built-in one.
+pub struct OcaRegistry(pub RustOpaque<OcaRegistryRaw>);
+
+impl OcaRegistry {
+    pub fn new() -> Result<OcaRegistry, OcaException> {
+        guard(|| Ok(OcaRegistry(RustOpaque::new(OcaRegistryRaw::default()))))
+    }
+
+    /// Parses the contents of one or more `.overlayfile`s.
+    pub fn from_overlay_file(overlay_file: String) -> Result<OcaRegistry, OcaException> {
+        guard(|| {
+            let registry = OcaRegistryRaw::from_string(overlay_file).map_err(|err| {
+                OcaErrorKind::Parse.error(format!("Failed to load overlay registry: {err}"))
+            })?;
+            Ok(OcaRegistry(RustOpaque::new(registry)))
+        })
+    }
+}
+
+fn registry_or_default(registry: Option<OcaRegistry>) -> RustOpaque<OcaRegistryRaw> {
+    registry
+        .map(|registry| registry.0)
+        .unwrap_or_else(|| RustOpaque::new(OcaRegistryRaw::default()))
+}
 
 pub struct OcaBox(pub RustOpaque<Mutex<OcaBoxRaw>>);
 
 impl OcaBox {
-    pub fn new() -> OcaBox {
-        OcaBox(RustOpaque::new(Mutex::new(OcaBoxRaw::new())))
+    pub fn new() -> Result<OcaBox, OcaException> {
+        guard(|| Ok(OcaBox(RustOpaque::new(Mutex::new(OcaBoxRaw::new())))))
     }
 
-    pub fn add_meta(&self, lang: String, name: String, value: String) -> Result<()> {
-        let mut oca_box = self.0.lock().unwrap();
-        let lang = lang.parse().context("Invalid language")?;
-        overlay::meta::Metas::add_meta(&mut *oca_box, lang, name, value);
-        Ok(())
+    pub fn add_meta(&self, lang: String, name: String, value: String) -> Result<(), OcaException> {
+        guard(|| {
+            let mut oca_box = lock(&self.0)?;
+            let meta = serde_json::Map::from_iter([(name, value.into())]);
+            oca_box
+                .meta(&lang, meta)
+                .map_err(|err| OcaErrorKind::Invalid.error(err))
+        })
     }
 
-    pub fn add_attribute(&self, attr: OcaAttr) {
-        let mut oca_box = self.0.lock().unwrap();
-        oca_box.add_attribute(attr.0.lock().unwrap().clone());
+    pub fn add_attribute(&self, attr: OcaAttr) -> Result<(), OcaException> {
+        guard(|| {
+            let mut oca_box = lock(&self.0)?;
+            let attr = lock(&attr.0)?;
+            attr.add_to(&mut oca_box)
+                .map_err(|err| OcaErrorKind::Invalid.error(err))
+        })
     }
 
-    pub fn generate_bundle(&self) -> OcaBundle {
-        let mut oca_box = self.0.lock().unwrap();
-        let oca_bundle = oca_box.generate_bundle();
-        OcaBundle(RustOpaque::new(Mutex::new(oca_bundle)))
+    /// Builds the bundle the same way as the equivalent OCAfile, so it gets
+    /// the same SAID.
+    pub fn generate_bundle(
+        &self,
+        registry: Option<OcaRegistry>,
+    ) -> Result<OcaBundle, OcaException> {
+        guard(|| {
+            let oca_box = lock(&self.0)?;
+            let rendered = oca_box
+                .render()
+                .map_err(|err| OcaErrorKind::Invalid.error(err))?;
+            let registry = registry_or_default(registry);
+            let model = build_rendered(&rendered, &registry)?;
+            OcaBundle::new(model, registry, vec![])
+        })
     }
+}
 
-    pub fn add_form_layout(&self, layout: String) {
-        let mut oca_box = self.0.lock().unwrap();
-        overlay::form_layout::FormLayouts::add_form_layout(&mut *oca_box, layout);
+/// An attribute and its overlay values, added to an `OcaBox` in one go.
+#[derive(Clone)]
+pub struct OcaAttrRaw {
+    name: String,
+    attr_type: Option<AttrType>,
+    flagged: bool,
+    encoding: Option<String>,
+    cardinality: Option<String>,
+    conformance: Option<String>,
+    labels: Vec<(String, String)>,
+    information: Vec<(String, String)>,
+    entry_codes: Option<Vec<String>>,
+    entries: Vec<(String, Vec<(String, String)>)>,
+    unit: Option<String>,
+    format: Option<String>,
+}
+
+impl OcaAttrRaw {
+    fn add_to(&self, oca_box: &mut OcaBoxRaw) -> Result<(), String> {
+        let name = self.name.as_str();
+        let attr_type = self
+            .attr_type
+            .clone()
+            .ok_or_else(|| format!("Attribute '{name}' has no type"))?;
+        oca_box.add_attribute(name, attr_type)?;
+        if self.flagged {
+            oca_box.sensitive(name)?;
+        }
+        if let Some(encoding) = &self.encoding {
+            oca_box.character_encoding(name, encoding)?;
+        }
+        if let Some(cardinality) = &self.cardinality {
+            oca_box.cardinality(name, cardinality)?;
+        }
+        if let Some(conformance) = &self.conformance {
+            oca_box.conformance(name, conformance)?;
+        }
+        for (lang, label) in &self.labels {
+            oca_box.label(lang, name, label)?;
+        }
+        for (lang, information) in &self.information {
+            oca_box.information(lang, name, information)?;
+        }
+        if let Some(entry_codes) = &self.entry_codes {
+            oca_box.entry_codes(name, entry_codes.clone())?;
+        }
+        for (lang, entries) in &self.entries {
+            oca_box.entries(lang, name, entries.clone())?;
+        }
+        if let Some(unit) = &self.unit {
+            oca_box.unit(name, unit)?;
+        }
+        if let Some(format) = &self.format {
+            oca_box.format(name, format)?;
+        }
+        Ok(())
     }
+}
 
-    pub fn add_credential_layout(&self, layout: String) {
-        let mut oca_box = self.0.lock().unwrap();
-        overlay::credential_layout::CredentialLayouts::add_credential_layout(&mut *oca_box, layout);
+/// Sets `value` for `lang`, replacing what was set for it before.
+fn set_for_language<T>(values: &mut Vec<(String, T)>, lang: String, value: T) {
+    match values.iter_mut().find(|(known, _)| *known == lang) {
+        Some((_, known)) => *known = value,
+        None => values.push((lang, value)),
     }
 }
 
 pub struct OcaAttr(pub RustOpaque<Mutex<OcaAttrRaw>>);
 
 impl OcaAttr {
-    pub fn new(name: String) -> OcaAttr {
-        let attr = OcaAttrRaw::new(name);
-        OcaAttr(RustOpaque::new(Mutex::new(attr)))
+    pub fn new(name: String) -> Result<OcaAttr, OcaException> {
+        guard(|| {
+            let attr = OcaAttrRaw {
+                name,
+                attr_type: None,
+                flagged: false,
+                encoding: None,
+                cardinality: None,
+                conformance: None,
+                labels: vec![],
+                information: vec![],
+                entry_codes: None,
+                entries: vec![],
+                unit: None,
+                format: None,
+            };
+            Ok(OcaAttr(RustOpaque::new(Mutex::new(attr))))
+        })
     }
 
-    pub fn set_attribute_type(&self, attr_type: OcaAttrType) {
-        let mut attr = self.0.lock().unwrap();
-        attr.set_attribute_type(attr_type);
+    pub fn set_attribute_type(&self, attr_type: OcaAttrType) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            attr.attr_type = Some(attr_type.into());
+            Ok(())
+        })
     }
 
-    pub fn set_flagged(&self) {
-        let mut attr = self.0.lock().unwrap();
-        attr.set_flagged();
+    /// Sets the type by its OCAfile spelling, e.g. `refs:E...` or
+    /// `Array[refn:address]`, for types `OcaAttrType` has no variant for.
+    pub fn set_attribute_type_spelling(&self, spelling: String) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            let attr_type = AttrType::parse(&spelling).ok_or_else(|| {
+                OcaErrorKind::Invalid.error(format!("Invalid attribute type '{spelling}'"))
+            })?;
+            attr.attr_type = Some(attr_type);
+            Ok(())
+        })
     }
 
-    pub fn set_encoding(&self, encoding: OcaEncoding) {
-        let mut attr = self.0.lock().unwrap();
-        overlay::character_encoding::CharacterEncodings::set_encoding(&mut *attr, encoding);
+    /// Lists the attribute in the sensitive overlay.
+    pub fn set_flagged(&self) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            attr.flagged = true;
+            Ok(())
+        })
     }
 
-    pub fn set_cardinality(&self, cardinality: String) {
-        let mut attr = self.0.lock().unwrap();
-        overlay::cardinality::Cardinalitys::set_cardinality(&mut *attr, cardinality);
+    pub fn set_encoding(&self, encoding: OcaEncoding) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            attr.encoding = Some(encoding.as_str().to_string());
+            Ok(())
+        })
     }
 
-    pub fn set_conformance(&self, conformance: String) {
-        let mut attr = self.0.lock().unwrap();
-        overlay::conformance::Conformances::set_conformance(&mut *attr, conformance);
+    pub fn set_cardinality(&self, cardinality: String) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            attr.cardinality = Some(cardinality);
+            Ok(())
+        })
     }
 
-    pub fn set_label(&self, lang: String, label: String) -> Result<()> {
-        let mut attr = self.0.lock().unwrap();
-        let lang = lang.parse().context("Invalid language")?;
-        overlay::label::Labels::set_label(&mut *attr, lang, label);
-        Ok(())
+    pub fn set_conformance(&self, conformance: String) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            attr.conformance = Some(conformance);
+            Ok(())
+        })
     }
 
-    pub fn set_information(&self, lang: String, information: String) -> Result<()> {
-        let mut attr = self.0.lock().unwrap();
-        let lang = lang.parse().context("Invalid language")?;
-        overlay::information::Information::set_information(&mut *attr, lang, information);
-        Ok(())
+    pub fn set_label(&self, lang: String, label: String) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            set_for_language(&mut attr.labels, lang, label);
+            Ok(())
+        })
     }
 
-    pub fn set_entry_codes(&self, entry_codes: Vec<String>) {
-        let mut attr = self.0.lock().unwrap();
-        overlay::entry_code::EntryCodes::set_entry_codes(
-            &mut *attr,
-            EntryCodes::Array(entry_codes),
-        );
+    pub fn set_information(&self, lang: String, information: String) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            set_for_language(&mut attr.information, lang, information);
+            Ok(())
+        })
     }
 
-    pub fn set_entry_codes_sai(&self, sai: String) {
-        let mut attr = self.0.lock().unwrap();
-        overlay::entry_code::EntryCodes::set_entry_codes(&mut *attr, EntryCodes::Sai(sai));
+    pub fn set_entry_codes(&self, entry_codes: Vec<String>) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            attr.entry_codes = Some(entry_codes);
+            Ok(())
+        })
     }
 
-    pub fn set_entry(&self, lang: String, entries: OcaMap) -> Result<()> {
-        let mut attr = self.0.lock().unwrap();
-        let lang = lang.parse().context("Invalid language")?;
-        let entries = entries.0.lock().unwrap().0.clone();
-        overlay::entry::Entries::set_entry(&mut *attr, lang, EntriesElement::Object(entries));
-        Ok(())
+    /// `entries` maps entry codes to their labels in `lang`.
+    pub fn set_entry(&self, lang: String, entries: OcaMap) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            let entries = lock(&entries.0)?;
+            let mut entries: Vec<(String, String)> = entries.0.clone().into_iter().collect();
+            entries.sort();
+            set_for_language(&mut attr.entries, lang, entries);
+            Ok(())
+        })
     }
 
-    pub fn set_unit_metric(&self, unit: OcaMetricUnit) {
-        let mut attr = self.0.lock().unwrap();
-        overlay::unit::Unit::set_unit(
-            &mut *attr,
-            overlay::unit::AttributeUnit {
-                measurement_system: overlay::unit::MeasurementSystem::Metric,
-                unit: overlay::unit::MeasurementUnit::Metric(unit),
-            },
-        );
+    /// A unit symbol such as `kg`, as listed by the unit overlay.
+    pub fn set_unit(&self, unit: String) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            attr.unit = Some(unit);
+            Ok(())
+        })
     }
 
-    pub fn set_unit_imperial(&self, unit: OcaImperialUnit) {
-        let mut attr = self.0.lock().unwrap();
-        overlay::unit::Unit::set_unit(
-            &mut *attr,
-            overlay::unit::AttributeUnit {
-                measurement_system: overlay::unit::MeasurementSystem::Imperial,
-                unit: overlay::unit::MeasurementUnit::Imperial(unit),
-            },
-        );
-    }
-
-    pub fn set_format(&self, format: String) {
-        let mut attr = self.0.lock().unwrap();
-        overlay::format::Formats::set_format(&mut *attr, format);
+    pub fn set_format(&self, format: String) -> Result<(), OcaException> {
+        guard(|| {
+            let mut attr = lock(&self.0)?;
+            attr.format = Some(format);
+            Ok(())
+        })
     }
 }
 
-#[frb(mirror(OcaAttrType))]
-pub enum _OcaAttrType {
+/// Value types and arrays of them. References are set with
+/// `OcaAttr::set_attribute_type_spelling`.
+pub enum OcaAttrType {
     Boolean,
     ArrayBoolean,
     Binary,
@@ -162,140 +355,830 @@ pub enum _OcaAttrType {
     ArrayNumeric,
     DateTime,
     ArrayDateTime,
-    Reference,
-    ArrayReference,
 }
 
-#[frb(mirror(OcaEncoding))]
-pub enum _OcaEncoding {
+impl From<OcaAttrType> for AttrType {
+    fn from(attr_type: OcaAttrType) -> AttrType {
+        let array = |items| AttrType::Array(Box::new(items));
+        match attr_type {
+            OcaAttrType::Boolean => AttrType::Boolean,
+            OcaAttrType::ArrayBoolean => array(AttrType::Boolean),
+            OcaAttrType::Binary => AttrType::Binary,
+            OcaAttrType::ArrayBinary => array(AttrType::Binary),
+            OcaAttrType::Text => AttrType::Text,
+            OcaAttrType::ArrayText => array(AttrType::Text),
+            OcaAttrType::Numeric => AttrType::Numeric,
+            OcaAttrType::ArrayNumeric => array(AttrType::Numeric),
+            OcaAttrType::DateTime => AttrType::DateTime,
+            OcaAttrType::ArrayDateTime => array(AttrType::DateTime),
+        }
+    }
+}
+
+pub enum OcaEncoding {
     Base64,
     Utf8,
     Iso8859_1,
 }
 
-#[frb(mirror(OcaMetricUnit))]
-pub enum _OcaMetricUnit {
-    Kilogram,
-    Gram,
-    Milligram,
-    Liter,
-    Milliliter,
-    Centimeter,
-    Millimeter,
-    Inch,
-    Foot,
-    Yard,
-    Mile,
-    Celsius,
-    Fahrenheit,
-    Kelvin,
-    Percent,
-    Count,
-    Other,
+impl OcaEncoding {
+    fn as_str(&self) -> &'static str {
+        match self {
+            OcaEncoding::Base64 => "base64",
+            OcaEncoding::Utf8 => "utf-8",
+            OcaEncoding::Iso8859_1 => "iso-8859-1",
+        }
+    }
 }
 
-#[frb(mirror(OcaImperialUnit))]
-pub enum _OcaImperialUnit {
-    Pound,
-    Ounce,
-    Gallon,
-    Quart,
-    Pint,
-    FluidOunce,
-    Inch,
-    Foot,
-    Yard,
-    Mile,
-    Celsius,
-    Fahrenheit,
-    Kelvin,
-    Percent,
-    Count,
-    Other,
+pub struct OcaBundleRaw {
+    model: OCABundleModel,
+    /// The serialized bundle, read by the accessors.
+    value: serde_json::Value,
+    /// The registry the bundle was loaded or built with, used again to load
+    /// edited copies.
+    registry: RustOpaque<OcaRegistryRaw>,
+    upgrade_notes: Vec<String>,
 }
 
 pub struct OcaBundle(pub RustOpaque<Mutex<OcaBundleRaw>>);
 
 impl OcaBundle {
-    pub fn to_json(&self) -> String {
-        let oca_bundle = self.0.lock().unwrap();
-        serde_json::to_string_pretty(&*oca_bundle).unwrap()
+    fn new(
+        mut model: OCABundleModel,
+        registry: RustOpaque<OcaRegistryRaw>,
+        upgrade_notes: Vec<String>,
+    ) -> Result<OcaBundle, OcaException> {
+        model.fill_attributes();
+        let value = serde_json::to_value(OCABundle::from(model.clone())).map_err(|err| {
+            OcaErrorKind::Bundle.error(format!("Failed to serialize bundle: {err}"))
+        })?;
+        Ok(OcaBundle(RustOpaque::new(Mutex::new(OcaBundleRaw {
+            model,
+            value,
+            registry,
+            upgrade_notes,
+        }))))
     }
 
-    pub fn said(&self) -> String {
-        let oca_bundle = self.0.lock().unwrap();
-        oca_bundle.said.clone().unwrap().to_string()
+    /// A copy of the bundle with `edit` applied to its JSON, loaded again
+    /// with the same registry.
+    fn edited(
+        &self,
+        kind: OcaErrorKind,
+        edit: impl FnOnce(&serde_json::Value) -> Result<serde_json::Value, String>,
+    ) -> Result<OcaBundle, OcaException> {
+        let oca_bundle = lock(&self.0)?;
+        let value = edit(&oca_bundle.value).map_err(|err| kind.error(err))?;
+        let model = load_model(&value.to_string(), &oca_bundle.registry)?;
+        OcaBundle::new(
+            model,
+            oca_bundle.registry.clone(),
+            oca_bundle.upgrade_notes.clone(),
+        )
     }
 
-    pub fn capture_base(&self) -> OcaCaptureBase {
-        let oca_bundle = self.0.lock().unwrap();
-        OcaCaptureBase(RustOpaque::new(Mutex::new(oca_bundle.capture_base.clone())))
+    pub fn to_json(&self) -> Result<String, OcaException> {
+        guard(|| {
+            let oca_bundle = lock(&self.0)?;
+            serde_json::to_string_pretty(&oca_bundle.value).map_err(|err| {
+                OcaErrorKind::Bundle.error(format!("Failed to serialize bundle: {err}"))
+            })
+        })
     }
 
-    pub fn overlays(&self) -> Vec<OcaOverlay> {
-        let oca_bundle = self.0.lock().unwrap();
-        oca_bundle
-            .overlays
-            .iter()
-            .map(|overlay| {
-                OcaOverlay(RustOpaque::new(Mutex::new(
-                    oca_bundle::dyn_clone::clone_box(&**overlay),
-                )))
+    pub fn said(&self) -> Result<String, OcaException> {
+        guard(|| {
+            let oca_bundle = lock(&self.0)?;
+            let said = bundle::digest(&oca_bundle.value)
+                .ok_or_else(|| OcaErrorKind::Missing.error("The bundle has no SAID"))?;
+            Ok(said.to_string())
+        })
+    }
+
+    pub fn capture_base(&self) -> Result<OcaCaptureBase, OcaException> {
+        guard(|| {
+            let oca_bundle = lock(&self.0)?;
+            let attributes = bundle::attributes(&oca_bundle.value)
+                .into_iter()
+                .map(|(name, attr_type)| {
+                    let attr_type = AttrType::from_json(attr_type)
+                        .map_or_else(|| attr_type.to_string(), |t| t.to_string());
+                    (name.to_string(), attr_type)
+                })
+                .collect();
+            let flagged_attributes = bundle::overlays(&oca_bundle.value)
+                .iter()
+                .filter(|overlay| bundle::overlay_name(overlay) == Some("sensitive"))
+                .filter_map(|overlay| {
+                    bundle::overlay_fields(overlay)?
+                        .get("attributes")?
+                        .as_array()
+                })
+                .flatten()
+                .filter_map(|name| Some(name.as_str()?.to_string()))
+                .collect();
+            Ok(OcaCaptureBase(RustOpaque::new(Mutex::new(
+                OcaCaptureBaseRaw {
+                    attributes,
+                    flagged_attributes,
+                },
+            ))))
+        })
+    }
+
+    pub fn overlays(&self) -> Result<Vec<OcaOverlay>, OcaException> {
+        guard(|| {
+            let oca_bundle = lock(&self.0)?;
+            Ok(bundle::overlays(&oca_bundle.value)
+                .iter()
+                .map(|overlay| OcaOverlay(RustOpaque::new(Mutex::new(overlay.clone()))))
+                .collect())
+        })
+    }
+
+    /// The languages of the overlays, in order of appearance.
+    pub fn languages(&self) -> Result<Vec<String>, OcaException> {
+        guard(|| {
+            let oca_bundle = lock(&self.0)?;
+            Ok(languages::languages(&oca_bundle.value))
+        })
+    }
+
+    /// A copy of the bundle without the overlays in other languages than
+    /// `keep`. Overlays without a language are kept.
+    pub fn keep_languages(&self, keep: Vec<String>) -> Result<OcaBundle, OcaException> {
+        guard(|| {
+            self.edited(OcaErrorKind::Bundle, |bundle| {
+                languages::keep_languages(bundle, &keep)
+            })
+        })
+    }
+
+    /// The overlays in `language` as a translation package, in JSON: the
+    /// `language`, the `capture_base` SAID and the `overlays` without SAIDs.
+    pub fn extract_translation(&self, language: String) -> Result<String, OcaException> {
+        guard(|| {
+            let oca_bundle = lock(&self.0)?;
+            let translation = languages::extract_translation(&oca_bundle.value, &language)
+                .map_err(|err| OcaErrorKind::Missing.error(err))?;
+            serde_json::to_string_pretty(&translation).map_err(|err| {
+                OcaErrorKind::Bundle.error(format!("Failed to serialize translation: {err}"))
+            })
+        })
+    }
+
+    /// A copy of the bundle with the overlays of a translation package, as
+    /// returned by `extract_translation`, replacing those of the same type
+    /// in its language.
+    pub fn merge_translation(&self, translation: String) -> Result<OcaBundle, OcaException> {
+        guard(|| {
+            let translation: Translation = serde_json::from_str(&translation).map_err(|err| {
+                OcaErrorKind::Parse.error(format!("Invalid translation JSON: {err}"))
+            })?;
+            self.edited(OcaErrorKind::Invalid, |bundle| {
+                languages::merge_translation(bundle, &translation)
             })
-            .collect()
+        })
     }
+
+    /// One descriptor per attribute of the capture base, in capture base
+    /// order, with what the overlays say about it.
+    pub fn attributes(&self) -> Result<Vec<OcaAttributeDescriptor>, OcaException> {
+        guard(|| {
+            let oca_bundle = lock(&self.0)?;
+            let filled = oca_bundle
+                .model
+                .attributes
+                .iter()
+                .flat_map(|attr_map| attr_map.values())
+                .map(|attr| {
+                    let attr_type = attr
+                        .attribute_type
+                        .as_ref()
+                        .and_then(|attr_type| serde_json::to_value(attr_type).ok())
+                        .and_then(|value| AttrType::from_json(&value));
+                    (attr.name.as_str(), attr_type)
+                });
+            Ok(attributes::describe_filled(&oca_bundle.value, filled, None)
+                .into_iter()
+                .map(|descriptor| OcaAttributeDescriptor(RustOpaque::new(Mutex::new(descriptor))))
+                .collect())
+        })
+    }
+
+    /// What a bundle loaded from the 1.x format lost in the upgrade, e.g.
+    /// its form layout. Empty for bundles that needed no upgrade.
+    pub fn upgrade_notes(&self) -> Result<Vec<String>, OcaException> {
+        guard(|| {
+            let oca_bundle = lock(&self.0)?;
+            Ok(oca_bundle.upgrade_notes.clone())
+        })
+    }
+
+    /// Checks the overlays against the capture base and each other. Empty
+    /// when the bundle is consistent.
+    pub fn validate_semantics(&self) -> Result<Vec<OcaSemanticIssue>, OcaException> {
+        guard(|| {
+            let oca_bundle = lock(&self.0)?;
+            let issues = semantics::check_bundle(&oca_bundle.value);
+            let sdk_errors = match validate_semantics(&oca_bundle.model) {
+                Ok(SemanticValidationStatus::Valid) => vec![],
+                Ok(SemanticValidationStatus::Invalid(errors)) => {
+                    errors.into_iter().map(|err| err.to_string()).collect()
+                }
+                Err(err) => vec![err.to_string()],
+            };
+            Ok(
+                semantics::with_sdk_errors(&oca_bundle.value, issues, sdk_errors)
+                    .into_iter()
+                    .map(OcaSemanticIssue::from)
+                    .collect(),
+            )
+        })
+    }
+
+    /// Checks a data record, given as a JSON object, against the attributes
+    /// and constraint overlays. Empty when the record is valid.
+    pub fn validate_data(&self, record: String) -> Result<Vec<OcaDataError>, OcaException> {
+        guard(|| {
+            let mut oca_bundle = lock(&self.0)?;
+            let record: serde_json::Value = serde_json::from_str(&record).map_err(|err| {
+                OcaErrorKind::Parse.error(format!("Invalid data record JSON: {err}"))
+            })?;
+            let oca_bundle = &mut *oca_bundle;
+            let sdk = |record: &serde_json::Value| match validate_data(&mut oca_bundle.model, record)
+            {
+                Ok(DataValidationStatus::Valid) => Ok(vec![]),
+                Ok(DataValidationStatus::Invalid(errors)) => Ok(errors),
+                Err(err) => {
+                    Err(OcaErrorKind::Bundle.error(format!("Failed to validate data: {err}")))
+                }
+            };
+            Ok(
+                data::validate_record(&oca_bundle.value, &record, sdk)?
+                    .into_iter()
+                    .map(OcaDataError::from)
+                    .collect(),
+            )
+        })
+    }
+}
+
+pub struct OcaCaptureBaseRaw {
+    /// Attribute names and the OCAfile spelling of their types.
+    attributes: HashMap<String, String>,
+    /// The attributes listed in the sensitive overlay.
+    flagged_attributes: Vec<String>,
 }
 
 pub struct OcaCaptureBase(pub RustOpaque<Mutex<OcaCaptureBaseRaw>>);
 
 impl OcaCaptureBase {
-    pub fn attributes(&self) -> OcaMap {
-        let capture_base = self.0.lock().unwrap();
-        OcaMap(RustOpaque::new(Mutex::new(StringMap(
-            capture_base.attributes.clone(),
-        ))))
+    pub fn attributes(&self) -> Result<OcaMap, OcaException> {
+        guard(|| {
+            let capture_base = lock(&self.0)?;
+            Ok(OcaMap(RustOpaque::new(Mutex::new(StringMap(
+                capture_base.attributes.clone(),
+            )))))
+        })
     }
 
-    pub fn flagged_attributes(&self) -> Vec<String> {
-        let capture_base = self.0.lock().unwrap();
-        capture_base.flagged_attributes.clone()
+    pub fn flagged_attributes(&self) -> Result<Vec<String>, OcaException> {
+        guard(|| {
+            let capture_base = lock(&self.0)?;
+            Ok(capture_base.flagged_attributes.clone())
+        })
     }
 }
 
-pub struct OcaOverlay(pub RustOpaque<Mutex<DynOverlay>>);
+pub struct OcaOverlay(pub RustOpaque<Mutex<OcaOverlayRaw>>);
+
+/// The getters for one kind of overlay return nothing for overlays of other
+/// kinds. `to_json` has all fields, including those without a getter.
+impl OcaOverlay {
+    /// The full type, e.g. `overlay/label/2.0.0`.
+    pub fn overlay_type(&self) -> Result<String, OcaException> {
+        guard(|| {
+            let overlay = lock(&self.0)?;
+            Ok(overlay.get("type").map(text).unwrap_or_default())
+        })
+    }
+
+    /// The short name of the type, e.g. `label`.
+    pub fn name(&self) -> Result<Option<String>, OcaException> {
+        guard(|| {
+            let overlay = lock(&self.0)?;
+            Ok(bundle::overlay_name(&overlay).map(str::to_string))
+        })
+    }
+
+    pub fn language(&self) -> Result<Option<String>, OcaException> {
+        guard(|| {
+            let overlay = lock(&self.0)?;
+            Ok(bundle::overlay_language(&overlay).map(str::to_string))
+        })
+    }
+
+    pub fn said(&self) -> Result<Option<String>, OcaException> {
+        guard(|| {
+            let overlay = lock(&self.0)?;
+            Ok(bundle::digest(&overlay).map(str::to_string))
+        })
+    }
+
+    /// The SAID of the capture base the overlay belongs to.
+    pub fn capture_base(&self) -> Result<Option<String>, OcaException> {
+        guard(|| {
+            let overlay = lock(&self.0)?;
+            let capture_base = bundle::overlay_fields(&overlay)
+                .and_then(|fields| fields.get("capture_base")?.as_str());
+            Ok(capture_base.map(str::to_string))
+        })
+    }
+
+    pub fn to_json(&self) -> Result<String, OcaException> {
+        guard(|| {
+            let overlay = lock(&self.0)?;
+            serde_json::to_string_pretty(&*overlay).map_err(|err| {
+                OcaErrorKind::Bundle.error(format!("Failed to serialize overlay: {err}"))
+            })
+        })
+    }
+
+    /// Attribute names and their labels.
+    pub fn labels(&self) -> Result<OcaMap, OcaException> {
+        self.attribute_map("label", "attribute_labels")
+    }
+
+    /// The entry codes and their labels for `attribute`, unless they are
+    /// given by reference to another bundle.
+    pub fn entries(&self, attribute: String) -> Result<Option<OcaMap>, OcaException> {
+        guard(|| {
+            let overlay = lock(&self.0)?;
+            let entries = attribute_field(&overlay, "entry", "attribute_entries")
+                .and_then(|entries| entries.get(&attribute)?.as_object());
+            Ok(entries.map(|entries| {
+                let entries = entries
+                    .iter()
+                    .map(|(code, label)| (code.clone(), text(label)));
+                OcaMap(RustOpaque::new(Mutex::new(StringMap(entries.collect()))))
+            }))
+        })
+    }
+
+    /// The entry codes of `attribute`, unless they are given by reference
+    /// to another bundle.
+    pub fn entry_codes(&self, attribute: String) -> Result<Option<Vec<String>>, OcaException> {
+        guard(|| {
+            let overlay = lock(&self.0)?;
+            let entry_codes = attribute_field(&overlay, "entry_code", "attribute_entry_codes")
+                .and_then(|entry_codes| entry_codes.get(&attribute)?.as_array());
+            Ok(entry_codes.map(|entry_codes| entry_codes.iter().map(text).collect()))
+        })
+    }
+
+    pub fn formats(&self) -> Result<OcaMap, OcaException> {
+        self.attribute_map("format", "attribute_formats")
+    }
+
+    pub fn units(&self) -> Result<OcaMap, OcaException> {
+        self.attribute_map("unit", "attribute_units")
+    }
+
+    pub fn conformances(&self) -> Result<OcaMap, OcaException> {
+        self.attribute_map("conformance", "attribute_conformances")
+    }
+
+    pub fn cardinalities(&self) -> Result<OcaMap, OcaException> {
+        self.attribute_map("cardinality", "attribute_cardinalities")
+    }
+
+    /// The meta fields, such as `name` and `description`. Values that are
+    /// not text are given as JSON.
+    pub fn meta(&self) -> Result<OcaMap, OcaException> {
+        guard(|| {
+            let overlay = lock(&self.0)?;
+            let mut meta = HashMap::new();
+            if bundle::overlay_name(&overlay) == Some("meta") {
+                let fields = bundle::overlay_fields(&overlay).into_iter().flatten();
+                meta = fields
+                    .filter(|(field, _)| !OVERLAY_HEADER.contains(&field.as_str()))
+                    .map(|(field, value)| (field.clone(), text(value)))
+                    .collect();
+            }
+            Ok(OcaMap(RustOpaque::new(Mutex::new(StringMap(meta)))))
+        })
+    }
+
+    fn attribute_map(&self, name: &str, field: &str) -> Result<OcaMap, OcaException> {
+        guard(|| {
+            let overlay = lock(&self.0)?;
+            let values = attribute_field(&overlay, name, field).into_iter().flatten();
+            let values = values.map(|(attribute, value)| (attribute.clone(), text(value)));
+            Ok(OcaMap(RustOpaque::new(Mutex::new(StringMap(
+                values.collect(),
+            )))))
+        })
+    }
+}
+
+pub struct OcaAttributeDescriptor(pub RustOpaque<Mutex<OcaAttributeDescriptorRaw>>);
+
+/// An attribute together with what the overlays of its bundle say about it.
+/// The getters return nothing when no overlay covers the attribute.
+impl OcaAttributeDescriptor {
+    pub fn name(&self) -> Result<String, OcaException> {
+        guard(|| {
+            let descriptor = lock(&self.0)?;
+            Ok(descriptor.name.clone())
+        })
+    }
+
+    /// The OCAfile spelling of the type, e.g. `Array[Text]`.
+    pub fn attribute_type(&self) -> Result<Option<String>, OcaException> {
+        guard(|| {
+            let descriptor = lock(&self.0)?;
+            Ok(descriptor.attribute_type.as_ref().map(AttrType::to_string))
+        })
+    }
+
+    /// Languages and the label in each.
+    pub fn labels(&self) -> Result<OcaMap, OcaException> {
+        guard(|| {
+            let descriptor = lock(&self.0)?;
+            Ok(string_map(&descriptor.labels))
+        })
+    }
+
+    /// Languages and the information text in each.
+    pub fn information(&self) -> Result<OcaMap, OcaException> {
+        guard(|| {
+            let descriptor = lock(&self.0)?;
+            Ok(string_map(&descriptor.information))
+        })
+    }
+
+    pub fn conformance(&self) -> Result<Option<String>, OcaException> {
+        guard(|| Ok(lock(&self.0)?.conformance.clone()))
+    }
+
+    pub fn cardinality(&self) -> Result<Option<String>, OcaException> {
+        guard(|| Ok(lock(&self.0)?.cardinality.clone()))
+    }
+
+    pub fn format(&self) -> Result<Option<String>, OcaException> {
+        guard(|| Ok(lock(&self.0)?.format.clone()))
+    }
+
+    pub fn unit(&self) -> Result<Option<String>, OcaException> {
+        guard(|| Ok(lock(&self.0)?.unit.clone()))
+    }
+
+    pub fn character_encoding(&self) -> Result<Option<String>, OcaException> {
+        guard(|| Ok(lock(&self.0)?.character_encoding.clone()))
+    }
+
+    pub fn standard(&self) -> Result<Option<String>, OcaException> {
+        guard(|| Ok(lock(&self.0)?.standard.clone()))
+    }
+
+    /// The entry codes, unless they are given by reference to another
+    /// bundle.
+    pub fn entry_codes(&self) -> Result<Option<Vec<String>>, OcaException> {
+        guard(|| {
+            let descriptor = lock(&self.0)?;
+            let entry_codes = descriptor
+                .entry_codes
+                .as_ref()
+                .and_then(|codes| codes.as_array());
+            Ok(entry_codes.map(|entry_codes| entry_codes.iter().map(text).collect()))
+        })
+    }
+
+    /// The entry codes and their labels in `lang`.
+    pub fn entries(&self, lang: String) -> Result<Option<OcaMap>, OcaException> {
+        guard(|| {
+            let descriptor = lock(&self.0)?;
+            Ok(descriptor.entries.get(&lang).map(string_map))
+        })
+    }
+
+    pub fn sensitive(&self) -> Result<bool, OcaException> {
+        guard(|| Ok(lock(&self.0)?.sensitive))
+    }
+
+    pub fn to_json(&self) -> Result<String, OcaException> {
+        guard(|| {
+            let descriptor = lock(&self.0)?;
+            serde_json::to_string_pretty(&*descriptor).map_err(|err| {
+                OcaErrorKind::Bundle.error(format!("Failed to serialize attribute: {err}"))
+            })
+        })
+    }
+}
+
+fn string_map(values: &BTreeMap<String, String>) -> OcaMap {
+    let values = values
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()));
+    OcaMap(RustOpaque::new(Mutex::new(StringMap(values.collect()))))
+}
+
+/// Fields every overlay has, apart from its content.
+const OVERLAY_HEADER: &[&str] = &["d", "digest", "capture_base", "type", "language"];
+
+/// The per-attribute `field` of an overlay called `name`.
+fn attribute_field<'a>(
+    overlay: &'a serde_json::Value,
+    name: &str,
+    field: &str,
+) -> Option<&'a serde_json::Map<String, serde_json::Value>> {
+    if bundle::overlay_name(overlay) != Some(name) {
+        return None;
+    }
+    bundle::overlay_fields(overlay)?.get(field)?.as_object()
+}
+
+/// Text values as they are, anything else as JSON.
+fn text(value: &serde_json::Value) -> String {
+    value
+        .as_str()
+        .map_or_else(|| value.to_string(), str::to_string)
+}
+
+/// A problem with a bundle, as found by `OcaBundle::validate_semantics`.
+pub struct OcaSemanticIssue {
+    pub category: OcaSemanticCategory,
+    /// The short name of the overlay concerned, e.g. `label`.
+    pub overlay: Option<String>,
+    pub attribute: Option<String>,
+    pub message: String,
+}
+
+pub enum OcaSemanticCategory {
+    /// The bundle could not be read at all.
+    Format,
+    /// An overlay points at a different capture base.
+    CaptureBaseMismatch,
+    /// An overlay mentions an attribute the capture base does not declare.
+    UnknownAttribute,
+    /// An entry overlay translates a code the entry code overlay lacks.
+    EntryCodeMismatch,
+    /// Reported by the SDK validator without further structure.
+    Other,
+}
+
+impl From<SemanticIssue> for OcaSemanticIssue {
+    fn from(issue: SemanticIssue) -> OcaSemanticIssue {
+        let category = match issue.category {
+            Category::Format => OcaSemanticCategory::Format,
+            Category::CaptureBaseMismatch => OcaSemanticCategory::CaptureBaseMismatch,
+            Category::UnknownAttribute => OcaSemanticCategory::UnknownAttribute,
+            Category::EntryCodeMismatch => OcaSemanticCategory::EntryCodeMismatch,
+            Category::Other => OcaSemanticCategory::Other,
+        };
+        OcaSemanticIssue {
+            category,
+            overlay: issue.overlay,
+            attribute: issue.attribute,
+            message: issue.message,
+        }
+    }
+}
+
+/// A problem with a data record, as found by `OcaBundle::validate_data`.
+pub struct OcaDataError {
+    pub attribute: Option<String>,
+    /// JSON pointer to the offending value within the record.
+    pub path: String,
+    pub rule: OcaDataRule,
+    /// What the rule expects, as JSON, e.g. the list of entry codes.
+    pub expected: Option<String>,
+    /// The offending value as JSON.
+    pub actual: Option<String>,
+    pub message: String,
+}
+
+/// The constraint an `OcaDataError` refers to.
+pub enum OcaDataRule {
+    Type,
+    Conformance,
+    EntryCode,
+    Format,
+    Cardinality,
+    /// A referenced bundle could not be resolved.
+    Reference,
+}
+
+impl From<DataError> for OcaDataError {
+    fn from(error: DataError) -> OcaDataError {
+        let rule = match error.rule {
+            Rule::Type => OcaDataRule::Type,
+            Rule::Conformance => OcaDataRule::Conformance,
+            Rule::EntryCode => OcaDataRule::EntryCode,
+            Rule::Format => OcaDataRule::Format,
+            Rule::Cardinality => OcaDataRule::Cardinality,
+            Rule::Reference => OcaDataRule::Reference,
+        };
+        OcaDataError {
+            attribute: error.attribute,
+            path: error.path,
+            rule,
+            expected: error.expected.map(|value| value.to_string()),
+            actual: error.actual.map(|value| value.to_string()),
+            message: error.message,
+        }
+    }
+}
+
+/// The SAID of one object of a bundle, as checked by `verify_bundle`.
+pub struct OcaSaidVerification {
+    /// `capture_base`, `overlays[<index>]` or `bundle`.
+    pub path: String,
+    pub object_type: Option<String>,
+    pub language: Option<String>,
+    /// The SAID found in the object.
+    pub embedded: Option<String>,
+    /// The SAID recomputed from the object's content.
+    pub computed: Option<String>,
+    pub valid: bool,
+    pub message: Option<String>,
+}
+
+impl From<ObjectVerification> for OcaSaidVerification {
+    fn from(object: ObjectVerification) -> OcaSaidVerification {
+        OcaSaidVerification {
+            path: object.path,
+            object_type: object.object_type,
+            language: object.language,
+            embedded: object.embedded,
+            computed: object.computed,
+            valid: object.valid,
+            message: object.message,
+        }
+    }
+}
 
 // TODO: use regular HashMap when FRB supports it
 pub struct OcaMap(pub RustOpaque<Mutex<StringMap>>);
 pub struct StringMap(HashMap<String, String>);
 
 impl OcaMap {
-    pub fn new() -> OcaMap {
-        OcaMap(RustOpaque::new(Mutex::new(StringMap(HashMap::new()))))
+    pub fn new() -> Result<OcaMap, OcaException> {
+        guard(|| {
+            Ok(OcaMap(RustOpaque::new(Mutex::new(StringMap(
+                HashMap::new(),
+            )))))
+        })
     }
 
-    pub fn insert(&self, key: String, value: String) {
-        let mut map = self.0.lock().unwrap();
-        map.0.insert(key, value);
+    pub fn insert(&self, key: String, value: String) -> Result<(), OcaException> {
+        guard(|| {
+            let mut map = lock(&self.0)?;
+            map.0.insert(key, value);
+            Ok(())
+        })
     }
 
-    pub fn get(&self, key: String) -> Option<String> {
-        let map = self.0.lock().unwrap();
-        map.0.get(&key).map(|v| v.to_owned())
+    pub fn get(&self, key: String) -> Result<Option<String>, OcaException> {
+        guard(|| {
+            let map = lock(&self.0)?;
+            Ok(map.0.get(&key).map(|v| v.to_owned()))
+        })
     }
 
-    pub fn remove(&self, key: String) {
-        let mut map = self.0.lock().unwrap();
-        map.0.remove(&key);
+    pub fn remove(&self, key: String) -> Result<(), OcaException> {
+        guard(|| {
+            let mut map = lock(&self.0)?;
+            map.0.remove(&key);
+            Ok(())
+        })
     }
 
-    pub fn get_keys(&self) -> Vec<String> {
-        let map = self.0.lock().unwrap();
-        map.0.keys().map(|k| k.to_owned()).collect()
+    pub fn get_keys(&self) -> Result<Vec<String>, OcaException> {
+        guard(|| {
+            let map = lock(&self.0)?;
+            Ok(map.0.keys().map(|k| k.to_owned()).collect())
+        })
     }
 }
 
-pub fn load_oca(json: String) -> Result<OcaBundle> {
-    let bundle =
-        oca_bundle::controller::load_oca(&mut json.as_bytes()).map_err(|err| anyhow!(err))?;
-    Ok(OcaBundle(RustOpaque::new(Mutex::new(bundle))))
+/// Loads bundle JSON of the 2.0 format, or of the 1.x format, which is
+/// upgraded first; see `OcaBundle::upgrade_notes`. The SAIDs of an upgraded
+/// bundle differ from those of the 1.x bundle.
+pub fn load_oca(json: String, registry: Option<OcaRegistry>) -> Result<OcaBundle, OcaException> {
+    guard(|| {
+        let registry = registry_or_default(registry);
+        let bundle = parse_bundle_json(&json)?;
+        if legacy::is_legacy(&bundle) {
+            let upgrade = legacy::upgrade(&bundle).map_err(|err| {
+                OcaErrorKind::Bundle.error(format!("Failed to upgrade 1.x bundle: {err}"))
+            })?;
+            let model = build_rendered(&upgrade.rendered, &registry)?;
+            return OcaBundle::new(model, registry, upgrade.skipped);
+        }
+        version::require_supported(&bundle).map_err(|err| OcaErrorKind::Bundle.error(err))?;
+        let model = load_model(&json, &registry)?;
+        OcaBundle::new(model, registry, vec![])
+    })
+}
+
+/// Whether `load_oca` would upgrade the bundle from the 1.x format.
+pub fn is_legacy_bundle(json: String) -> Result<bool, OcaException> {
+    guard(|| Ok(legacy::is_legacy(&parse_bundle_json(&json)?)))
+}
+
+/// Returns the OCAfile AST as JSON. `meta` and `commands` are what most
+/// callers want.
+pub fn parse_ocafile(
+    ocafile: String,
+    registry: Option<OcaRegistry>,
+) -> Result<String, OcaException> {
+    guard(|| {
+        let registry = registry_or_default(registry);
+        let ast = parse_ocafile_ast(ocafile, &registry)?;
+        serde_json::to_string(&ast).map_err(|err| {
+            OcaErrorKind::Parse.error(format!("Failed to serialize OCAfile AST: {err}"))
+        })
+    })
+}
+
+/// With a `base` bundle, the OCAfile's commands are applied on top of it.
+pub fn build_from_ocafile(
+    ocafile: String,
+    registry: Option<OcaRegistry>,
+    base: Option<OcaBundle>,
+) -> Result<OcaBundle, OcaException> {
+    guard(|| {
+        let registry = registry_or_default(registry);
+        let base = match &base {
+            Some(base) => Some(lock(&base.0)?.model.clone()),
+            None => None,
+        };
+        let ast = parse_ocafile_ast(ocafile, &registry)?;
+        let model = build_ast(base, &ast)?;
+        OcaBundle::new(model, registry, vec![])
+    })
+}
+
+pub fn generate_ocafile(bundle: OcaBundle) -> Result<String, OcaException> {
+    guard(|| {
+        let oca_bundle = lock(&bundle.0)?;
+        Ok(ocafile::generate_from_ast(&oca_bundle.model.to_ast()))
+    })
+}
+
+/// Recomputes the SAIDs of the capture base, each overlay and the bundle and
+/// compares them with the embedded ones, in that order. The bundle is intact
+/// when every one of them is `valid`.
+pub fn verify_bundle(json: String) -> Result<Vec<OcaSaidVerification>, OcaException> {
+    guard(|| {
+        let oca_bundle = parse_bundle_json(&json)?;
+        Ok(said::verify(&oca_bundle)
+            .objects
+            .into_iter()
+            .map(OcaSaidVerification::from)
+            .collect())
+    })
+}
+
+fn load_model(json: &str, registry: &OcaRegistryRaw) -> Result<OCABundleModel, OcaException> {
+    oca_sdk_rs::oca::bundle::load(&mut json.as_bytes(), registry)
+        .map_err(|err| OcaErrorKind::Bundle.error(format!("Failed to load bundle: {err}")))
+}
+
+fn parse_bundle_json(json: &str) -> Result<serde_json::Value, OcaException> {
+    serde_json::from_str(json)
+        .map_err(|err| OcaErrorKind::Parse.error(format!("Invalid bundle JSON: {err}")))
+}
+
+fn parse_ocafile_ast(ocafile: String, registry: &OcaRegistryRaw) -> Result<OCAAst, OcaException> {
+    ocafile::parse_from_string(ocafile, registry)
+        .map_err(|err| OcaErrorKind::Parse.error(format!("Failed to parse OCAfile: {err}")))
+}
+
+/// Parses an OCAfile rendered by `OcaBoxRaw`, puts back the text it could
+/// not carry, and builds the bundle. The rendered OCAfile comes from the
+/// builder, so failing to read it back is a bundle error.
+fn build_rendered(
+    rendered: &Rendered,
+    registry: &OcaRegistryRaw,
+) -> Result<OCABundleModel, OcaException> {
+    let ast = rendered
+        .read_back(None, |text| ocafile::parse_from_string(text, registry))
+        .map_err(|err| OcaErrorKind::Bundle.error(err.to_string()))?;
+    build_ast(None, &ast)
+}
+
+fn build_ast(base: Option<OCABundleModel>, ast: &OCAAst) -> Result<OCABundleModel, OcaException> {
+    let build = oca_sdk_rs::oca::bundle::from_ast(base, ast).map_err(|errors| {
+        let summary = errors
+            .iter()
+            .map(|err| err.to_string())
+            .collect::<Vec<_>>()
+            .join("; ");
+        OcaErrorKind::Bundle.error(format!("Failed to build bundle: {summary}"))
+    })?;
+    Ok(build.oca_bundle)
 }
@@ -2,8 +2,59 @@ use super::*;
 // Section: wire functions
 
 #[no_mangle]
-pub extern "C" fn wire_load_oca(port_: i64, json: *mut wire_uint_8_list) {
-    wire_load_oca_impl(port_, json)
+pub extern "C" fn wire_load_oca(
+    port_: i64,
+    json: *mut wire_uint_8_list,
+    registry: *mut wire_OcaRegistry,
+) {
+    wire_load_oca_impl(port_, json, registry)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_is_legacy_bundle(port_: i64, json: *mut wire_uint_8_list) {
+    wire_is_legacy_bundle_impl(port_, json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_parse_ocafile(
+    port_: i64,
+    ocafile: *mut wire_uint_8_list,
+    registry: *mut wire_OcaRegistry,
+) {
+    wire_parse_ocafile_impl(port_, ocafile, registry)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_build_from_ocafile(
+    port_: i64,
+    ocafile: *mut wire_uint_8_list,
+    registry: *mut wire_OcaRegistry,
+    base: *mut wire_OcaBundle,
+) {
+    wire_build_from_ocafile_impl(port_, ocafile, registry, base)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_generate_ocafile(port_: i64, bundle: *mut wire_OcaBundle) {
+    wire_generate_ocafile_impl(port_, bundle)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_verify_bundle(port_: i64, json: *mut wire_uint_8_list) {
+    wire_verify_bundle_impl(port_, json)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_new__static_method__OcaRegistry(port_: i64) {
+    wire_new__static_method__OcaRegistry_impl(port_)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_from_overlay_file__static_method__OcaRegistry(
+    port_: i64,
+    overlay_file: *mut wire_uint_8_list,
+) {
+    wire_from_overlay_file__static_method__OcaRegistry_impl(port_, overlay_file)
 }
 
 #[no_mangle]
@@ -32,26 +83,12 @@ pub extern "C" fn wire_add_attribute__method__OcaBox(
 }
 
 #[no_mangle]
-pub extern "C" fn wire_generate_bundle__method__OcaBox(port_: i64, that: *mut wire_OcaBox) {
-    wire_generate_bundle__method__OcaBox_impl(port_, that)
-}
-
-#[no_mangle]
-pub extern "C" fn wire_add_form_layout__method__OcaBox(
+pub extern "C" fn wire_generate_bundle__method__OcaBox(
     port_: i64,
     that: *mut wire_OcaBox,
-    layout: *mut wire_uint_8_list,
+    registry: *mut wire_OcaRegistry,
 ) {
-    wire_add_form_layout__method__OcaBox_impl(port_, that, layout)
-}
-
-#[no_mangle]
-pub extern "C" fn wire_add_credential_layout__method__OcaBox(
-    port_: i64,
-    that: *mut wire_OcaBox,
-    layout: *mut wire_uint_8_list,
-) {
-    wire_add_credential_layout__method__OcaBox_impl(port_, that, layout)
+    wire_generate_bundle__method__OcaBox_impl(port_, that, registry)
 }
 
 #[no_mangle]
@@ -68,6 +105,15 @@ pub extern "C" fn wire_set_attribute_type__method__OcaAttr(
     wire_set_attribute_type__method__OcaAttr_impl(port_, that, attr_type)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_set_attribute_type_spelling__method__OcaAttr(
+    port_: i64,
+    that: *mut wire_OcaAttr,
+    spelling: *mut wire_uint_8_list,
+) {
+    wire_set_attribute_type_spelling__method__OcaAttr_impl(port_, that, spelling)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_set_flagged__method__OcaAttr(port_: i64, that: *mut wire_OcaAttr) {
     wire_set_flagged__method__OcaAttr_impl(port_, that)
@@ -129,15 +175,6 @@ pub extern "C" fn wire_set_entry_codes__method__OcaAttr(
     wire_set_entry_codes__method__OcaAttr_impl(port_, that, entry_codes)
 }
 
-#[no_mangle]
-pub extern "C" fn wire_set_entry_codes_sai__method__OcaAttr(
-    port_: i64,
-    that: *mut wire_OcaAttr,
-    sai: *mut wire_uint_8_list,
-) {
-    wire_set_entry_codes_sai__method__OcaAttr_impl(port_, that, sai)
-}
-
 #[no_mangle]
 pub extern "C" fn wire_set_entry__method__OcaAttr(
     port_: i64,
@@ -149,21 +186,12 @@ pub extern "C" fn wire_set_entry__method__OcaAttr(
 }
 
 #[no_mangle]
-pub extern "C" fn wire_set_unit_metric__method__OcaAttr(
-    port_: i64,
-    that: *mut wire_OcaAttr,
-    unit: i32,
-) {
-    wire_set_unit_metric__method__OcaAttr_impl(port_, that, unit)
-}
-
-#[no_mangle]
-pub extern "C" fn wire_set_unit_imperial__method__OcaAttr(
+pub extern "C" fn wire_set_unit__method__OcaAttr(
     port_: i64,
     that: *mut wire_OcaAttr,
-    unit: i32,
+    unit: *mut wire_uint_8_list,
 ) {
-    wire_set_unit_imperial__method__OcaAttr_impl(port_, that, unit)
+    wire_set_unit__method__OcaAttr_impl(port_, that, unit)
 }
 
 #[no_mangle]
@@ -195,6 +223,65 @@ pub extern "C" fn wire_overlays__method__OcaBundle(port_: i64, that: *mut wire_O
     wire_overlays__method__OcaBundle_impl(port_, that)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_languages__method__OcaBundle(port_: i64, that: *mut wire_OcaBundle) {
+    wire_languages__method__OcaBundle_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_keep_languages__method__OcaBundle(
+    port_: i64,
+    that: *mut wire_OcaBundle,
+    keep: *mut wire_StringList,
+) {
+    wire_keep_languages__method__OcaBundle_impl(port_, that, keep)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_extract_translation__method__OcaBundle(
+    port_: i64,
+    that: *mut wire_OcaBundle,
+    language: *mut wire_uint_8_list,
+) {
+    wire_extract_translation__method__OcaBundle_impl(port_, that, language)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_merge_translation__method__OcaBundle(
+    port_: i64,
+    that: *mut wire_OcaBundle,
+    translation: *mut wire_uint_8_list,
+) {
+    wire_merge_translation__method__OcaBundle_impl(port_, that, translation)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_attributes__method__OcaBundle(port_: i64, that: *mut wire_OcaBundle) {
+    wire_attributes__method__OcaBundle_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_upgrade_notes__method__OcaBundle(port_: i64, that: *mut wire_OcaBundle) {
+    wire_upgrade_notes__method__OcaBundle_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_validate_semantics__method__OcaBundle(
+    port_: i64,
+    that: *mut wire_OcaBundle,
+) {
+    wire_validate_semantics__method__OcaBundle_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_validate_data__method__OcaBundle(
+    port_: i64,
+    that: *mut wire_OcaBundle,
+    record: *mut wire_uint_8_list,
+) {
+    wire_validate_data__method__OcaBundle_impl(port_, that, record)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_attributes__method__OcaCaptureBase(
     port_: i64,
@@ -211,6 +298,197 @@ pub extern "C" fn wire_flagged_attributes__method__OcaCaptureBase(
     wire_flagged_attributes__method__OcaCaptureBase_impl(port_, that)
 }
 
+#[no_mangle]
+pub extern "C" fn wire_overlay_type__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_overlay_type__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_name__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_name__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_language__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_language__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_said__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_said__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_capture_base__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_capture_base__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_to_json__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_to_json__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_labels__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_labels__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_entries__method__OcaOverlay(
+    port_: i64,
+    that: *mut wire_OcaOverlay,
+    attribute: *mut wire_uint_8_list,
+) {
+    wire_entries__method__OcaOverlay_impl(port_, that, attribute)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_entry_codes__method__OcaOverlay(
+    port_: i64,
+    that: *mut wire_OcaOverlay,
+    attribute: *mut wire_uint_8_list,
+) {
+    wire_entry_codes__method__OcaOverlay_impl(port_, that, attribute)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_formats__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_formats__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_units__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_units__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_conformances__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_conformances__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_cardinalities__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_cardinalities__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_meta__method__OcaOverlay(port_: i64, that: *mut wire_OcaOverlay) {
+    wire_meta__method__OcaOverlay_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_name__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_name__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_attribute_type__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_attribute_type__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_labels__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_labels__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_information__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_information__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_conformance__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_conformance__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_cardinality__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_cardinality__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_format__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_format__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_unit__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_unit__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_character_encoding__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_character_encoding__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_standard__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_standard__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_entry_codes__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_entry_codes__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_entries__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+    lang: *mut wire_uint_8_list,
+) {
+    wire_entries__method__OcaAttributeDescriptor_impl(port_, that, lang)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_sensitive__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_sensitive__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn wire_to_json__method__OcaAttributeDescriptor(
+    port_: i64,
+    that: *mut wire_OcaAttributeDescriptor,
+) {
+    wire_to_json__method__OcaAttributeDescriptor_impl(port_, that)
+}
+
 #[no_mangle]
 pub extern "C" fn wire_new__static_method__OcaMap(port_: i64) {
     wire_new__static_method__OcaMap_impl(port_)
@@ -256,6 +534,11 @@ pub extern "C" fn new_MutexOcaAttrRaw() -> wire_MutexOcaAttrRaw {
     wire_MutexOcaAttrRaw::new_with_null_ptr()
 }
 
+#[no_mangle]
+pub extern "C" fn new_MutexOcaAttributeDescriptorRaw() -> wire_MutexOcaAttributeDescriptorRaw {
+    wire_MutexOcaAttributeDescriptorRaw::new_with_null_ptr()
+}
+
 #[no_mangle]
 pub extern "C" fn new_MutexOcaBoxRaw() -> wire_MutexOcaBoxRaw {
     wire_MutexOcaBoxRaw::new_with_null_ptr()
@@ -271,11 +554,21 @@ pub extern "C" fn new_MutexOcaCaptureBaseRaw() -> wire_MutexOcaCaptureBaseRaw {
     wire_MutexOcaCaptureBaseRaw::new_with_null_ptr()
 }
 
+#[no_mangle]
+pub extern "C" fn new_MutexOcaOverlayRaw() -> wire_MutexOcaOverlayRaw {
+    wire_MutexOcaOverlayRaw::new_with_null_ptr()
+}
+
 #[no_mangle]
 pub extern "C" fn new_MutexStringMap() -> wire_MutexStringMap {
     wire_MutexStringMap::new_with_null_ptr()
 }
 
+#[no_mangle]
+pub extern "C" fn new_OcaRegistryRaw() -> wire_OcaRegistryRaw {
+    wire_OcaRegistryRaw::new_with_null_ptr()
+}
+
 #[no_mangle]
 pub extern "C" fn new_StringList_0(len: i32) -> *mut wire_StringList {
     let wrap = wire_StringList {
@@ -290,6 +583,11 @@ pub extern "C" fn new_box_autoadd_oca_attr_0() -> *mut wire_OcaAttr {
     support::new_leak_box_ptr(wire_OcaAttr::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_oca_attribute_descriptor_0() -> *mut wire_OcaAttributeDescriptor {
+    support::new_leak_box_ptr(wire_OcaAttributeDescriptor::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_box_autoadd_oca_box_0() -> *mut wire_OcaBox {
     support::new_leak_box_ptr(wire_OcaBox::new_with_null_ptr())
@@ -310,6 +608,16 @@ pub extern "C" fn new_box_autoadd_oca_map_0() -> *mut wire_OcaMap {
     support::new_leak_box_ptr(wire_OcaMap::new_with_null_ptr())
 }
 
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_oca_overlay_0() -> *mut wire_OcaOverlay {
+    support::new_leak_box_ptr(wire_OcaOverlay::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn new_box_autoadd_oca_registry_0() -> *mut wire_OcaRegistry {
+    support::new_leak_box_ptr(wire_OcaRegistry::new_with_null_ptr())
+}
+
 #[no_mangle]
 pub extern "C" fn new_uint_8_list_0(len: i32) -> *mut wire_uint_8_list {
     let ans = wire_uint_8_list {
@@ -322,31 +630,31 @@ pub extern "C" fn new_uint_8_list_0(len: i32) -> *mut wire_uint_8_list {
 // Section: related functions
 
 #[no_mangle]
-pub extern "C" fn drop_opaque_MutexDynOverlay(ptr: *const c_void) {
+pub extern "C" fn drop_opaque_MutexOcaAttrRaw(ptr: *const c_void) {
     unsafe {
-        Arc::<Mutex<DynOverlay>>::decrement_strong_count(ptr as _);
+        Arc::<Mutex<OcaAttrRaw>>::decrement_strong_count(ptr as _);
     }
 }
 
 #[no_mangle]
-pub extern "C" fn share_opaque_MutexDynOverlay(ptr: *const c_void) -> *const c_void {
+pub extern "C" fn share_opaque_MutexOcaAttrRaw(ptr: *const c_void) -> *const c_void {
     unsafe {
-        Arc::<Mutex<DynOverlay>>::increment_strong_count(ptr as _);
+        Arc::<Mutex<OcaAttrRaw>>::increment_strong_count(ptr as _);
         ptr
     }
 }
 
 #[no_mangle]
-pub extern "C" fn drop_opaque_MutexOcaAttrRaw(ptr: *const c_void) {
+pub extern "C" fn drop_opaque_MutexOcaAttributeDescriptorRaw(ptr: *const c_void) {
     unsafe {
-        Arc::<Mutex<OcaAttrRaw>>::decrement_strong_count(ptr as _);
+        Arc::<Mutex<OcaAttributeDescriptorRaw>>::decrement_strong_count(ptr as _);
     }
 }
 
 #[no_mangle]
-pub extern "C" fn share_opaque_MutexOcaAttrRaw(ptr: *const c_void) -> *const c_void {
+pub extern "C" fn share_opaque_MutexOcaAttributeDescriptorRaw(ptr: *const c_void) -> *const c_void {
     unsafe {
-        Arc::<Mutex<OcaAttrRaw>>::increment_strong_count(ptr as _);
+        Arc::<Mutex<OcaAttributeDescriptorRaw>>::increment_strong_count(ptr as _);
         ptr
     }
 }
@@ -396,6 +704,21 @@ pub extern "C" fn share_opaque_MutexOcaCaptureBaseRaw(ptr: *const c_void) -> *co
     }
 }
 
+#[no_mangle]
+pub extern "C" fn drop_opaque_MutexOcaOverlayRaw(ptr: *const c_void) {
+    unsafe {
+        Arc::<Mutex<OcaOverlayRaw>>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn share_opaque_MutexOcaOverlayRaw(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<Mutex<OcaOverlayRaw>>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn drop_opaque_MutexStringMap(ptr: *const c_void) {
     unsafe {
@@ -411,6 +734,21 @@ pub extern "C" fn share_opaque_MutexStringMap(ptr: *const c_void) -> *const c_vo
     }
 }
 
+#[no_mangle]
+pub extern "C" fn drop_opaque_OcaRegistryRaw(ptr: *const c_void) {
+    unsafe {
+        Arc::<OcaRegistryRaw>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn share_opaque_OcaRegistryRaw(ptr: *const c_void) -> *const c_void {
+    unsafe {
+        Arc::<OcaRegistryRaw>::increment_strong_count(ptr as _);
+        ptr
+    }
+}
+
 // Section: impl Wire2Api
 
 impl Wire2Api<RustOpaque<Mutex<OcaAttrRaw>>> for wire_MutexOcaAttrRaw {
@@ -418,6 +756,13 @@ impl Wire2Api<RustOpaque<Mutex<OcaAttrRaw>>> for wire_MutexOcaAttrRaw {
         unsafe { support::opaque_from_dart(self.ptr as _) }
     }
 }
+impl Wire2Api<RustOpaque<Mutex<OcaAttributeDescriptorRaw>>>
+    for wire_MutexOcaAttributeDescriptorRaw
+{
+    fn wire2api(self) -> RustOpaque<Mutex<OcaAttributeDescriptorRaw>> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
 impl Wire2Api<RustOpaque<Mutex<OcaBoxRaw>>> for wire_MutexOcaBoxRaw {
     fn wire2api(self) -> RustOpaque<Mutex<OcaBoxRaw>> {
         unsafe { support::opaque_from_dart(self.ptr as _) }
@@ -433,11 +778,21 @@ impl Wire2Api<RustOpaque<Mutex<OcaCaptureBaseRaw>>> for wire_MutexOcaCaptureBase
         unsafe { support::opaque_from_dart(self.ptr as _) }
     }
 }
+impl Wire2Api<RustOpaque<Mutex<OcaOverlayRaw>>> for wire_MutexOcaOverlayRaw {
+    fn wire2api(self) -> RustOpaque<Mutex<OcaOverlayRaw>> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
 impl Wire2Api<RustOpaque<Mutex<StringMap>>> for wire_MutexStringMap {
     fn wire2api(self) -> RustOpaque<Mutex<StringMap>> {
         unsafe { support::opaque_from_dart(self.ptr as _) }
     }
 }
+impl Wire2Api<RustOpaque<OcaRegistryRaw>> for wire_OcaRegistryRaw {
+    fn wire2api(self) -> RustOpaque<OcaRegistryRaw> {
+        unsafe { support::opaque_from_dart(self.ptr as _) }
+    }
+}
 impl Wire2Api<String> for *mut wire_uint_8_list {
     fn wire2api(self) -> String {
         let vec: Vec<u8> = self.wire2api();
@@ -459,6 +814,12 @@ impl Wire2Api<OcaAttr> for *mut wire_OcaAttr {
         Wire2Api::<OcaAttr>::wire2api(*wrap).into()
     }
 }
+impl Wire2Api<OcaAttributeDescriptor> for *mut wire_OcaAttributeDescriptor {
+    fn wire2api(self) -> OcaAttributeDescriptor {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<OcaAttributeDescriptor>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<OcaBox> for *mut wire_OcaBox {
     fn wire2api(self) -> OcaBox {
         let wrap = unsafe { support::box_from_leak_ptr(self) };
@@ -483,13 +844,28 @@ impl Wire2Api<OcaMap> for *mut wire_OcaMap {
         Wire2Api::<OcaMap>::wire2api(*wrap).into()
     }
 }
-
+impl Wire2Api<OcaOverlay> for *mut wire_OcaOverlay {
+    fn wire2api(self) -> OcaOverlay {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<OcaOverlay>::wire2api(*wrap).into()
+    }
+}
+impl Wire2Api<OcaRegistry> for *mut wire_OcaRegistry {
+    fn wire2api(self) -> OcaRegistry {
+        let wrap = unsafe { support::box_from_leak_ptr(self) };
+        Wire2Api::<OcaRegistry>::wire2api(*wrap).into()
+    }
+}
 impl Wire2Api<OcaAttr> for wire_OcaAttr {
     fn wire2api(self) -> OcaAttr {
         OcaAttr(self.field0.wire2api())
     }
 }
-
+impl Wire2Api<OcaAttributeDescriptor> for wire_OcaAttributeDescriptor {
+    fn wire2api(self) -> OcaAttributeDescriptor {
+        OcaAttributeDescriptor(self.field0.wire2api())
+    }
+}
 impl Wire2Api<OcaBox> for wire_OcaBox {
     fn wire2api(self) -> OcaBox {
         OcaBox(self.field0.wire2api())
@@ -505,13 +881,21 @@ impl Wire2Api<OcaCaptureBase> for wire_OcaCaptureBase {
         OcaCaptureBase(self.field0.wire2api())
     }
 }
-
 impl Wire2Api<OcaMap> for wire_OcaMap {
     fn wire2api(self) -> OcaMap {
         OcaMap(self.field0.wire2api())
     }
 }
-
+impl Wire2Api<OcaOverlay> for wire_OcaOverlay {
+    fn wire2api(self) -> OcaOverlay {
+        OcaOverlay(self.field0.wire2api())
+    }
+}
+impl Wire2Api<OcaRegistry> for wire_OcaRegistry {
+    fn wire2api(self) -> OcaRegistry {
+        OcaRegistry(self.field0.wire2api())
+    }
+}
 impl Wire2Api<Vec<u8>> for *mut wire_uint_8_list {
     fn wire2api(self) -> Vec<u8> {
         unsafe {
@@ -528,6 +912,12 @@ pub struct wire_MutexOcaAttrRaw {
     ptr: *const core::ffi::c_void,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_MutexOcaAttributeDescriptorRaw {
+    ptr: *const core::ffi::c_void,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_MutexOcaBoxRaw {
@@ -546,12 +936,24 @@ pub struct wire_MutexOcaCaptureBaseRaw {
     ptr: *const core::ffi::c_void,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_MutexOcaOverlayRaw {
+    ptr: *const core::ffi::c_void,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_MutexStringMap {
     ptr: *const core::ffi::c_void,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_OcaRegistryRaw {
+    ptr: *const core::ffi::c_void,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_StringList {
@@ -565,6 +967,12 @@ pub struct wire_OcaAttr {
     field0: wire_MutexOcaAttrRaw,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_OcaAttributeDescriptor {
+    field0: wire_MutexOcaAttributeDescriptorRaw,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_OcaBox {
@@ -589,6 +997,18 @@ pub struct wire_OcaMap {
     field0: wire_MutexStringMap,
 }
 
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_OcaOverlay {
+    field0: wire_MutexOcaOverlayRaw,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+pub struct wire_OcaRegistry {
+    field0: wire_OcaRegistryRaw,
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct wire_uint_8_list {
@@ -615,6 +1035,13 @@ impl NewWithNullPtr for wire_MutexOcaAttrRaw {
         }
     }
 }
+impl NewWithNullPtr for wire_MutexOcaAttributeDescriptorRaw {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
 impl NewWithNullPtr for wire_MutexOcaBoxRaw {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -636,6 +1063,13 @@ impl NewWithNullPtr for wire_MutexOcaCaptureBaseRaw {
         }
     }
 }
+impl NewWithNullPtr for wire_MutexOcaOverlayRaw {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
 impl NewWithNullPtr for wire_MutexStringMap {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -643,6 +1077,13 @@ impl NewWithNullPtr for wire_MutexStringMap {
         }
     }
 }
+impl NewWithNullPtr for wire_OcaRegistryRaw {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            ptr: core::ptr::null(),
+        }
+    }
+}
 
 impl NewWithNullPtr for wire_OcaAttr {
     fn new_with_null_ptr() -> Self {
@@ -658,6 +1099,20 @@ impl Default for wire_OcaAttr {
     }
 }
 
+impl NewWithNullPtr for wire_OcaAttributeDescriptor {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: wire_MutexOcaAttributeDescriptorRaw::new_with_null_ptr(),
+        }
+    }
+}
+
+impl Default for wire_OcaAttributeDescriptor {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 impl NewWithNullPtr for wire_OcaBox {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -714,6 +1169,34 @@ impl Default for wire_OcaMap {
     }
 }
 
+impl NewWithNullPtr for wire_OcaOverlay {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: wire_MutexOcaOverlayRaw::new_with_null_ptr(),
+        }
+    }
+}
+
+impl Default for wire_OcaOverlay {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
+impl NewWithNullPtr for wire_OcaRegistry {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            field0: wire_OcaRegistryRaw::new_with_null_ptr(),
+        }
+    }
+}
+
+impl Default for wire_OcaRegistry {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+
 // Section: sync execution mode utility
 
 #[no_mangle]
@@ -22,482 +22,1114 @@ use std::sync::Arc;
 
 // Section: wire functions
 
-fn wire_load_oca_impl(port_: MessagePort, json: impl Wire2Api<String> + UnwindSafe) {
+fn wire_load_oca_impl(
+    port_: MessagePort,
+    json: impl Wire2Api<String> + UnwindSafe,
+    registry: impl Wire2Api<Option<OcaRegistry>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaBundle, _>(
+        WrapInfo {
+            debug_name: "load_oca",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_json = json.wire2api();
+            let api_registry = registry.wire2api();
+            move |task_callback| load_oca(api_json, api_registry)
+        },
+    )
+}
+fn wire_is_legacy_bundle_impl(port_: MessagePort, json: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
+        WrapInfo {
+            debug_name: "is_legacy_bundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_json = json.wire2api();
+            move |task_callback| is_legacy_bundle(api_json)
+        },
+    )
+}
+fn wire_parse_ocafile_impl(
+    port_: MessagePort,
+    ocafile: impl Wire2Api<String> + UnwindSafe,
+    registry: impl Wire2Api<Option<OcaRegistry>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "parse_ocafile",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_ocafile = ocafile.wire2api();
+            let api_registry = registry.wire2api();
+            move |task_callback| parse_ocafile(api_ocafile, api_registry)
+        },
+    )
+}
+fn wire_build_from_ocafile_impl(
+    port_: MessagePort,
+    ocafile: impl Wire2Api<String> + UnwindSafe,
+    registry: impl Wire2Api<Option<OcaRegistry>> + UnwindSafe,
+    base: impl Wire2Api<Option<OcaBundle>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaBundle, _>(
+        WrapInfo {
+            debug_name: "build_from_ocafile",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_ocafile = ocafile.wire2api();
+            let api_registry = registry.wire2api();
+            let api_base = base.wire2api();
+            move |task_callback| build_from_ocafile(api_ocafile, api_registry, api_base)
+        },
+    )
+}
+fn wire_generate_ocafile_impl(port_: MessagePort, bundle: impl Wire2Api<OcaBundle> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "generate_ocafile",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_bundle = bundle.wire2api();
+            move |task_callback| generate_ocafile(api_bundle)
+        },
+    )
+}
+fn wire_verify_bundle_impl(port_: MessagePort, json: impl Wire2Api<String> + UnwindSafe) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<OcaSaidVerification>, _>(
+        WrapInfo {
+            debug_name: "verify_bundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_json = json.wire2api();
+            move |task_callback| verify_bundle(api_json)
+        },
+    )
+}
+fn wire_new__static_method__OcaRegistry_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaRegistry, _>(
+        WrapInfo {
+            debug_name: "new__static_method__OcaRegistry",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| OcaRegistry::new(),
+    )
+}
+fn wire_from_overlay_file__static_method__OcaRegistry_impl(
+    port_: MessagePort,
+    overlay_file: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaRegistry, _>(
+        WrapInfo {
+            debug_name: "from_overlay_file__static_method__OcaRegistry",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_overlay_file = overlay_file.wire2api();
+            move |task_callback| OcaRegistry::from_overlay_file(api_overlay_file)
+        },
+    )
+}
+fn wire_new__static_method__OcaBox_impl(port_: MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaBox, _>(
+        WrapInfo {
+            debug_name: "new__static_method__OcaBox",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || move |task_callback| OcaBox::new(),
+    )
+}
+fn wire_add_meta__method__OcaBox_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBox> + UnwindSafe,
+    lang: impl Wire2Api<String> + UnwindSafe,
+    name: impl Wire2Api<String> + UnwindSafe,
+    value: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "add_meta__method__OcaBox",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_lang = lang.wire2api();
+            let api_name = name.wire2api();
+            let api_value = value.wire2api();
+            move |task_callback| OcaBox::add_meta(&api_that, api_lang, api_name, api_value)
+        },
+    )
+}
+fn wire_add_attribute__method__OcaBox_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBox> + UnwindSafe,
+    attr: impl Wire2Api<OcaAttr> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "add_attribute__method__OcaBox",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_attr = attr.wire2api();
+            move |task_callback| OcaBox::add_attribute(&api_that, api_attr)
+        },
+    )
+}
+fn wire_generate_bundle__method__OcaBox_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBox> + UnwindSafe,
+    registry: impl Wire2Api<Option<OcaRegistry>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaBundle, _>(
+        WrapInfo {
+            debug_name: "generate_bundle__method__OcaBox",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_registry = registry.wire2api();
+            move |task_callback| OcaBox::generate_bundle(&api_that, api_registry)
+        },
+    )
+}
+fn wire_new__static_method__OcaAttr_impl(
+    port_: MessagePort,
+    name: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaAttr, _>(
+        WrapInfo {
+            debug_name: "new__static_method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_name = name.wire2api();
+            move |task_callback| OcaAttr::new(api_name)
+        },
+    )
+}
+fn wire_set_attribute_type__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    attr_type: impl Wire2Api<OcaAttrType> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_attribute_type__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_attr_type = attr_type.wire2api();
+            move |task_callback| OcaAttr::set_attribute_type(&api_that, api_attr_type)
+        },
+    )
+}
+fn wire_set_attribute_type_spelling__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    spelling: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_attribute_type_spelling__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_spelling = spelling.wire2api();
+            move |task_callback| OcaAttr::set_attribute_type_spelling(&api_that, api_spelling)
+        },
+    )
+}
+fn wire_set_flagged__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_flagged__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaAttr::set_flagged(&api_that)
+        },
+    )
+}
+fn wire_set_encoding__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    encoding: impl Wire2Api<OcaEncoding> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_encoding__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_encoding = encoding.wire2api();
+            move |task_callback| OcaAttr::set_encoding(&api_that, api_encoding)
+        },
+    )
+}
+fn wire_set_cardinality__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    cardinality: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_cardinality__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_cardinality = cardinality.wire2api();
+            move |task_callback| OcaAttr::set_cardinality(&api_that, api_cardinality)
+        },
+    )
+}
+fn wire_set_conformance__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    conformance: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_conformance__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_conformance = conformance.wire2api();
+            move |task_callback| OcaAttr::set_conformance(&api_that, api_conformance)
+        },
+    )
+}
+fn wire_set_label__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    lang: impl Wire2Api<String> + UnwindSafe,
+    label: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_label__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_lang = lang.wire2api();
+            let api_label = label.wire2api();
+            move |task_callback| OcaAttr::set_label(&api_that, api_lang, api_label)
+        },
+    )
+}
+fn wire_set_information__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    lang: impl Wire2Api<String> + UnwindSafe,
+    information: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_information__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_lang = lang.wire2api();
+            let api_information = information.wire2api();
+            move |task_callback| OcaAttr::set_information(&api_that, api_lang, api_information)
+        },
+    )
+}
+fn wire_set_entry_codes__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    entry_codes: impl Wire2Api<Vec<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_entry_codes__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_entry_codes = entry_codes.wire2api();
+            move |task_callback| OcaAttr::set_entry_codes(&api_that, api_entry_codes)
+        },
+    )
+}
+fn wire_set_entry__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    lang: impl Wire2Api<String> + UnwindSafe,
+    entries: impl Wire2Api<OcaMap> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_entry__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_lang = lang.wire2api();
+            let api_entries = entries.wire2api();
+            move |task_callback| OcaAttr::set_entry(&api_that, api_lang, api_entries)
+        },
+    )
+}
+fn wire_set_unit__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    unit: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_unit__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_unit = unit.wire2api();
+            move |task_callback| OcaAttr::set_unit(&api_that, api_unit)
+        },
+    )
+}
+fn wire_set_format__method__OcaAttr_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    format: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+        WrapInfo {
+            debug_name: "set_format__method__OcaAttr",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_format = format.wire2api();
+            move |task_callback| OcaAttr::set_format(&api_that, api_format)
+        },
+    )
+}
+fn wire_to_json__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "to_json__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaBundle::to_json(&api_that)
+        },
+    )
+}
+fn wire_said__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "said__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaBundle::said(&api_that)
+        },
+    )
+}
+fn wire_capture_base__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaCaptureBase, _>(
+        WrapInfo {
+            debug_name: "capture_base__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaBundle::capture_base(&api_that)
+        },
+    )
+}
+fn wire_overlays__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<OcaOverlay>, _>(
+        WrapInfo {
+            debug_name: "overlays__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaBundle::overlays(&api_that)
+        },
+    )
+}
+fn wire_languages__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
+        WrapInfo {
+            debug_name: "languages__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaBundle::languages(&api_that)
+        },
+    )
+}
+fn wire_keep_languages__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+    keep: impl Wire2Api<Vec<String>> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaBundle, _>(
+        WrapInfo {
+            debug_name: "keep_languages__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_keep = keep.wire2api();
+            move |task_callback| OcaBundle::keep_languages(&api_that, api_keep)
+        },
+    )
+}
+fn wire_extract_translation__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+    language: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "extract_translation__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_language = language.wire2api();
+            move |task_callback| OcaBundle::extract_translation(&api_that, api_language)
+        },
+    )
+}
+fn wire_merge_translation__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+    translation: impl Wire2Api<String> + UnwindSafe,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaBundle, _>(
         WrapInfo {
-            debug_name: "load_oca",
+            debug_name: "merge_translation__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_translation = translation.wire2api();
+            move |task_callback| OcaBundle::merge_translation(&api_that, api_translation)
+        },
+    )
+}
+fn wire_attributes__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<OcaAttributeDescriptor>, _>(
+        WrapInfo {
+            debug_name: "attributes__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaBundle::attributes(&api_that)
+        },
+    )
+}
+fn wire_upgrade_notes__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
+        WrapInfo {
+            debug_name: "upgrade_notes__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaBundle::upgrade_notes(&api_that)
+        },
+    )
+}
+fn wire_validate_semantics__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<OcaSemanticIssue>, _>(
+        WrapInfo {
+            debug_name: "validate_semantics__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaBundle::validate_semantics(&api_that)
+        },
+    )
+}
+fn wire_validate_data__method__OcaBundle_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+    record: impl Wire2Api<String> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<OcaDataError>, _>(
+        WrapInfo {
+            debug_name: "validate_data__method__OcaBundle",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            let api_record = record.wire2api();
+            move |task_callback| OcaBundle::validate_data(&api_that, api_record)
+        },
+    )
+}
+fn wire_attributes__method__OcaCaptureBase_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaCaptureBase> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaMap, _>(
+        WrapInfo {
+            debug_name: "attributes__method__OcaCaptureBase",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaCaptureBase::attributes(&api_that)
+        },
+    )
+}
+fn wire_flagged_attributes__method__OcaCaptureBase_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaCaptureBase> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
+        WrapInfo {
+            debug_name: "flagged_attributes__method__OcaCaptureBase",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaCaptureBase::flagged_attributes(&api_that)
+        },
+    )
+}
+fn wire_overlay_type__method__OcaOverlay_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+        WrapInfo {
+            debug_name: "overlay_type__method__OcaOverlay",
+            port: Some(port_),
+            mode: FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaOverlay::overlay_type(&api_that)
+        },
+    )
+}
+fn wire_name__method__OcaOverlay_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
+        WrapInfo {
+            debug_name: "name__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_json = json.wire2api();
-            move |task_callback| load_oca(api_json)
+            let api_that = that.wire2api();
+            move |task_callback| OcaOverlay::name(&api_that)
         },
     )
 }
-fn wire_new__static_method__OcaBox_impl(port_: MessagePort) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaBox, _>(
+fn wire_language__method__OcaOverlay_impl(
+    port_: MessagePort,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
         WrapInfo {
-            debug_name: "new__static_method__OcaBox",
+            debug_name: "language__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Result::<_, ()>::Ok(OcaBox::new()),
+        move || {
+            let api_that = that.wire2api();
+            move |task_callback| OcaOverlay::language(&api_that)
+        },
     )
 }
-fn wire_add_meta__method__OcaBox_impl(
+fn wire_said__method__OcaOverlay_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaBox> + UnwindSafe,
-    lang: impl Wire2Api<String> + UnwindSafe,
-    name: impl Wire2Api<String> + UnwindSafe,
-    value: impl Wire2Api<String> + UnwindSafe,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
         WrapInfo {
-            debug_name: "add_meta__method__OcaBox",
+            debug_name: "said__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_lang = lang.wire2api();
-            let api_name = name.wire2api();
-            let api_value = value.wire2api();
-            move |task_callback| OcaBox::add_meta(&api_that, api_lang, api_name, api_value)
+            move |task_callback| OcaOverlay::said(&api_that)
         },
     )
 }
-fn wire_add_attribute__method__OcaBox_impl(
+fn wire_capture_base__method__OcaOverlay_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaBox> + UnwindSafe,
-    attr: impl Wire2Api<OcaAttr> + UnwindSafe,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
         WrapInfo {
-            debug_name: "add_attribute__method__OcaBox",
+            debug_name: "capture_base__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_attr = attr.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaBox::add_attribute(&api_that, api_attr))
+            move |task_callback| OcaOverlay::capture_base(&api_that)
         },
     )
 }
-fn wire_generate_bundle__method__OcaBox_impl(
+fn wire_to_json__method__OcaOverlay_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaBox> + UnwindSafe,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaBundle, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
         WrapInfo {
-            debug_name: "generate_bundle__method__OcaBox",
+            debug_name: "to_json__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaBox::generate_bundle(&api_that))
+            move |task_callback| OcaOverlay::to_json(&api_that)
         },
     )
 }
-fn wire_add_form_layout__method__OcaBox_impl(
+fn wire_labels__method__OcaOverlay_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaBox> + UnwindSafe,
-    layout: impl Wire2Api<String> + UnwindSafe,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaMap, _>(
         WrapInfo {
-            debug_name: "add_form_layout__method__OcaBox",
+            debug_name: "labels__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_layout = layout.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaBox::add_form_layout(&api_that, api_layout))
+            move |task_callback| OcaOverlay::labels(&api_that)
         },
     )
 }
-fn wire_add_credential_layout__method__OcaBox_impl(
+fn wire_entries__method__OcaOverlay_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaBox> + UnwindSafe,
-    layout: impl Wire2Api<String> + UnwindSafe,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
+    attribute: impl Wire2Api<String> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<OcaMap>, _>(
         WrapInfo {
-            debug_name: "add_credential_layout__method__OcaBox",
+            debug_name: "entries__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_layout = layout.wire2api();
-            move |task_callback| {
-                Result::<_, ()>::Ok(OcaBox::add_credential_layout(&api_that, api_layout))
-            }
+            let api_attribute = attribute.wire2api();
+            move |task_callback| OcaOverlay::entries(&api_that, api_attribute)
         },
     )
 }
-fn wire_new__static_method__OcaAttr_impl(
+fn wire_entry_codes__method__OcaOverlay_impl(
     port_: MessagePort,
-    name: impl Wire2Api<String> + UnwindSafe,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
+    attribute: impl Wire2Api<String> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaAttr, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<Vec<String>>, _>(
         WrapInfo {
-            debug_name: "new__static_method__OcaAttr",
+            debug_name: "entry_codes__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
-            let api_name = name.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaAttr::new(api_name))
+            let api_that = that.wire2api();
+            let api_attribute = attribute.wire2api();
+            move |task_callback| OcaOverlay::entry_codes(&api_that, api_attribute)
         },
     )
 }
-fn wire_set_attribute_type__method__OcaAttr_impl(
+fn wire_formats__method__OcaOverlay_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    attr_type: impl Wire2Api<OcaAttrType> + UnwindSafe,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaMap, _>(
         WrapInfo {
-            debug_name: "set_attribute_type__method__OcaAttr",
+            debug_name: "formats__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_attr_type = attr_type.wire2api();
-            move |task_callback| {
-                Result::<_, ()>::Ok(OcaAttr::set_attribute_type(&api_that, api_attr_type))
-            }
+            move |task_callback| OcaOverlay::formats(&api_that)
         },
     )
 }
-fn wire_set_flagged__method__OcaAttr_impl(
+fn wire_units__method__OcaOverlay_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaMap, _>(
         WrapInfo {
-            debug_name: "set_flagged__method__OcaAttr",
+            debug_name: "units__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaAttr::set_flagged(&api_that))
+            move |task_callback| OcaOverlay::units(&api_that)
         },
     )
 }
-fn wire_set_encoding__method__OcaAttr_impl(
+fn wire_conformances__method__OcaOverlay_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    encoding: impl Wire2Api<OcaEncoding> + UnwindSafe,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaMap, _>(
         WrapInfo {
-            debug_name: "set_encoding__method__OcaAttr",
+            debug_name: "conformances__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_encoding = encoding.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaAttr::set_encoding(&api_that, api_encoding))
+            move |task_callback| OcaOverlay::conformances(&api_that)
         },
     )
 }
-fn wire_set_cardinality__method__OcaAttr_impl(
+fn wire_cardinalities__method__OcaOverlay_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    cardinality: impl Wire2Api<String> + UnwindSafe,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaMap, _>(
         WrapInfo {
-            debug_name: "set_cardinality__method__OcaAttr",
+            debug_name: "cardinalities__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_cardinality = cardinality.wire2api();
-            move |task_callback| {
-                Result::<_, ()>::Ok(OcaAttr::set_cardinality(&api_that, api_cardinality))
-            }
+            move |task_callback| OcaOverlay::cardinalities(&api_that)
         },
     )
 }
-fn wire_set_conformance__method__OcaAttr_impl(
+fn wire_meta__method__OcaOverlay_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    conformance: impl Wire2Api<String> + UnwindSafe,
+    that: impl Wire2Api<OcaOverlay> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaMap, _>(
         WrapInfo {
-            debug_name: "set_conformance__method__OcaAttr",
+            debug_name: "meta__method__OcaOverlay",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_conformance = conformance.wire2api();
-            move |task_callback| {
-                Result::<_, ()>::Ok(OcaAttr::set_conformance(&api_that, api_conformance))
-            }
+            move |task_callback| OcaOverlay::meta(&api_that)
         },
     )
 }
-fn wire_set_label__method__OcaAttr_impl(
+fn wire_name__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    lang: impl Wire2Api<String> + UnwindSafe,
-    label: impl Wire2Api<String> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
         WrapInfo {
-            debug_name: "set_label__method__OcaAttr",
+            debug_name: "name__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_lang = lang.wire2api();
-            let api_label = label.wire2api();
-            move |task_callback| OcaAttr::set_label(&api_that, api_lang, api_label)
+            move |task_callback| OcaAttributeDescriptor::name(&api_that)
         },
     )
 }
-fn wire_set_information__method__OcaAttr_impl(
+fn wire_attribute_type__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    lang: impl Wire2Api<String> + UnwindSafe,
-    information: impl Wire2Api<String> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
         WrapInfo {
-            debug_name: "set_information__method__OcaAttr",
+            debug_name: "attribute_type__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_lang = lang.wire2api();
-            let api_information = information.wire2api();
-            move |task_callback| OcaAttr::set_information(&api_that, api_lang, api_information)
+            move |task_callback| OcaAttributeDescriptor::attribute_type(&api_that)
         },
     )
 }
-fn wire_set_entry_codes__method__OcaAttr_impl(
+fn wire_labels__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    entry_codes: impl Wire2Api<Vec<String>> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaMap, _>(
         WrapInfo {
-            debug_name: "set_entry_codes__method__OcaAttr",
+            debug_name: "labels__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_entry_codes = entry_codes.wire2api();
-            move |task_callback| {
-                Result::<_, ()>::Ok(OcaAttr::set_entry_codes(&api_that, api_entry_codes))
-            }
+            move |task_callback| OcaAttributeDescriptor::labels(&api_that)
         },
     )
 }
-fn wire_set_entry_codes_sai__method__OcaAttr_impl(
+fn wire_information__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    sai: impl Wire2Api<String> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaMap, _>(
         WrapInfo {
-            debug_name: "set_entry_codes_sai__method__OcaAttr",
+            debug_name: "information__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_sai = sai.wire2api();
-            move |task_callback| {
-                Result::<_, ()>::Ok(OcaAttr::set_entry_codes_sai(&api_that, api_sai))
-            }
+            move |task_callback| OcaAttributeDescriptor::information(&api_that)
         },
     )
 }
-fn wire_set_entry__method__OcaAttr_impl(
+fn wire_conformance__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    lang: impl Wire2Api<String> + UnwindSafe,
-    entries: impl Wire2Api<OcaMap> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
         WrapInfo {
-            debug_name: "set_entry__method__OcaAttr",
+            debug_name: "conformance__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_lang = lang.wire2api();
-            let api_entries = entries.wire2api();
-            move |task_callback| OcaAttr::set_entry(&api_that, api_lang, api_entries)
+            move |task_callback| OcaAttributeDescriptor::conformance(&api_that)
         },
     )
 }
-fn wire_set_unit_metric__method__OcaAttr_impl(
+fn wire_cardinality__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    unit: impl Wire2Api<OcaMetricUnit> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
         WrapInfo {
-            debug_name: "set_unit_metric__method__OcaAttr",
+            debug_name: "cardinality__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_unit = unit.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaAttr::set_unit_metric(&api_that, api_unit))
+            move |task_callback| OcaAttributeDescriptor::cardinality(&api_that)
         },
     )
 }
-fn wire_set_unit_imperial__method__OcaAttr_impl(
+fn wire_format__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    unit: impl Wire2Api<OcaImperialUnit> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
         WrapInfo {
-            debug_name: "set_unit_imperial__method__OcaAttr",
+            debug_name: "format__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_unit = unit.wire2api();
-            move |task_callback| {
-                Result::<_, ()>::Ok(OcaAttr::set_unit_imperial(&api_that, api_unit))
-            }
+            move |task_callback| OcaAttributeDescriptor::format(&api_that)
         },
     )
 }
-fn wire_set_format__method__OcaAttr_impl(
+fn wire_unit__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaAttr> + UnwindSafe,
-    format: impl Wire2Api<String> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, (), _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
         WrapInfo {
-            debug_name: "set_format__method__OcaAttr",
+            debug_name: "unit__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            let api_format = format.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaAttr::set_format(&api_that, api_format))
+            move |task_callback| OcaAttributeDescriptor::unit(&api_that)
         },
     )
 }
-fn wire_to_json__method__OcaBundle_impl(
+fn wire_character_encoding__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
         WrapInfo {
-            debug_name: "to_json__method__OcaBundle",
+            debug_name: "character_encoding__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaBundle::to_json(&api_that))
+            move |task_callback| OcaAttributeDescriptor::character_encoding(&api_that)
         },
     )
 }
-fn wire_said__method__OcaBundle_impl(
+fn wire_standard__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<String>, _>(
         WrapInfo {
-            debug_name: "said__method__OcaBundle",
+            debug_name: "standard__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaBundle::said(&api_that))
+            move |task_callback| OcaAttributeDescriptor::standard(&api_that)
         },
     )
 }
-fn wire_capture_base__method__OcaBundle_impl(
+fn wire_entry_codes__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaCaptureBase, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<Vec<String>>, _>(
         WrapInfo {
-            debug_name: "capture_base__method__OcaBundle",
+            debug_name: "entry_codes__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaBundle::capture_base(&api_that))
+            move |task_callback| OcaAttributeDescriptor::entry_codes(&api_that)
         },
     )
 }
-fn wire_overlays__method__OcaBundle_impl(
+fn wire_entries__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaBundle> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
+    lang: impl Wire2Api<String> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<OcaOverlay>, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Option<OcaMap>, _>(
         WrapInfo {
-            debug_name: "overlays__method__OcaBundle",
+            debug_name: "entries__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaBundle::overlays(&api_that))
+            let api_lang = lang.wire2api();
+            move |task_callback| OcaAttributeDescriptor::entries(&api_that, api_lang)
         },
     )
 }
-fn wire_attributes__method__OcaCaptureBase_impl(
+fn wire_sensitive__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaCaptureBase> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, OcaMap, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, bool, _>(
         WrapInfo {
-            debug_name: "attributes__method__OcaCaptureBase",
+            debug_name: "sensitive__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaCaptureBase::attributes(&api_that))
+            move |task_callback| OcaAttributeDescriptor::sensitive(&api_that)
         },
     )
 }
-fn wire_flagged_attributes__method__OcaCaptureBase_impl(
+fn wire_to_json__method__OcaAttributeDescriptor_impl(
     port_: MessagePort,
-    that: impl Wire2Api<OcaCaptureBase> + UnwindSafe,
+    that: impl Wire2Api<OcaAttributeDescriptor> + UnwindSafe,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, Vec<String>, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap::<_, _, _, String, _>(
         WrapInfo {
-            debug_name: "flagged_attributes__method__OcaCaptureBase",
+            debug_name: "to_json__method__OcaAttributeDescriptor",
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
         move || {
             let api_that = that.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaCaptureBase::flagged_attributes(&api_that))
+            move |task_callback| OcaAttributeDescriptor::to_json(&api_that)
         },
     )
 }
@@ -508,7 +1140,7 @@ fn wire_new__static_method__OcaMap_impl(port_: MessagePort) {
             port: Some(port_),
             mode: FfiCallMode::Normal,
         },
-        move || move |task_callback| Result::<_, ()>::Ok(OcaMap::new()),
+        move || move |task_callback| OcaMap::new(),
     )
 }
 fn wire_insert__method__OcaMap_impl(
@@ -527,7 +1159,7 @@ fn wire_insert__method__OcaMap_impl(
             let api_that = that.wire2api();
             let api_key = key.wire2api();
             let api_value = value.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaMap::insert(&api_that, api_key, api_value))
+            move |task_callback| OcaMap::insert(&api_that, api_key, api_value)
         },
     )
 }
@@ -545,7 +1177,7 @@ fn wire_get__method__OcaMap_impl(
         move || {
             let api_that = that.wire2api();
             let api_key = key.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaMap::get(&api_that, api_key))
+            move |task_callback| OcaMap::get(&api_that, api_key)
         },
     )
 }
@@ -563,7 +1195,7 @@ fn wire_remove__method__OcaMap_impl(
         move || {
             let api_that = that.wire2api();
             let api_key = key.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaMap::remove(&api_that, api_key))
+            move |task_callback| OcaMap::remove(&api_that, api_key)
         },
     )
 }
@@ -579,7 +1211,7 @@ fn wire_get_keys__method__OcaMap_impl(
         },
         move || {
             let api_that = that.wire2api();
-            move |task_callback| Result::<_, ()>::Ok(OcaMap::get_keys(&api_that))
+            move |task_callback| OcaMap::get_keys(&api_that)
         },
     )
 }
@@ -625,8 +1257,6 @@ impl Wire2Api<OcaAttrType> for i32 {
             7 => OcaAttrType::ArrayNumeric,
             8 => OcaAttrType::DateTime,
             9 => OcaAttrType::ArrayDateTime,
-            10 => OcaAttrType::Reference,
-            11 => OcaAttrType::ArrayReference,
             _ => unreachable!("Invalid variant for OcaAttrType: {}", self),
         }
     }
@@ -642,54 +1272,7 @@ impl Wire2Api<OcaEncoding> for i32 {
         }
     }
 }
-impl Wire2Api<OcaImperialUnit> for i32 {
-    fn wire2api(self) -> OcaImperialUnit {
-        match self {
-            0 => OcaImperialUnit::Pound,
-            1 => OcaImperialUnit::Ounce,
-            2 => OcaImperialUnit::Gallon,
-            3 => OcaImperialUnit::Quart,
-            4 => OcaImperialUnit::Pint,
-            5 => OcaImperialUnit::FluidOunce,
-            6 => OcaImperialUnit::Inch,
-            7 => OcaImperialUnit::Foot,
-            8 => OcaImperialUnit::Yard,
-            9 => OcaImperialUnit::Mile,
-            10 => OcaImperialUnit::Celsius,
-            11 => OcaImperialUnit::Fahrenheit,
-            12 => OcaImperialUnit::Kelvin,
-            13 => OcaImperialUnit::Percent,
-            14 => OcaImperialUnit::Count,
-            15 => OcaImperialUnit::Other,
-            _ => unreachable!("Invalid variant for OcaImperialUnit: {}", self),
-        }
-    }
-}
 
-impl Wire2Api<OcaMetricUnit> for i32 {
-    fn wire2api(self) -> OcaMetricUnit {
-        match self {
-            0 => OcaMetricUnit::Kilogram,
-            1 => OcaMetricUnit::Gram,
-            2 => OcaMetricUnit::Milligram,
-            3 => OcaMetricUnit::Liter,
-            4 => OcaMetricUnit::Milliliter,
-            5 => OcaMetricUnit::Centimeter,
-            6 => OcaMetricUnit::Millimeter,
-            7 => OcaMetricUnit::Inch,
-            8 => OcaMetricUnit::Foot,
-            9 => OcaMetricUnit::Yard,
-            10 => OcaMetricUnit::Mile,
-            11 => OcaMetricUnit::Celsius,
-            12 => OcaMetricUnit::Fahrenheit,
-            13 => OcaMetricUnit::Kelvin,
-            14 => OcaMetricUnit::Percent,
-            15 => OcaMetricUnit::Count,
-            16 => OcaMetricUnit::Other,
-            _ => unreachable!("Invalid variant for OcaMetricUnit: {}", self),
-        }
-    }
-}
 impl Wire2Api<u8> for u8 {
     fn wire2api(self) -> u8 {
         self
@@ -710,6 +1293,18 @@ impl rust2dart::IntoIntoDart<OcaAttr> for OcaAttr {
     }
 }
 
+impl support::IntoDart for OcaAttributeDescriptor {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OcaAttributeDescriptor {}
+impl rust2dart::IntoIntoDart<OcaAttributeDescriptor> for OcaAttributeDescriptor {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for OcaBox {
     fn into_dart(self) -> support::DartAbi {
         vec![self.0.into_dart()].into_dart()
@@ -746,6 +1341,82 @@ impl rust2dart::IntoIntoDart<OcaCaptureBase> for OcaCaptureBase {
     }
 }
 
+impl support::IntoDart for OcaDataError {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.attribute.into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.rule.into_into_dart().into_dart(),
+            self.expected.into_dart(),
+            self.actual.into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OcaDataError {}
+impl rust2dart::IntoIntoDart<OcaDataError> for OcaDataError {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OcaDataRule {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Type => 0,
+            Self::Conformance => 1,
+            Self::EntryCode => 2,
+            Self::Format => 3,
+            Self::Cardinality => 4,
+            Self::Reference => 5,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OcaDataRule {}
+impl rust2dart::IntoIntoDart<OcaDataRule> for OcaDataRule {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OcaErrorKind {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Parse => 0,
+            Self::Invalid => 1,
+            Self::Bundle => 2,
+            Self::Missing => 3,
+            Self::Poisoned => 4,
+            Self::Panic => 5,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OcaErrorKind {}
+impl rust2dart::IntoIntoDart<OcaErrorKind> for OcaErrorKind {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OcaException {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.kind.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OcaException {}
+impl rust2dart::IntoIntoDart<OcaException> for OcaException {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 impl support::IntoDart for OcaMap {
     fn into_dart(self) -> support::DartAbi {
         vec![self.0.into_dart()].into_dart()
@@ -770,6 +1441,76 @@ impl rust2dart::IntoIntoDart<OcaOverlay> for OcaOverlay {
     }
 }
 
+impl support::IntoDart for OcaRegistry {
+    fn into_dart(self) -> support::DartAbi {
+        vec![self.0.into_dart()].into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OcaRegistry {}
+impl rust2dart::IntoIntoDart<OcaRegistry> for OcaRegistry {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OcaSaidVerification {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.path.into_into_dart().into_dart(),
+            self.object_type.into_dart(),
+            self.language.into_dart(),
+            self.embedded.into_dart(),
+            self.computed.into_dart(),
+            self.valid.into_into_dart().into_dart(),
+            self.message.into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OcaSaidVerification {}
+impl rust2dart::IntoIntoDart<OcaSaidVerification> for OcaSaidVerification {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OcaSemanticCategory {
+    fn into_dart(self) -> support::DartAbi {
+        match self {
+            Self::Format => 0,
+            Self::CaptureBaseMismatch => 1,
+            Self::UnknownAttribute => 2,
+            Self::EntryCodeMismatch => 3,
+            Self::Other => 4,
+        }
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OcaSemanticCategory {}
+impl rust2dart::IntoIntoDart<OcaSemanticCategory> for OcaSemanticCategory {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
+impl support::IntoDart for OcaSemanticIssue {
+    fn into_dart(self) -> support::DartAbi {
+        vec![
+            self.category.into_into_dart().into_dart(),
+            self.overlay.into_dart(),
+            self.attribute.into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl support::IntoDartExceptPrimitive for OcaSemanticIssue {}
+impl rust2dart::IntoIntoDart<OcaSemanticIssue> for OcaSemanticIssue {
+    fn into_into_dart(self) -> Self {
+        self
+    }
+}
+
 // Section: executor
 
 support::lazy_static! {
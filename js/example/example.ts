@@ -56,9 +56,9 @@ function example2_loadFromJSON() {
 
   const bundle = loadBundle(JSON.stringify(oca_bundle_json), overlay_file)
 
-  // Get bundle attributes (returns Map)
+  // Get bundle attributes with their type trees
   const attributes = getBundleAttributes(bundle)
-  const attributeNames = attributes instanceof Map ? Array.from(attributes.keys()) : Object.keys(attributes)
+  const attributeNames = Object.keys(attributes)
 
   console.log('Number of attributes:', attributeNames.length)
   console.log('First 5 attributes:', attributeNames.slice(0, 5))
@@ -0,0 +1,63 @@
+import { expect } from 'chai'
+import { buildFromOCAfile, getAttributeDescriptors } from 'oca.js'
+import fs from 'fs'
+
+const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
+
+describe('Attribute descriptors', () => {
+  const ocafile = `--name=person
+ADD Attribute name=Text sex=Text
+
+ADD OVERLAY LABEL
+  language="en"
+  attribute_labels
+    name="Name"
+    sex="Sex"
+
+ADD OVERLAY LABEL
+  language="pl"
+  attribute_labels
+    name="Imię"
+    sex="Płeć"
+
+ADD OVERLAY CONFORMANCE
+  attribute_conformances
+    name="M"
+
+ADD OVERLAY ENTRY_CODE
+  attribute_entry_codes
+    sex=["M", "F"]
+
+ADD OVERLAY ENTRY
+  language="en"
+  attribute_entries
+    sex
+      "M"="Male"
+      "F"="Female"
+
+ADD OVERLAY SENSITIVE
+  attributes=["name"]
+`
+
+  const descriptors = getAttributeDescriptors(buildFromOCAfile(ocafile, overlay_file))
+  const byName = (name: string) => descriptors.find((d: any) => d.name === name)
+
+  it('joins labels and constraints per attribute', () => {
+    expect(descriptors).to.have.length(2)
+    expect(byName('name')).to.deep.include({
+      type: 'Text',
+      labels: { en: 'Name', pl: 'Imię' },
+      conformance: 'M',
+      format: null,
+      sensitive: true,
+    })
+  })
+
+  it('includes entry codes and localized entries', () => {
+    expect(byName('sex')).to.deep.include({
+      entry_codes: ['M', 'F'],
+      entries: { en: { M: 'Male', F: 'Female' } },
+      sensitive: false,
+    })
+  })
+})
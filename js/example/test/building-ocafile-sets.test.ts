@@ -0,0 +1,30 @@
+import { expect } from 'chai'
+import { buildOCAfiles, getBundleAttributes } from 'oca.js'
+import fs from 'fs'
+
+const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
+
+describe('Building OCAfiles that reference each other', () => {
+  it('builds in dependency order and substitutes names', () => {
+    const built = buildOCAfiles({
+      person: 'ADD Attribute name=Text home=refn:address\n',
+      address: 'ADD Attribute street=Text\n',
+    }, overlay_file)
+
+    expect(built.order).to.deep.equal(['address', 'person'])
+    const person = JSON.parse(built.bundles.person)
+    expect(person.digest).to.equal(built.saids.person)
+    expect(getBundleAttributes(person).home).to.deep.equal({ kind: 'reference', said: built.saids.address })
+  })
+
+  it('reports cycles and missing names', () => {
+    const build = () => buildOCAfiles({
+      a: 'ADD Attribute x=refn:b\n',
+      b: 'ADD Attribute x=refn:a\n',
+      c: 'ADD Attribute x=refn:nowhere\n',
+    }, overlay_file)
+
+    expect(build).to.throw(/cycle: a -> b -> a/)
+    expect(build).to.throw(/unknown name 'nowhere'/)
+  })
+})
@@ -0,0 +1,64 @@
+import { expect } from 'chai'
+import { buildFromOCAfile, BundleBuilder, getBundleDigest, OverlayRegistry } from 'oca.js'
+import fs from 'fs'
+
+const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
+
+describe('Building a bundle in code', () => {
+  const registry = new OverlayRegistry(overlay_file)
+
+  it('builds the same bundle as the equivalent OCAfile', () => {
+    const bundle = new BundleBuilder()
+      .addAttribute('name', 'Text')
+      .addAttribute('sex', { kind: 'value', type: 'Text' })
+      .label('eng', 'name', 'Name')
+      .label('eng', 'sex', 'Sex')
+      .entryCodes('sex', ['M', 'F'])
+      .entries('eng', 'sex', { M: 'Male', F: 'Female' })
+      .conformance('name', 'M')
+      .build(registry)
+
+    const ocafile = buildFromOCAfile(`ADD Attribute name=Text sex=Text
+
+ADD OVERLAY Label
+  language="eng"
+  attribute_labels
+    name="Name"
+    sex="Sex"
+
+ADD OVERLAY Entry_Code
+  attribute_entry_codes
+    sex=["M", "F"]
+
+ADD OVERLAY Entry
+  language="eng"
+  attribute_entries
+    sex
+      "M"="Male"
+      "F"="Female"
+
+ADD OVERLAY Conformance
+  attribute_conformances
+    name="M"
+`, overlay_file)
+
+    expect(getBundleDigest(bundle)).to.equal(getBundleDigest(ocafile))
+  })
+
+  it('keeps quotes and line breaks in labels', () => {
+    const bundle = JSON.parse(new BundleBuilder()
+      .addAttribute('note', 'Text')
+      .label('eng', 'note', 'A "quoted"\nlabel')
+      .build(registry))
+    const label = bundle.overlays.find((o: any) => o.type.split('/')[1] === 'label')
+
+    expect(label.attribute_labels.note).to.equal('A "quoted"\nlabel')
+  })
+
+  it('refuses overlays for unknown attributes', () => {
+    const builder = new BundleBuilder().addAttribute('name', 'Text')
+
+    expect(() => builder.label('eng', 'missing', 'Missing')).to.throw('Unknown attribute')
+    expect(() => new BundleBuilder().build(registry)).to.throw('no attributes')
+  })
+})
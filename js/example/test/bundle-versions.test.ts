@@ -0,0 +1,34 @@
+import { expect } from 'chai'
+import { parseBundleVersion, checkBundleVersion, loadBundle } from 'oca.js'
+import fs from 'fs'
+
+const bundle_text = fs.readFileSync('./test/assets/oca_new.json', 'utf8')
+const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
+
+describe('Bundle version strings', () => {
+  it('are parsed into their parts', () => {
+    expect(parseBundleVersion('OCAS02JSON000106_')).to.deep.equal({
+      protocol: 'OCAS', major: 0, minor: 2, format: 'JSON', size: 0x106,
+    })
+    expect(() => parseBundleVersion('OCAS02JSON')).to.throw(/Invalid version string/)
+  })
+
+  it('declare the serialized size of the bundle', () => {
+    const check = checkBundleVersion(bundle_text)
+
+    expect(check.supported).to.be.true
+    expect(check.size_matches).to.be.true
+    expect(check.actual_size).to.equal(check.version.size)
+
+    const bundle = JSON.parse(bundle_text)
+    bundle.overlays.pop()
+    expect(checkBundleVersion(bundle).size_matches).to.be.false
+  })
+
+  it('of other protocol versions are refused on load', () => {
+    const bundle = JSON.parse(bundle_text)
+    bundle.v = 'OCAS10JSON0012ce_'
+
+    expect(() => loadBundle(JSON.stringify(bundle), overlay_file)).to.throw(/Unsupported bundle version OCAS10JSON0012ce_/)
+  })
+})
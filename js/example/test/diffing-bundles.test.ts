@@ -0,0 +1,53 @@
+import { expect } from 'chai'
+import { buildFromOCAfile, diffBundles, diffBundlesReport, OverlayRegistry } from 'oca.js'
+import fs from 'fs'
+
+const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
+
+describe('Comparing bundles', () => {
+  const old_bundle = buildFromOCAfile(`ADD Attribute name=Text age=Text fax=Text
+
+ADD OVERLAY Label
+  language="en"
+  attribute_labels
+    name="Name"
+    age="Age"
+`, overlay_file)
+  const new_bundle = buildFromOCAfile(`ADD Attribute name=Text age=Numeric email=Text
+
+ADD OVERLAY Label
+  language="en"
+  attribute_labels
+    name="Full name"
+    age="Age"
+
+ADD OVERLAY Conformance
+  attribute_conformances
+    name="M"
+`, overlay_file)
+
+  it('reports attribute, overlay and label changes', () => {
+    const diff = diffBundles(old_bundle, new_bundle, overlay_file)
+
+    expect(diff.added_attributes.map((a: any) => a.name)).to.deep.equal(['email'])
+    expect(diff.removed_attributes.map((a: any) => a.name)).to.deep.equal(['fax'])
+    expect(diff.retyped_attributes).to.deep.equal([{
+      name: 'age',
+      old_type: { kind: 'value', type: 'Text' },
+      new_type: { kind: 'value', type: 'Numeric' },
+    }])
+    expect(diff.added_overlays.map((o: any) => o.type.split('/')[1])).to.deep.equal(['conformance'])
+    expect(diff.label_changes).to.deep.equal([
+      { language: 'en', attribute: 'name', old: 'Name', new: 'Full name' },
+    ])
+  })
+
+  it('renders a text report', () => {
+    const registry = new OverlayRegistry(overlay_file)
+    const report = registry.diffBundlesReport(old_bundle, new_bundle)
+
+    expect(report).to.contain('  ~ age: Text -> Numeric')
+    expect(report).to.contain('  [en] name: "Name" -> "Full name"')
+    expect(registry.diffBundlesReport(old_bundle, old_bundle)).to.contain('No changes')
+  })
+})
@@ -0,0 +1,37 @@
+import { expect } from 'chai'
+import { addOverlay, buildFromOCAfile, getBundleDigest, OverlayRegistry, removeOverlays, verifyBundle } from 'oca.js'
+import fs from 'fs'
+
+const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
+
+describe('Editing the overlays of a bundle', () => {
+  const registry = new OverlayRegistry(overlay_file)
+  const bundle = buildFromOCAfile(`ADD Attribute name=Text
+
+ADD OVERLAY Label
+  language="eng"
+  attribute_labels
+    name="Name"
+`, overlay_file)
+  const french = { type: 'label', language: 'fra', attribute_labels: { name: 'Nom' } }
+
+  it('adds an overlay and recomputes the SAIDs', () => {
+    const translated = registry.addOverlay(bundle, french)
+
+    expect(getBundleDigest(translated)).to.not.equal(getBundleDigest(bundle))
+    expect(verifyBundle(translated).valid).to.equal(true)
+    expect(JSON.parse(translated).overlays.map((o: any) => o.language)).to.have.members(['eng', 'fra'])
+    expect(() => registry.addOverlay(translated, french)).to.throw('replace it instead')
+  })
+
+  it('replaces and removes overlays by type and language', () => {
+    const translated = addOverlay(bundle, french, overlay_file)
+    const relabelled = registry.replaceOverlay(translated, { ...french, attribute_labels: { name: 'Nom complet' } })
+    const labels = JSON.parse(relabelled).overlays.map((o: any) => o.attribute_labels.name)
+
+    expect(labels).to.have.members(['Name', 'Nom complet'])
+    expect(getBundleDigest(removeOverlays(relabelled, { type: 'label', language: 'fra' }, overlay_file)))
+      .to.equal(getBundleDigest(bundle))
+    expect(() => registry.removeOverlays(bundle, { language: 'deu' })).to.throw('No overlay matches')
+  })
+})
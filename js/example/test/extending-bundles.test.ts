@@ -0,0 +1,43 @@
+import { expect } from 'chai'
+import { buildFromOCAfile, getBundleAdditions, getBundleAttributes, OverlayRegistry } from 'oca.js'
+import fs from 'fs'
+
+const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
+
+describe('Building on top of a base bundle', () => {
+  const base = buildFromOCAfile(`ADD Attribute name=Text
+
+ADD OVERLAY Label
+  language="en"
+  attribute_labels
+    name="Name"
+`, overlay_file)
+
+  it('keeps the base attributes and reports the new ones', () => {
+    const extended = buildFromOCAfile(`ADD Attribute email=Text
+
+ADD OVERLAY Label
+  language="en"
+  attribute_labels
+    email="E-mail"
+
+ADD OVERLAY Conformance
+  attribute_conformances
+    email="M"
+`, overlay_file, base)
+
+    expect(Object.keys(getBundleAttributes(extended))).to.have.members(['name', 'email'])
+    const added = getBundleAdditions(base, extended)
+    expect(added.attributes).to.deep.equal(['email'])
+    expect(added.overlays.map((o: any) => o.type.split('/')[1])).to.deep.equal(['conformance'])
+    expect(added.changed_overlays.map((o: any) => o.language)).to.deep.equal(['eng'])
+  })
+
+  it('accepts a base through the registry', () => {
+    const registry = new OverlayRegistry(overlay_file)
+    const extended = registry.buildFromOCAfile('ADD Attribute age=Numeric\n', base)
+
+    expect(getBundleAdditions(base, extended).attributes).to.deep.equal(['age'])
+    expect(getBundleAdditions(base, base)).to.deep.equal({ attributes: [], overlays: [], changed_overlays: [] })
+  })
+})
@@ -0,0 +1,56 @@
+import { expect } from 'chai'
+import {
+  buildFromOCAfile, extractTranslation, getBundleDigest, getBundleLanguages, keepLanguages, OverlayRegistry, verifyBundle,
+} from 'oca.js'
+import fs from 'fs'
+
+const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
+
+describe('Languages and translations', () => {
+  const registry = new OverlayRegistry(overlay_file)
+  const bundle = buildFromOCAfile(`ADD Attribute name=Text
+
+ADD OVERLAY Label
+  language="eng"
+  attribute_labels
+    name="Name"
+
+ADD OVERLAY Label
+  language="pol"
+  attribute_labels
+    name="Imię"
+
+ADD OVERLAY Label
+  language="deu"
+  attribute_labels
+    name="Name"
+
+ADD OVERLAY Conformance
+  attribute_conformances
+    name="M"
+`, overlay_file)
+
+  it('keeps only the requested languages', () => {
+    const pruned = keepLanguages(bundle, ['eng', 'pol'], overlay_file)
+
+    expect(getBundleLanguages(pruned)).to.have.members(['eng', 'pol'])
+    expect(JSON.parse(pruned).overlays.some((o: any) => o.type.includes('conformance'))).to.equal(true)
+    expect(verifyBundle(pruned).valid).to.equal(true)
+  })
+
+  it('extracts a translation and merges it back', () => {
+    const pruned = registry.keepLanguages(bundle, ['eng', 'pol'])
+    const translation = extractTranslation(pruned, 'eng', overlay_file)
+
+    expect(translation.overlays).to.deep.equal([{ type: 'overlay/label/2.0.0', attribute_labels: { name: 'Name' } }])
+    expect(getBundleDigest(registry.mergeTranslation(pruned, translation))).to.equal(getBundleDigest(pruned))
+
+    translation.language = 'fra'
+    translation.overlays[0].attribute_labels.name = 'Nom'
+    const translated = registry.mergeTranslation(pruned, translation)
+
+    expect(getBundleLanguages(translated)).to.have.members(['eng', 'pol', 'fra'])
+    expect(getBundleDigest(registry.keepLanguages(translated, ['eng', 'pol']))).to.equal(getBundleDigest(pruned))
+    expect(() => registry.extractTranslation(pruned, 'deu')).to.throw('no overlays')
+  })
+})
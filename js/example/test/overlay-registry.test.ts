@@ -0,0 +1,34 @@
+import { expect } from 'chai'
+import { OverlayRegistry, buildFromOCAfile, getBundleDigest } from 'oca.js'
+import fs from 'fs'
+
+const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
+
+describe('Overlay registry handle', () => {
+  const ocafile = `--name=person
+ADD Attribute name=Text age=Numeric
+`
+
+  const registry = new OverlayRegistry(overlay_file)
+
+  it('builds the same bundle as the free function', () => {
+    const bundle = registry.buildFromOCAfile(ocafile)
+
+    expect(getBundleDigest(JSON.parse(bundle)))
+      .to.equal(getBundleDigest(JSON.parse(buildFromOCAfile(ocafile, overlay_file))))
+  })
+
+  it('is reused across calls', () => {
+    const bundle = registry.buildFromOCAfile(ocafile)
+
+    expect(registry.validateBundleSemantics(bundle).valid).to.be.true
+    expect(registry.validateData(bundle, { name: 'Alice', age: 42 }).valid).to.be.true
+    expect(registry.generateOCAfile(bundle)).to.contain('ADD ATTRIBUTE')
+  })
+
+  it('merges with another registry', () => {
+    const merged = new OverlayRegistry('').merge(registry)
+
+    expect(merged.buildFromOCAfile(ocafile)).to.equal(registry.buildFromOCAfile(ocafile))
+  })
+})
@@ -1,5 +1,5 @@
 import { expect } from 'chai'
-import { buildFromOCAfile, bundleToJSON } from 'oca.js'
+import { attributeTypeFromTree, buildFromOCAfile, bundleToJSON, getBundleAttributes } from 'oca.js'
 import fs from 'fs'
 
 const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
@@ -25,3 +25,29 @@ ADD Attribute attribute5=DateTime
     expect(json.capture_base.attributes).to.have.property('attribute5', 'DateTime')
   })
 })
+
+describe('Attribute type trees', () => {
+  const ocafile = `--name=test2
+ADD Attribute name=Text tags=Array[Text] friend=refs:EF5ERATRBBN_ewEo9buQbznirhBmvrSSC0O2GIR4Gbfs
+`
+
+  const attributes = getBundleAttributes(JSON.parse(buildFromOCAfile(ocafile, overlay_file)))
+
+  it('returns a tree per attribute', () => {
+    expect(attributes.name).to.deep.equal({ kind: 'value', type: 'Text' })
+    expect(attributes.tags).to.deep.equal({ kind: 'array', items: { kind: 'value', type: 'Text' } })
+    expect(attributes.friend).to.deep.equal({
+      kind: 'reference',
+      said: 'EF5ERATRBBN_ewEo9buQbznirhBmvrSSC0O2GIR4Gbfs',
+    })
+  })
+
+  it('turns a tree back into OCAfile syntax', () => {
+    expect(attributeTypeFromTree(attributes.tags)).to.equal('Array[Text]')
+    expect(attributeTypeFromTree({ kind: 'null' })).to.equal('Null')
+    expect(() => attributeTypeFromTree({ kind: 'reference' }))
+      .to.throw().with.property('name', 'InvalidAttributeTypeError')
+    expect(() => attributeTypeFromTree({ kind: 'reference', said: 'not-a-said' }))
+      .to.throw().with.property('name', 'InvalidAttributeTypeError')
+  })
+})
@@ -12,8 +12,7 @@ describe('OCA is loaded', () => {
     const attributes = getBundleAttributes(bundle)
 
     expect(attributes).to.exist
-    const attributeKeys = attributes instanceof Map ? Array.from(attributes.keys()) : Object.keys(attributes)
-    expect(attributeKeys).to.have.lengthOf(21)
+    expect(Object.keys(attributes)).to.have.lengthOf(21)
   })
 
   it('has meta', () => {
@@ -0,0 +1,45 @@
+import { expect } from 'chai'
+import { BundleResolver, OverlayRegistry, getBundleAttributes } from 'oca.js'
+import fs from 'fs'
+
+const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
+
+describe('Resolving references', () => {
+  const registry = new OverlayRegistry(overlay_file)
+  const address = JSON.parse(registry.buildFromOCAfile(`--name=address
+ADD Attribute street=Text number=Numeric
+`))
+
+  const seen: any[] = []
+  const resolver = new BundleResolver((reference: any) => {
+    seen.push(reference)
+    return reference.said === address.digest || reference.name === 'address' ? address : undefined
+  })
+
+  const person = JSON.parse(resolver.buildFromOCAfile(`--name=person
+ADD Attribute name=Text home=refn:address
+`, registry))
+
+  it('replaces names with SAIDs when building', () => {
+    expect(getBundleAttributes(person).home).to.deep.equal({ kind: 'reference', said: address.digest })
+    expect(seen).to.deep.include({ kind: 'reference', name: 'address' })
+  })
+
+  it('describes referenced attributes', () => {
+    const home = resolver.getAttributeDescriptors(person).find((d: any) => d.name === 'home')
+
+    expect(home.nested.map((d: any) => d.name)).to.have.members(['street', 'number'])
+  })
+
+  it('validates nested records', () => {
+    const result = resolver.validateData(person, { name: 'Alice', home: { street: 'Main', number: 'ten' } }, registry)
+
+    expect(result.valid).to.be.false
+    expect(result.errors[0]).to.include({ path: '/home/number', rule: 'type' })
+  })
+
+  it('reports names it cannot resolve', () => {
+    expect(() => resolver.buildFromOCAfile('ADD Attribute home=refn:nowhere\n', registry))
+      .to.throw(/refn:nowhere not found/)
+  })
+})
@@ -0,0 +1,58 @@
+import { expect } from 'chai'
+import { buildFromOCAfile, validateData } from 'oca.js'
+import fs from 'fs'
+
+const overlay_file = fs.readFileSync('./test/assets/semantic.overlayfile', 'utf8')
+
+describe('Data record validation', () => {
+  const ocafile = `--name=person
+ADD Attribute name=Text age=Numeric sex=Text
+
+ADD OVERLAY ENTRY_CODE
+  attribute_entry_codes
+    sex=["M", "F"]
+`
+
+  const bundle = buildFromOCAfile(ocafile, overlay_file)
+
+  it('accepts a matching record', () => {
+    const result = validateData(bundle, { name: 'Alice', age: 42, sex: 'F' }, overlay_file)
+
+    expect(result.valid).to.be.true
+    expect(result.errors).to.be.an('array').that.is.empty
+  })
+
+  it('reports the failing attribute and rule', () => {
+    const result = validateData(bundle, { name: 'Alice', age: 'old', sex: 'Q' }, overlay_file)
+
+    expect(result.valid).to.be.false
+    const age = result.errors.find((e: any) => e.attribute === 'age')
+    expect(age).to.include({ path: '/age', rule: 'type', expected: 'Numeric', actual: 'old' })
+    const sex = result.errors.find((e: any) => e.attribute === 'sex')
+    expect(sex).to.include({ path: '/sex', rule: 'entry_code', actual: 'Q' })
+  })
+})
+
+describe('Data validation through the overlay registry', () => {
+  const ocafile = `--name=person
+ADD Attribute name=Text age=Numeric
+`
+
+  const bundle = buildFromOCAfile(ocafile, overlay_file)
+
+  it('accepts a matching record', () => {
+    const result = validateData(bundle, { name: 'Alice', age: 42 }, overlay_file)
+
+    expect(result).to.haveOwnProperty('valid')
+    expect(result).to.haveOwnProperty('errors')
+    expect(result.valid).to.be.true
+    expect(result.errors).to.be.an('array').that.is.empty
+  })
+
+  it('rejects a mistyped record', () => {
+    const result = validateData(bundle, JSON.stringify({ name: 'Alice', age: 'old' }), overlay_file)
+
+    expect(result.valid).to.be.false
+    expect(result.errors.map((e: any) => e.attribute)).to.include('age')
+  })
+})
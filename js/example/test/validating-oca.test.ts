@@ -58,3 +58,37 @@ describe('Malformed OCA Bundle', () => {
     expect(validator).to.haveOwnProperty("valid")
   })
 })
+
+describe('OCA Bundle with several problems', () => {
+  const ocafile = `--name=several-problems
+ADD Attribute name=Text age=Numeric
+`
+
+  const bundleObj = JSON.parse(buildFromOCAfile(ocafile, overlay_file))
+  const capture_base = bundleObj.capture_base.digest
+  bundleObj.overlays.push(
+    {
+      capture_base,
+      type: 'overlay/label/2.0.0',
+      language: 'en',
+      attribute_labels: { name: 'Name', nickname: 'Nickname' }
+    },
+    {
+      capture_base,
+      type: 'overlay/format/2.0.0',
+      attribute_formats: { birthday: 'YYYY-MM-DD' }
+    }
+  )
+
+  const result = validateBundleSemantics(bundleObj, overlay_file)
+
+  it('reports all of them', () => {
+    expect(result.valid).to.be.false
+
+    const unknown = result.errors.filter((e: any) => e.category === 'unknown_attribute')
+    expect(unknown.map((e: any) => [e.overlay, e.attribute])).to.deep.include.members([
+      ['label', 'nickname'],
+      ['format', 'birthday']
+    ])
+  })
+})
@@ -0,0 +1,25 @@
+import { expect } from 'chai'
+import { verifyBundle, getBundleDigest } from 'oca.js'
+import fs from 'fs'
+
+const bundle_text = fs.readFileSync('./test/assets/oca_new.json', 'utf8')
+
+describe('Verifying bundle SAIDs', () => {
+  it('accepts an untouched bundle', () => {
+    const report = verifyBundle(bundle_text)
+
+    expect(report.valid).to.be.true
+    expect(report.objects[0].path).to.equal('capture_base')
+    expect(report.objects[report.objects.length - 1].computed).to.equal(getBundleDigest(JSON.parse(bundle_text)))
+  })
+
+  it('lists the objects that were edited', () => {
+    const bundle = JSON.parse(bundle_text)
+    bundle.overlays[1].name = 'Edited by hand'
+    const report = verifyBundle(JSON.stringify(bundle))
+
+    expect(report.valid).to.be.false
+    const invalid = report.objects.filter((o: any) => !o.valid).map((o: any) => o.path)
+    expect(invalid).to.deep.equal(['overlays[1]', 'bundle'])
+  })
+})
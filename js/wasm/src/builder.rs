@@ -0,0 +1,146 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use oca_bindings_common::builder;
+use oca_bindings_common::types::AttrType;
+use oca_sdk_rs::oca;
+use wasm_bindgen::prelude::*;
+
+use crate::{BundleResolver, OverlayRegistry};
+
+/// Builds a bundle step by step. Every method returns a builder sharing the
+/// same state, so calls chain; `build` produces the same bundle, with the
+/// same SAID, as the equivalent OCAfile.
+#[wasm_bindgen]
+pub struct BundleBuilder {
+    builder: Rc<RefCell<builder::BundleBuilder>>,
+}
+
+impl BundleBuilder {
+    fn with(&self, f: impl FnOnce(&mut builder::BundleBuilder) -> Result<(), String>) -> Result<BundleBuilder, JsValue> {
+        f(&mut self.builder.borrow_mut()).map_err(|e| JsValue::from_str(&e))?;
+        Ok(BundleBuilder {
+            builder: self.builder.clone(),
+        })
+    }
+
+    fn rendered(&self) -> Result<builder::Rendered, JsValue> {
+        self.builder.borrow().render().map_err(|e| JsValue::from_str(&e))
+    }
+}
+
+fn json_map(value: JsValue, what: &str) -> Result<serde_json::Map<String, serde_json::Value>, JsValue> {
+    serde_wasm_bindgen::from_value(value).map_err(|e| JsValue::from_str(&format!("Invalid {}: {}", what, e)))
+}
+
+#[wasm_bindgen]
+impl BundleBuilder {
+    #[wasm_bindgen(constructor)]
+    pub fn new(name: Option<String>) -> Result<BundleBuilder, JsValue> {
+        let mut builder = builder::BundleBuilder::new();
+        if let Some(name) = name {
+            builder.name(&name).map_err(|e| JsValue::from_str(&e))?;
+        }
+        Ok(BundleBuilder {
+            builder: Rc::new(RefCell::new(builder)),
+        })
+    }
+
+    /// `attribute_type` is the OCAfile spelling, such as `Array[Text]` or
+    /// `refn:address`, or a type tree as taken by `attributeTypeFromTree`.
+    #[wasm_bindgen(js_name = "addAttribute")]
+    pub fn add_attribute(&self, name: String, attribute_type: JsValue) -> Result<BundleBuilder, JsValue> {
+        let attr_type = match attribute_type.as_string() {
+            Some(spelling) => AttrType::parse(&spelling)
+                .ok_or_else(|| JsValue::from_str(&format!("Invalid attribute type '{}'", spelling)))?,
+            None => serde_wasm_bindgen::from_value(attribute_type)
+                .map_err(|e| JsValue::from_str(&format!("Invalid attribute type: {}", e)))?,
+        };
+        self.with(|builder| builder.add_attribute(&name, attr_type))
+    }
+
+    pub fn label(&self, language: String, attribute: String, label: String) -> Result<BundleBuilder, JsValue> {
+        self.with(|builder| builder.label(&language, &attribute, &label))
+    }
+
+    /// Needs a registry that defines the `information` overlay.
+    pub fn information(&self, language: String, attribute: String, information: String) -> Result<BundleBuilder, JsValue> {
+        self.with(|builder| builder.information(&language, &attribute, &information))
+    }
+
+    /// `entries` maps entry codes to their labels in `language`.
+    pub fn entries(&self, language: String, attribute: String, entries: JsValue) -> Result<BundleBuilder, JsValue> {
+        let entries = json_map(entries, "entries")?
+            .into_iter()
+            .map(|(code, label)| match label {
+                serde_json::Value::String(label) => Ok((code, label)),
+                other => Err(JsValue::from_str(&format!("Invalid label of entry '{}': {}", code, other))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        self.with(|builder| builder.entries(&language, &attribute, entries))
+    }
+
+    #[wasm_bindgen(js_name = "entryCodes")]
+    pub fn entry_codes(&self, attribute: String, codes: Vec<String>) -> Result<BundleBuilder, JsValue> {
+        self.with(|builder| builder.entry_codes(&attribute, codes))
+    }
+
+    pub fn conformance(&self, attribute: String, conformance: String) -> Result<BundleBuilder, JsValue> {
+        self.with(|builder| builder.conformance(&attribute, &conformance))
+    }
+
+    pub fn format(&self, attribute: String, format: String) -> Result<BundleBuilder, JsValue> {
+        self.with(|builder| builder.format(&attribute, &format))
+    }
+
+    pub fn cardinality(&self, attribute: String, cardinality: String) -> Result<BundleBuilder, JsValue> {
+        self.with(|builder| builder.cardinality(&attribute, &cardinality))
+    }
+
+    pub fn unit(&self, attribute: String, unit: String) -> Result<BundleBuilder, JsValue> {
+        self.with(|builder| builder.unit(&attribute, &unit))
+    }
+
+    #[wasm_bindgen(js_name = "characterEncoding")]
+    pub fn character_encoding(&self, attribute: String, encoding: String) -> Result<BundleBuilder, JsValue> {
+        self.with(|builder| builder.character_encoding(&attribute, &encoding))
+    }
+
+    pub fn standard(&self, attribute: String, standard: String) -> Result<BundleBuilder, JsValue> {
+        self.with(|builder| builder.standard(&attribute, &standard))
+    }
+
+    pub fn sensitive(&self, attribute: String) -> Result<BundleBuilder, JsValue> {
+        self.with(|builder| builder.sensitive(&attribute))
+    }
+
+    pub fn meta(&self, language: String, meta: JsValue) -> Result<BundleBuilder, JsValue> {
+        let meta = json_map(meta, "meta")?;
+        self.with(|builder| builder.meta(&language, meta))
+    }
+
+    /// Adds fields to any overlay the registry defines, such as
+    /// `overlay("unit", { metric_system: "SI" })`. Objects merge into what
+    /// earlier calls added to the same overlay and language.
+    pub fn overlay(&self, name: String, fields: JsValue, language: Option<String>) -> Result<BundleBuilder, JsValue> {
+        let fields = json_map(fields, "overlay fields")?;
+        self.with(|builder| builder.overlay(&name, language.as_deref(), fields))
+    }
+
+    /// Returns the bundle JSON.
+    pub fn build(&self, registry: &OverlayRegistry) -> Result<String, JsValue> {
+        registry.build_ast(&registry.parse_rendered(&self.rendered()?, None)?)
+    }
+
+    /// Like `build`, replacing `refn:` types with the SAIDs of the bundles
+    /// their names resolve to.
+    #[wasm_bindgen(js_name = "buildWith")]
+    pub fn build_with(&self, registry: &OverlayRegistry, resolver: &BundleResolver) -> Result<String, JsValue> {
+        registry.build_ast(&registry.parse_rendered(&self.rendered()?, Some(resolver))?)
+    }
+
+    #[wasm_bindgen(js_name = "toOCAfile")]
+    pub fn to_ocafile(&self, registry: &OverlayRegistry) -> Result<String, JsValue> {
+        Ok(oca::file::generate_from_ast(&registry.parse_rendered(&self.rendered()?, None)?))
+    }
+}
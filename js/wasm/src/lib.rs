@@ -1,12 +1,54 @@
+mod builder;
+mod registry;
+mod resolver;
+mod types;
+
+use std::collections::BTreeMap;
+
+use oca_bindings_common::semantics::{self, Category, SemanticIssue};
+use oca_bindings_common::types::AttrType;
+use oca_bindings_common::{additions, attributes, bundle, languages, resolve, said, version};
 use oca_sdk_rs::oca;
-use oca_sdk_rs::ToJSON;
-use serde_json::json;
+use oca_sdk_rs::oca::bundle::SemanticValidationStatus;
+use serde::Serialize;
 use wasm_bindgen::prelude::*;
 
+pub use builder::BundleBuilder;
+pub use registry::OverlayRegistry;
+pub use resolver::BundleResolver;
+
 #[derive(serde::Serialize)]
-struct ValidationResult {
+struct ValidationResult<E> {
     valid: bool,
-    errors: Vec<String>,
+    errors: Vec<E>,
+}
+
+impl<E> From<Vec<E>> for ValidationResult<E> {
+    fn from(errors: Vec<E>) -> Self {
+        ValidationResult {
+            valid: errors.is_empty(),
+            errors,
+        }
+    }
+}
+
+/// Accepts either a JS object or its JSON text, as returned by
+/// `buildFromOCAfile`.
+fn json_value(value: JsValue, what: &str) -> Result<serde_json::Value, JsValue> {
+    let value: serde_json::Value = serde_wasm_bindgen::from_value(value)
+        .map_err(|e| JsValue::from_str(&format!("Invalid {}: {}", what, e)))?;
+    match value {
+        serde_json::Value::String(text) => serde_json::from_str(&text)
+            .map_err(|e| JsValue::from_str(&format!("Invalid {}: {}", what, e))),
+        value => Ok(value),
+    }
+}
+
+/// Serializes maps as plain objects rather than `Map`s.
+fn to_js_object<T: Serialize>(value: &T) -> Result<JsValue, JsValue> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsValue::from_str(&format!("Failed to serialize result: {}", e)))
 }
 
 #[wasm_bindgen(start)]
@@ -31,91 +73,266 @@ interface OCABundle {
     };
   };
 }
+
+interface SemanticValidationError {
+  category:
+    | "format"
+    | "capture_base_mismatch"
+    | "unknown_attribute"
+    | "entry_code_mismatch"
+    | "other";
+  overlay: string | null;
+  attribute: string | null;
+  message: string;
+}
+
+interface SemanticValidationResult {
+  valid: boolean;
+  errors: SemanticValidationError[];
+}
+
+interface DataValidationError {
+  attribute: string | null;
+  path: string;
+  rule: "type" | "conformance" | "entry_code" | "format" | "cardinality" | "reference";
+  expected: any;
+  actual: any;
+  message: string;
+}
+
+type AttributeType =
+  | { kind: "value"; type: "Text" | "Numeric" | "Boolean" | "DateTime" | "Binary" }
+  | { kind: "reference"; said: string }
+  | { kind: "reference"; name: string }
+  | { kind: "array"; items: AttributeType }
+  | { kind: "null" };
+
+interface AttributeDescriptor {
+  name: string;
+  type: AttributeType | null;
+  labels: { [language: string]: string };
+  information: { [language: string]: string };
+  conformance: string | null;
+  cardinality: string | null;
+  format: string | null;
+  unit: string | null;
+  character_encoding: string | null;
+  entry_codes: string[] | string | null;
+  entries: { [language: string]: { [code: string]: string } };
+  sensitive: boolean;
+  standard: string | null;
+  nested: AttributeDescriptor[] | null;
+}
+
+interface OverlayKey {
+  type: string;
+  language: string | null;
+}
+
+interface BundleAdditions {
+  attributes: string[];
+  overlays: OverlayKey[];
+  changed_overlays: OverlayKey[];
+}
+
+interface ObjectVerification {
+  path: string;
+  type: string | null;
+  language: string | null;
+  embedded: string | null;
+  computed: string | null;
+  valid: boolean;
+  message: string | null;
+}
+
+interface BundleVerification {
+  valid: boolean;
+  objects: ObjectVerification[];
+}
+
+interface BundleVersion {
+  protocol: string;
+  major: number;
+  minor: number;
+  format: string;
+  size: number;
+}
+
+interface BundleVersionCheck {
+  version: BundleVersion;
+  actual_size: number;
+  size_matches: boolean;
+  supported: boolean;
+}
+
+interface BundleDiff {
+  old_digest: string | null;
+  new_digest: string | null;
+  added_attributes: { name: string; type: AttributeType | null }[];
+  removed_attributes: { name: string; type: AttributeType | null }[];
+  retyped_attributes: { name: string; old_type: AttributeType | null; new_type: AttributeType | null }[];
+  added_overlays: OverlayKey[];
+  removed_overlays: OverlayKey[];
+  changed_overlays: OverlayKey[];
+  label_changes: { language: string; attribute: string; old: string | null; new: string | null }[];
+  entry_changes: { language: string; attribute: string; code: string; old: string | null; new: string | null }[];
+}
+
+interface OverlayFilter {
+  type?: string;
+  language?: string;
+}
+
+interface Translation {
+  language: string;
+  capture_base: string;
+  overlays: { type: string; [field: string]: unknown }[];
+}
+
+interface OCAfileSetBuild {
+  saids: { [name: string]: string };
+  bundles: { [name: string]: string };
+  order: string[];
+}
+
+interface DataValidationResult {
+  valid: boolean;
+  errors: DataValidationError[];
+}
 "#;
 
 #[wasm_bindgen(js_name = "parseOCAfile")]
 pub fn parse_ocafile(ocafile_str: String, overlay_file: String) -> Result<JsValue, JsValue> {
-    let registry = oca::overlay_file::OverlayLocalRegistry::from_string(overlay_file)
-            .map_err(|e| JsValue::from_str(&format!("Failed to load overlay registry: {}", e)))?;
+    OverlayRegistry::new(overlay_file)?.parse_ocafile(ocafile_str)
+}
 
-    let oca_ast = oca::file::parse_from_string(ocafile_str, &registry)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse OCAfile: {}", e)))?;
+/// Builds a bundle, on top of the `base` bundle (JSON text) when given.
+#[wasm_bindgen(js_name = "buildFromOCAfile")]
+pub fn build_from_ocafile(ocafile_str: String, overlay_file: String, base: Option<String>) -> Result<JsValue, JsValue> {
+    OverlayRegistry::new(overlay_file)?.build_from_ocafile(ocafile_str, base)
+}
 
-    let ast_json = json!({
-        "meta": oca_ast.meta,
-        "commands": oca_ast.commands
-    });
+/// Builds OCAfiles that reference each other by `refn:` name, given as an
+/// object of name to OCAfile text. See `OverlayRegistry.buildOCAfiles`.
+#[wasm_bindgen(js_name = "buildOCAfiles")]
+pub fn build_ocafiles(ocafiles: JsValue, overlay_file: String) -> Result<JsValue, JsValue> {
+    OverlayRegistry::new(overlay_file)?.build_ocafiles(ocafiles)
+}
 
-    Ok(serde_wasm_bindgen::to_value(&ast_json).unwrap())
+#[wasm_bindgen(js_name = "loadBundle")]
+pub fn load_bundle(json_str: String, overlay_file: String) -> Result<JsValue, JsValue> {
+    OverlayRegistry::new(overlay_file)?.load_bundle(json_str)
 }
 
-#[wasm_bindgen(js_name = "buildFromOCAfile")]
-pub fn build_from_ocafile(ocafile_str: String, overlay_file: String) -> Result<JsValue, JsValue> {
-    let registry = oca::overlay_file::OverlayLocalRegistry::from_string(overlay_file)
-            .map_err(|e| JsValue::from_str(&format!("Failed to load overlay registry: {}", e)))?;
+/// Compares two versions of a bundle, given as objects or JSON text:
+/// attributes, overlays, and labels and entries per language.
+#[wasm_bindgen(js_name = "diffBundles")]
+pub fn diff_bundles(old: JsValue, new: JsValue, overlay_file: String) -> Result<JsValue, JsValue> {
+    OverlayRegistry::new(overlay_file)?.diff_bundles(old, new)
+}
 
-    let oca_ast = oca::file::parse_from_string(ocafile_str, &registry)
-        .map_err(|e| JsValue::from_str(&format!("Failed to parse OCAfile: {}", e)))?;
+#[wasm_bindgen(js_name = "diffBundlesReport")]
+pub fn diff_bundles_report(old: JsValue, new: JsValue, overlay_file: String) -> Result<String, JsValue> {
+    OverlayRegistry::new(overlay_file)?.diff_bundles_report(old, new)
+}
 
-    let build = oca::bundle::from_ast(None, &oca_ast)
-        .map_err(|e| JsValue::from_str(&format!("Failed to build bundle from OCAfile: {:?}", e)))?;
+/// Adds an overlay to the bundle and recomputes its SAIDs. Returns the new
+/// bundle JSON.
+#[wasm_bindgen(js_name = "addOverlay")]
+pub fn add_overlay(oca_bundle: JsValue, overlay: JsValue, overlay_file: String) -> Result<String, JsValue> {
+    OverlayRegistry::new(overlay_file)?.add_overlay(oca_bundle, overlay)
+}
 
-    let bundle_json = build.oca_bundle.get_json_bundle();
+#[wasm_bindgen(js_name = "replaceOverlay")]
+pub fn replace_overlay(oca_bundle: JsValue, overlay: JsValue, overlay_file: String) -> Result<String, JsValue> {
+    OverlayRegistry::new(overlay_file)?.replace_overlay(oca_bundle, overlay)
+}
 
-    Ok(serde_wasm_bindgen::to_value(&bundle_json).unwrap())
+#[wasm_bindgen(js_name = "removeOverlays")]
+pub fn remove_overlays(oca_bundle: JsValue, filter: JsValue, overlay_file: String) -> Result<String, JsValue> {
+    OverlayRegistry::new(overlay_file)?.remove_overlays(oca_bundle, filter)
 }
 
-#[wasm_bindgen(js_name = "loadBundle")]
-pub fn load_bundle(json_str: String, overlay_file: String) -> Result<JsValue, JsValue> {
-    let registry = oca::overlay_file::OverlayLocalRegistry::from_string(overlay_file)
-            .map_err(|e| JsValue::from_str(&format!("Failed to load overlay registry: {}", e)))?;
+/// Keeps only the overlays in the given languages, and those without a
+/// language. Returns the new bundle JSON.
+#[wasm_bindgen(js_name = "keepLanguages")]
+pub fn keep_languages(oca_bundle: JsValue, keep: Vec<String>, overlay_file: String) -> Result<String, JsValue> {
+    OverlayRegistry::new(overlay_file)?.keep_languages(oca_bundle, keep)
+}
 
-    let mut bytes = json_str.as_bytes();
-    let oca_bundle_model = oca::bundle::load(&mut bytes, &registry)
-        .map_err(|e| JsValue::from_str(&format!("Failed to load bundle: {}", e)))?;
+#[wasm_bindgen(js_name = "extractTranslation")]
+pub fn extract_translation(oca_bundle: JsValue, language: String, overlay_file: String) -> Result<JsValue, JsValue> {
+    OverlayRegistry::new(overlay_file)?.extract_translation(oca_bundle, language)
+}
 
-    Ok(serde_wasm_bindgen::to_value(&oca_bundle_model).unwrap())
+#[wasm_bindgen(js_name = "mergeTranslation")]
+pub fn merge_translation(oca_bundle: JsValue, translation: JsValue, overlay_file: String) -> Result<String, JsValue> {
+    OverlayRegistry::new(overlay_file)?.merge_translation(oca_bundle, translation)
 }
 
+/// Reports every semantic problem of the bundle, each with a category and
+/// the overlay and attribute it concerns. The bundle is loaded through the
+/// overlay registry when one is given, and read as it is otherwise.
 #[wasm_bindgen(js_name = "validateBundleSemantics")]
-pub fn validate_bundle_semantics(bundle: JsValue) -> Result<JsValue, JsValue> {
-    let result = match serde_wasm_bindgen::from_value::<serde_json::Value>(bundle) {
-        Ok(bundle) => {
-            let oca_bundle_model: oca::bundle::OCABundleModel = serde_json::from_value(bundle)
-                .map_err(|e| JsValue::from_str(&format!("Failed to parse bundle: {}", e)))?;
-
-            match oca::bundle::validate_semantics(&oca_bundle_model) {
-                Ok(_) => ValidationResult {
-                    valid: true,
-                    errors: vec![],
-                },
-                Err(e) => ValidationResult {
-                    valid: false,
-                    errors: vec![e.to_string()],
-                },
-            }
+pub fn validate_bundle_semantics(bundle: JsValue, overlay_file: Option<String>) -> Result<JsValue, JsValue> {
+    let registry = overlay_file.map(OverlayRegistry::new).transpose()?;
+    let issues = match json_value(bundle, "bundle format") {
+        Ok(oca_bundle) => semantic_issues(&oca_bundle, registry.as_ref()),
+        Err(e) => vec![SemanticIssue::new(
+            Category::Format,
+            e.as_string().unwrap_or_default(),
+        )],
+    };
+
+    to_js_object(&ValidationResult::from(issues))
+}
+
+fn semantic_issues(oca_bundle: &serde_json::Value, registry: Option<&OverlayRegistry>) -> Vec<SemanticIssue> {
+    let mut issues = semantics::check_bundle(oca_bundle);
+
+    let loaded = match registry {
+        Some(registry) => registry.load(oca_bundle),
+        // Without a registry the overlays cannot be checked against their
+        // definitions, only read.
+        None => serde_json::from_value::<oca::bundle::OCABundleModel>(oca_bundle.clone())
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse bundle: {}", e))),
+    };
+    let oca_bundle_model = match loaded {
+        Ok(model) => model,
+        Err(e) => {
+            issues.push(SemanticIssue::new(
+                Category::Format,
+                e.as_string().unwrap_or_default(),
+            ));
+            return issues;
+        }
+    };
+
+    let sdk_errors = match oca::bundle::validate_semantics(&oca_bundle_model) {
+        Ok(SemanticValidationStatus::Valid) => vec![],
+        Ok(SemanticValidationStatus::Invalid(errors)) => {
+            errors.into_iter().map(|e| e.to_string()).collect()
         }
-        Err(e) => ValidationResult {
-            valid: false,
-            errors: vec![format!("Invalid bundle format: {}", e)],
-        },
+        Err(e) => vec![e.to_string()],
     };
 
-    Ok(serde_wasm_bindgen::to_value(&result).unwrap())
+    semantics::with_sdk_errors(oca_bundle, issues, sdk_errors)
+}
+
+/// Validates a data record with the structured checks of the bundle's
+/// attributes and constraint overlays: conformance, types, formats, entry
+/// codes and cardinality. Each error names the attribute, JSON pointer and
+/// rule it concerns. The SDK validator, with the bundle loaded through the
+/// given overlay registry, only adds type errors for scalar values the
+/// structured checks accept.
+#[wasm_bindgen(js_name = "validateData")]
+pub fn validate_data(oca_bundle: JsValue, record: JsValue, overlay_file: String) -> Result<JsValue, JsValue> {
+    OverlayRegistry::new(overlay_file)?.validate_data(oca_bundle, record)
 }
 
 #[wasm_bindgen(js_name = "generateOCAfile")]
 pub fn generate_ocafile(bundle: String, overlay_file: String) -> Result<String, JsValue> {
-    let registry = oca::overlay_file::OverlayLocalRegistry::from_string(overlay_file)
-            .map_err(|e| JsValue::from_str(&format!("Failed to load overlay registry: {}", e)))?;
-
-    let mut bytes = bundle.as_bytes();
-    let oca_bundle_model = oca::bundle::load(&mut bytes, &registry)
-        .map_err(|e| JsValue::from_str(&format!("Failed to load bundle: {}", e)))?;
-    let oca_ast = oca_bundle_model.to_ast();
-    let ocafile = oca::file::generate_from_ast(&oca_ast);
-
-    Ok(ocafile)
+    OverlayRegistry::new(overlay_file)?.generate_ocafile(bundle)
 }
 
 #[wasm_bindgen(js_name = "bundleToJSON")]
@@ -148,11 +365,17 @@ pub fn get_bundle_digest(oca_bundle: JsValue) -> Result<String, JsValue> {
         }
     };
 
-    Ok(oca_bundle
-        .get("d")
-        .and_then(|v| v.as_str())
-        .unwrap_or_default()
-        .to_string())
+    Ok(bundle::digest(&oca_bundle).unwrap_or_default().to_string())
+}
+
+/// Recomputes the SAIDs of the capture base, each overlay and the bundle,
+/// and compares them with the embedded ones. Pass the JSON text as received:
+/// a parsed object loses the field order of keys that look like numbers.
+#[wasm_bindgen(js_name = "verifyBundle")]
+pub fn verify_bundle(oca_bundle: JsValue) -> Result<JsValue, JsValue> {
+    let oca_bundle = json_value(oca_bundle, "bundle format")?;
+
+    to_js_object(&said::verify(&oca_bundle))
 }
 
 #[wasm_bindgen(js_name = "getBundleVersion")]
@@ -174,6 +397,25 @@ pub fn get_bundle_version(oca_bundle: JsValue) -> Result<String, JsValue> {
         .to_string())
 }
 
+/// Splits a version string such as `OCAS02JSON000106_` into protocol,
+/// major and minor version, serialization format and declared size.
+#[wasm_bindgen(js_name = "parseBundleVersion")]
+pub fn parse_bundle_version(version_str: String) -> Result<JsValue, JsValue> {
+    let version: version::Version = version_str.parse().map_err(|e: String| JsValue::from_str(&e))?;
+
+    to_js_object(&version)
+}
+
+/// Parses the bundle's version string and checks the declared size against
+/// the serialized length and the protocol against the supported versions.
+#[wasm_bindgen(js_name = "checkBundleVersion")]
+pub fn check_bundle_version(oca_bundle: JsValue) -> Result<JsValue, JsValue> {
+    let oca_bundle = json_value(oca_bundle, "bundle format")?;
+    let check = version::check(&oca_bundle).map_err(|e| JsValue::from_str(&e))?;
+
+    to_js_object(&check)
+}
+
 #[wasm_bindgen(js_name = "getBundleType")]
 pub fn get_bundle_type(oca_bundle: JsValue) -> Result<String, JsValue> {
     let oca_bundle: serde_json::Value = match serde_wasm_bindgen::from_value(oca_bundle) {
@@ -194,6 +436,9 @@ pub fn get_bundle_type(oca_bundle: JsValue) -> Result<String, JsValue> {
         .to_string())
 }
 
+/// Maps each attribute name to its type tree, e.g.
+/// `{ kind: "array", items: { kind: "value", type: "Text" } }`. Types that
+/// cannot be read are `null`.
 #[wasm_bindgen(js_name = "getBundleAttributes")]
 pub fn get_bundle_attributes(oca_bundle: JsValue) -> Result<JsValue, JsValue> {
     let oca_bundle: serde_json::Value = match serde_wasm_bindgen::from_value(oca_bundle) {
@@ -206,13 +451,63 @@ pub fn get_bundle_attributes(oca_bundle: JsValue) -> Result<JsValue, JsValue> {
         }
     };
 
-    Ok(serde_wasm_bindgen::to_value(
-        &oca_bundle
-            .get("capture_base")
-            .and_then(|v| v.get("attributes"))
-            .unwrap_or(&json!({})),
-    )
-    .unwrap())
+    let attributes: BTreeMap<&str, Option<AttrType>> = bundle::attributes(&oca_bundle)
+        .into_iter()
+        .map(|(name, attr_type)| (name, AttrType::from_json(attr_type)))
+        .collect();
+
+    to_js_object(&attributes)
+}
+
+/// Checks a type tree and returns its OCAfile spelling, e.g.
+/// `Array[refs:E...]`, for use in generated OCAfiles. Trees the SDK has no
+/// attribute type for throw an `InvalidAttributeTypeError`.
+#[wasm_bindgen(js_name = "attributeTypeFromTree")]
+pub fn attribute_type_from_tree(tree: JsValue) -> Result<String, JsValue> {
+    let attr_type: AttrType = serde_wasm_bindgen::from_value(tree)
+        .map_err(|e| types::invalid_attribute_type(&e.to_string()))?;
+    let nested = types::nested_attr_type_from_tree(&attr_type)
+        .map_err(|e| types::invalid_attribute_type(&e))?;
+
+    Ok(types::nested_attr_type_to_tree(&nested).to_string())
+}
+
+/// Describes each attribute of the bundle together with what its overlays
+/// say about it: labels, information, conformance, entry codes and so on.
+#[wasm_bindgen(js_name = "getAttributeDescriptors")]
+pub fn get_attribute_descriptors(oca_bundle: JsValue) -> Result<JsValue, JsValue> {
+    let oca_bundle = json_value(oca_bundle, "bundle format")?;
+
+    to_js_object(&attribute_descriptors(&oca_bundle, None)?)
+}
+
+/// Describes the attributes the SDK fills in on the bundle's model, in model
+/// order, with the rest read from the bundle's overlays.
+fn attribute_descriptors(
+    oca_bundle: &serde_json::Value,
+    resolver: Option<&dyn resolve::BundleResolver>,
+) -> Result<Vec<attributes::AttributeDescriptor>, JsValue> {
+    let mut oca_bundle_model = serde_json::from_value::<oca::bundle::OCABundleModel>(oca_bundle.clone())
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse bundle: {}", e)))?;
+    oca_bundle_model.fill_attributes();
+
+    let filled = oca_bundle_model
+        .attributes
+        .iter()
+        .flat_map(|attr_map| attr_map.values())
+        .map(|attr| (attr.name.as_str(), attr.attribute_type.as_ref().map(types::nested_attr_type_to_tree)));
+    Ok(attributes::describe_filled(oca_bundle, filled, resolver))
+}
+
+/// Reports what a bundle built with `base` added to it: new attributes,
+/// overlays of a new type or language, and overlays with new content.
+/// SAIDs are not compared.
+#[wasm_bindgen(js_name = "getBundleAdditions")]
+pub fn get_bundle_additions(base: JsValue, oca_bundle: JsValue) -> Result<JsValue, JsValue> {
+    let base = json_value(base, "base bundle")?;
+    let oca_bundle = json_value(oca_bundle, "bundle format")?;
+
+    to_js_object(&additions::additions(&base, &oca_bundle))
 }
 
 #[wasm_bindgen(js_name = "getOverlayCount")]
@@ -234,6 +529,14 @@ pub fn get_overlay_count(oca_bundle: JsValue) -> Result<u32, JsValue> {
         .unwrap_or(0))
 }
 
+/// The languages of the bundle's overlays, in order of appearance.
+#[wasm_bindgen(js_name = "getBundleLanguages")]
+pub fn get_bundle_languages(oca_bundle: JsValue) -> Result<Vec<String>, JsValue> {
+    let oca_bundle = json_value(oca_bundle, "bundle format")?;
+
+    Ok(languages::languages(&oca_bundle))
+}
+
 #[wasm_bindgen(js_name = "getOverlayNames")]
 pub fn get_overlay_names(oca_bundle: JsValue) -> Result<JsValue, JsValue> {
     let oca_bundle: serde_json::Value = match serde_wasm_bindgen::from_value(oca_bundle) {
@@ -0,0 +1,291 @@
+use std::collections::BTreeMap;
+
+use oca_bindings_common::semantics::{Category, SemanticIssue};
+use oca_bindings_common::edit::{self, OverlayFilter};
+use oca_bindings_common::languages::{self, Translation};
+use oca_bindings_common::{build, builder, data, diff, resolve, version};
+use oca_sdk_rs::oca;
+use oca_sdk_rs::oca::file::OCAAst;
+use oca_sdk_rs::oca::validator::{validate_data, DataValidationStatus};
+use oca_sdk_rs::ToJSON;
+use serde_json::json;
+use wasm_bindgen::prelude::*;
+
+use crate::{json_value, semantic_issues, to_js_object, ValidationResult};
+
+/// An overlay registry parsed once and reused across calls. Every function
+/// that takes an overlay file is also available as a method here.
+#[wasm_bindgen]
+pub struct OverlayRegistry {
+    sources: Vec<String>,
+    registry: oca::overlay_file::OverlayLocalRegistry,
+}
+
+impl OverlayRegistry {
+    fn from_sources(sources: Vec<String>) -> Result<OverlayRegistry, JsValue> {
+        let registry = oca::overlay_file::OverlayLocalRegistry::from_string(sources.join("\n"))
+            .map_err(|e| JsValue::from_str(&format!("Failed to load overlay registry: {}", e)))?;
+        Ok(OverlayRegistry { sources, registry })
+    }
+
+    pub(crate) fn load(&self, oca_bundle: &serde_json::Value) -> Result<oca::bundle::OCABundleModel, JsValue> {
+        let bundle_str = oca_bundle.to_string();
+        self.load_str(&bundle_str)
+    }
+
+    /// The bundle loaded through the SDK and serialized again, so that two
+    /// bundles compare in the same serialization.
+    fn loaded_value(&self, oca_bundle: JsValue) -> Result<serde_json::Value, JsValue> {
+        let oca_bundle_model = self.load(&json_value(oca_bundle, "bundle format")?)?;
+        serde_json::from_str(&oca_bundle_model.get_json_bundle())
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize bundle: {}", e)))
+    }
+
+    /// Applies `change` to the bundle and loads the result, which checks its
+    /// overlays against this registry. Returns the new bundle JSON.
+    fn edited(
+        &self,
+        oca_bundle: JsValue,
+        change: impl FnOnce(&serde_json::Value) -> Result<serde_json::Value, String>,
+    ) -> Result<String, JsValue> {
+        let edited = change(&self.loaded_value(oca_bundle)?)
+            .map_err(|e| JsValue::from_str(&format!("Failed to edit bundle: {}", e)))?;
+
+        Ok(self.load(&edited)?.get_json_bundle())
+    }
+
+    /// Builds an OCAfile into bundle JSON, on top of the `base` bundle JSON
+    /// when given.
+    pub(crate) fn build_json(&self, ocafile_str: String, base: Option<String>) -> Result<String, JsValue> {
+        let base = base.map(|base| self.load_str(&base)).transpose()?;
+        let oca_ast = oca::file::parse_from_string(ocafile_str, &self.registry)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse OCAfile: {}", e)))?;
+
+        let build = oca::bundle::from_ast(base, &oca_ast)
+            .map_err(|e| JsValue::from_str(&format!("Failed to build bundle from OCAfile: {:?}", e)))?;
+
+        Ok(build.oca_bundle.get_json_bundle())
+    }
+
+    /// Parses an OCAfile rendered by a `BundleBuilder` and puts back the text
+    /// it could not carry.
+    pub(crate) fn parse_rendered(
+        &self,
+        rendered: &builder::Rendered,
+        resolver: Option<&dyn resolve::BundleResolver>,
+    ) -> Result<OCAAst, JsValue> {
+        rendered
+            .read_back(resolver, |text| oca::file::parse_from_string(text, &self.registry))
+            .map_err(|e| JsValue::from_str(&e.to_string()))
+    }
+
+    pub(crate) fn build_ast(&self, oca_ast: &OCAAst) -> Result<String, JsValue> {
+        let build = oca::bundle::from_ast(None, oca_ast)
+            .map_err(|e| JsValue::from_str(&format!("Failed to build bundle from OCAfile: {:?}", e)))?;
+
+        Ok(build.oca_bundle.get_json_bundle())
+    }
+
+    /// Builds a set of OCAfiles given as a name to text object.
+    pub(crate) fn build_set(
+        &self,
+        ocafiles: JsValue,
+        resolver: Option<&dyn resolve::BundleResolver>,
+    ) -> Result<JsValue, JsValue> {
+        let ocafiles: BTreeMap<String, String> = serde_wasm_bindgen::from_value(ocafiles)
+            .map_err(|e| JsValue::from_str(&format!("Invalid OCAfiles: {}", e)))?;
+
+        let built = build::build_set(ocafiles.into_iter().collect(), resolver, |text| {
+            self.build_json(text.to_string(), None)
+                .map_err(|e| e.as_string().unwrap_or_default())
+        })
+        .map_err(|errors| {
+            let messages: Vec<String> = errors.into_iter().map(|e| e.message).collect();
+            JsValue::from_str(&format!("Failed to build OCAfiles: {}", messages.join("; ")))
+        })?;
+
+        to_js_object(&built)
+    }
+
+    /// Validates a data record with the structured checks and the SDK
+    /// validator, following references with `resolver` when given.
+    pub(crate) fn validate_data_with(
+        &self,
+        oca_bundle: JsValue,
+        record: JsValue,
+        resolver: Option<&dyn resolve::BundleResolver>,
+    ) -> Result<JsValue, JsValue> {
+        let oca_bundle = json_value(oca_bundle, "bundle format")?;
+        let record = json_value(record, "data record")?;
+        let mut oca_bundle_model = self.load(&oca_bundle)?;
+
+        let sdk = |record: &serde_json::Value| match validate_data(&mut oca_bundle_model, record) {
+            Ok(DataValidationStatus::Valid) => Ok(vec![]),
+            Ok(DataValidationStatus::Invalid(errors)) => Ok(errors),
+            Err(e) => Err(JsValue::from_str(&format!("Failed to validate data: {}", e))),
+        };
+        let errors = data::validate_record_with(&oca_bundle, &record, sdk, resolver)?;
+
+        to_js_object(&ValidationResult::from(errors))
+    }
+
+    fn load_str(&self, bundle_str: &str) -> Result<oca::bundle::OCABundleModel, JsValue> {
+        if let Ok(oca_bundle) = serde_json::from_str::<serde_json::Value>(bundle_str) {
+            version::require_supported(&oca_bundle)
+                .map_err(|e| JsValue::from_str(&format!("Failed to load bundle: {}", e)))?;
+        }
+        let mut bytes = bundle_str.as_bytes();
+        oca::bundle::load(&mut bytes, &self.registry)
+            .map_err(|e| JsValue::from_str(&format!("Failed to load bundle: {}", e)))
+    }
+}
+
+#[wasm_bindgen]
+impl OverlayRegistry {
+    #[wasm_bindgen(constructor)]
+    pub fn new(overlay_file: String) -> Result<OverlayRegistry, JsValue> {
+        OverlayRegistry::from_sources(vec![overlay_file])
+    }
+
+    /// Returns a registry holding the overlay definitions of both.
+    pub fn merge(&self, other: &OverlayRegistry) -> Result<OverlayRegistry, JsValue> {
+        OverlayRegistry::from_sources([self.sources.clone(), other.sources.clone()].concat())
+    }
+
+    #[wasm_bindgen(js_name = "addOverlayFile")]
+    pub fn add_overlay_file(&mut self, overlay_file: String) -> Result<(), JsValue> {
+        let mut sources = self.sources.clone();
+        sources.push(overlay_file);
+        *self = OverlayRegistry::from_sources(sources)?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "parseOCAfile")]
+    pub fn parse_ocafile(&self, ocafile_str: String) -> Result<JsValue, JsValue> {
+        let oca_ast = oca::file::parse_from_string(ocafile_str, &self.registry)
+            .map_err(|e| JsValue::from_str(&format!("Failed to parse OCAfile: {}", e)))?;
+
+        let ast_json = json!({
+            "meta": oca_ast.meta,
+            "commands": oca_ast.commands
+        });
+
+        Ok(serde_wasm_bindgen::to_value(&ast_json).unwrap())
+    }
+
+    /// With a `base` bundle (JSON text), the OCAfile's commands are applied
+    /// on top of it; `getBundleAdditions` reports what they added.
+    #[wasm_bindgen(js_name = "buildFromOCAfile")]
+    pub fn build_from_ocafile(&self, ocafile_str: String, base: Option<String>) -> Result<JsValue, JsValue> {
+        let bundle_json = self.build_json(ocafile_str, base)?;
+
+        Ok(serde_wasm_bindgen::to_value(&bundle_json).unwrap())
+    }
+
+    /// Builds OCAfiles that reference each other by `refn:` name, given as
+    /// an object of name to OCAfile text, in dependency order. Returns
+    /// `{ saids, bundles, order }`.
+    #[wasm_bindgen(js_name = "buildOCAfiles")]
+    pub fn build_ocafiles(&self, ocafiles: JsValue) -> Result<JsValue, JsValue> {
+        self.build_set(ocafiles, None)
+    }
+
+    #[wasm_bindgen(js_name = "loadBundle")]
+    pub fn load_bundle(&self, json_str: String) -> Result<JsValue, JsValue> {
+        let oca_bundle_model = self.load_str(&json_str)?;
+
+        Ok(serde_wasm_bindgen::to_value(&oca_bundle_model).unwrap())
+    }
+
+    #[wasm_bindgen(js_name = "generateOCAfile")]
+    pub fn generate_ocafile(&self, bundle: String) -> Result<String, JsValue> {
+        let oca_bundle_model = self.load_str(&bundle)?;
+        let oca_ast = oca_bundle_model.to_ast();
+
+        Ok(oca::file::generate_from_ast(&oca_ast))
+    }
+
+    /// Compares two versions of a bundle. Returns a `BundleDiff`.
+    #[wasm_bindgen(js_name = "diffBundles")]
+    pub fn diff_bundles(&self, old: JsValue, new: JsValue) -> Result<JsValue, JsValue> {
+        to_js_object(&diff::diff(&self.loaded_value(old)?, &self.loaded_value(new)?))
+    }
+
+    /// `diffBundles` as a text report, one change per line.
+    #[wasm_bindgen(js_name = "diffBundlesReport")]
+    pub fn diff_bundles_report(&self, old: JsValue, new: JsValue) -> Result<String, JsValue> {
+        Ok(diff::diff(&self.loaded_value(old)?, &self.loaded_value(new)?).to_string())
+    }
+
+    /// Adds an overlay, an object with its `type`, its `language` if it has
+    /// one, and its fields, and recomputes the SAIDs. The type may be a name
+    /// such as `label` when the bundle already has an overlay of that name.
+    /// Returns the new bundle JSON.
+    #[wasm_bindgen(js_name = "addOverlay")]
+    pub fn add_overlay(&self, oca_bundle: JsValue, overlay: JsValue) -> Result<String, JsValue> {
+        let overlay = json_value(overlay, "overlay")?;
+        self.edited(oca_bundle, |bundle| edit::add_overlay(bundle, &overlay))
+    }
+
+    /// Replaces the overlay of the same type and language as `overlay`.
+    #[wasm_bindgen(js_name = "replaceOverlay")]
+    pub fn replace_overlay(&self, oca_bundle: JsValue, overlay: JsValue) -> Result<String, JsValue> {
+        let overlay = json_value(overlay, "overlay")?;
+        self.edited(oca_bundle, |bundle| edit::replace_overlay(bundle, &overlay))
+    }
+
+    /// Removes the overlays matching `{ type?, language? }`, where `type` is
+    /// the full type or the name; an omitted field matches any.
+    #[wasm_bindgen(js_name = "removeOverlays")]
+    pub fn remove_overlays(&self, oca_bundle: JsValue, filter: JsValue) -> Result<String, JsValue> {
+        let filter: OverlayFilter = serde_wasm_bindgen::from_value(filter)
+            .map_err(|e| JsValue::from_str(&format!("Invalid overlay filter: {}", e)))?;
+        self.edited(oca_bundle, |bundle| {
+            edit::remove_overlays(bundle, &filter).map(|(edited, _)| edited)
+        })
+    }
+
+    /// Keeps only the overlays in the given languages, and those without a
+    /// language, and recomputes the SAIDs. Returns the new bundle JSON.
+    #[wasm_bindgen(js_name = "keepLanguages")]
+    pub fn keep_languages(&self, oca_bundle: JsValue, keep: Vec<String>) -> Result<String, JsValue> {
+        self.edited(oca_bundle, |bundle| languages::keep_languages(bundle, &keep))
+    }
+
+    /// Extracts the overlays in `language` into a `Translation` package.
+    #[wasm_bindgen(js_name = "extractTranslation")]
+    pub fn extract_translation(&self, oca_bundle: JsValue, language: String) -> Result<JsValue, JsValue> {
+        let translation = languages::extract_translation(&self.loaded_value(oca_bundle)?, &language)
+            .map_err(|e| JsValue::from_str(&format!("Failed to extract translation: {}", e)))?;
+
+        to_js_object(&translation)
+    }
+
+    /// Writes the overlays of a `Translation` package into the bundle,
+    /// replacing those of the same type in its language. Returns the new
+    /// bundle JSON.
+    #[wasm_bindgen(js_name = "mergeTranslation")]
+    pub fn merge_translation(&self, oca_bundle: JsValue, translation: JsValue) -> Result<String, JsValue> {
+        let translation: Translation = serde_wasm_bindgen::from_value(translation)
+            .map_err(|e| JsValue::from_str(&format!("Invalid translation: {}", e)))?;
+        self.edited(oca_bundle, |bundle| languages::merge_translation(bundle, &translation))
+    }
+
+    #[wasm_bindgen(js_name = "validateBundleSemantics")]
+    pub fn validate_bundle_semantics(&self, bundle: JsValue) -> Result<JsValue, JsValue> {
+        let issues = match json_value(bundle, "bundle format") {
+            Ok(oca_bundle) => semantic_issues(&oca_bundle, Some(self)),
+            Err(e) => vec![SemanticIssue::new(
+                Category::Format,
+                e.as_string().unwrap_or_default(),
+            )],
+        };
+
+        to_js_object(&ValidationResult::from(issues))
+    }
+
+    #[wasm_bindgen(js_name = "validateData")]
+    pub fn validate_data(&self, oca_bundle: JsValue, record: JsValue) -> Result<JsValue, JsValue> {
+        self.validate_data_with(oca_bundle, record, None)
+    }
+}
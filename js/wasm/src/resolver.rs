@@ -0,0 +1,76 @@
+use oca_bindings_common::resolve::{self, Reference};
+use oca_bindings_common::types::AttrType;
+use wasm_bindgen::prelude::*;
+
+use crate::{attribute_descriptors, json_value, to_js_object, OverlayRegistry};
+
+/// Finds the bundles that `refs:` and `refn:` attribute types point at. The
+/// callback receives the reference as a type tree, e.g.
+/// `{ kind: "reference", said: "E..." }`, and returns the bundle (object or
+/// JSON text), or `null`/`undefined` when it does not know it.
+#[wasm_bindgen]
+pub struct BundleResolver {
+    callback: js_sys::Function,
+}
+
+impl resolve::BundleResolver for BundleResolver {
+    fn resolve(&self, reference: &Reference) -> Result<Option<serde_json::Value>, String> {
+        let describe = |e: JsValue| e.as_string().unwrap_or_else(|| format!("{:?}", e));
+        let tree = to_js_object(&AttrType::from(reference.clone())).map_err(describe)?;
+        let bundle = self
+            .callback
+            .call1(&JsValue::NULL, &tree)
+            .map_err(|e| format!("Resolver failed for {}: {}", reference, describe(e)))?;
+        if bundle.is_null() || bundle.is_undefined() {
+            return Ok(None);
+        }
+        json_value(bundle, "referenced bundle").map(Some).map_err(describe)
+    }
+}
+
+#[wasm_bindgen]
+impl BundleResolver {
+    #[wasm_bindgen(constructor)]
+    pub fn new(callback: js_sys::Function) -> BundleResolver {
+        BundleResolver { callback }
+    }
+
+    /// Like `getAttributeDescriptors`, with the attributes of referenced
+    /// bundles under `nested`.
+    #[wasm_bindgen(js_name = "getAttributeDescriptors")]
+    pub fn get_attribute_descriptors(&self, oca_bundle: JsValue) -> Result<JsValue, JsValue> {
+        let oca_bundle = json_value(oca_bundle, "bundle format")?;
+
+        to_js_object(&attribute_descriptors(&oca_bundle, Some(self))?)
+    }
+
+    /// Like `OverlayRegistry.validateData`, also validating the values of
+    /// reference attributes against the bundles they reference.
+    #[wasm_bindgen(js_name = "validateData")]
+    pub fn validate_data(&self, oca_bundle: JsValue, record: JsValue, registry: &OverlayRegistry) -> Result<JsValue, JsValue> {
+        registry.validate_data_with(oca_bundle, record, Some(self))
+    }
+
+    /// Builds a bundle after replacing `refn:` types with the SAIDs of the
+    /// bundles their names resolve to.
+    #[wasm_bindgen(js_name = "buildFromOCAfile")]
+    pub fn build_from_ocafile(
+        &self,
+        ocafile_str: String,
+        registry: &OverlayRegistry,
+        base: Option<String>,
+    ) -> Result<JsValue, JsValue> {
+        let ocafile_str = resolve::substitute_names(&ocafile_str, self).map_err(|errors| {
+            JsValue::from_str(&format!("Failed to resolve references: {}", errors.join("; ")))
+        })?;
+
+        registry.build_from_ocafile(ocafile_str, base)
+    }
+
+    /// Like `OverlayRegistry.buildOCAfiles`, looking up names outside the
+    /// set with this resolver.
+    #[wasm_bindgen(js_name = "buildOCAfiles")]
+    pub fn build_ocafiles(&self, ocafiles: JsValue, registry: &OverlayRegistry) -> Result<JsValue, JsValue> {
+        registry.build_set(ocafiles, Some(self))
+    }
+}
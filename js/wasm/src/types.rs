@@ -0,0 +1,75 @@
+use oca_bindings_common::types::AttrType;
+use oca_sdk_rs::oca::overlay_file::{AttributeType, NestedAttrType, RefValue};
+use wasm_bindgen::prelude::*;
+
+/// Thrown for type trees the SDK has no attribute type for, so callers can
+/// tell them apart from other failures by `name`.
+pub(crate) fn invalid_attribute_type(message: &str) -> JsValue {
+    let error = js_sys::Error::new(&format!("Invalid attribute type: {}", message));
+    error.set_name("InvalidAttributeTypeError");
+    error.into()
+}
+
+pub(crate) fn nested_attr_type_to_tree(nested: &NestedAttrType) -> AttrType {
+    match nested {
+        NestedAttrType::Reference(RefValue::Said(said)) => AttrType::RefSaid(said.to_string()),
+        NestedAttrType::Reference(RefValue::Name(name)) => AttrType::RefName(name.clone()),
+        NestedAttrType::Value(AttributeType::Text) => AttrType::Text,
+        NestedAttrType::Value(AttributeType::Numeric) => AttrType::Numeric,
+        NestedAttrType::Value(AttributeType::DateTime) => AttrType::DateTime,
+        NestedAttrType::Value(AttributeType::Boolean) => AttrType::Boolean,
+        NestedAttrType::Value(AttributeType::Binary) => AttrType::Binary,
+        NestedAttrType::Array(items) => AttrType::Array(Box::new(nested_attr_type_to_tree(items))),
+        NestedAttrType::Null => AttrType::Null,
+    }
+}
+
+pub(crate) fn nested_attr_type_from_tree(attr_type: &AttrType) -> Result<NestedAttrType, String> {
+    Ok(match attr_type {
+        AttrType::Text => NestedAttrType::Value(AttributeType::Text),
+        AttrType::Numeric => NestedAttrType::Value(AttributeType::Numeric),
+        AttrType::DateTime => NestedAttrType::Value(AttributeType::DateTime),
+        AttrType::Boolean => NestedAttrType::Value(AttributeType::Boolean),
+        AttrType::Binary => NestedAttrType::Value(AttributeType::Binary),
+        AttrType::RefSaid(said) => NestedAttrType::Reference(RefValue::Said(
+            said.parse()
+                .map_err(|_| format!("Invalid SAID in attribute type: '{}'", said))?,
+        )),
+        AttrType::RefName(name) => NestedAttrType::Reference(RefValue::Name(name.clone())),
+        AttrType::Array(items) => NestedAttrType::Array(Box::new(nested_attr_type_from_tree(items)?)),
+        AttrType::Null => NestedAttrType::Null,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use oca_sdk_rs::oca;
+    use oca_sdk_rs::oca::overlay_file::OverlayLocalRegistry;
+
+    use super::*;
+
+    const OCAFILE: &str = "ADD ATTRIBUTE name=Text age=Numeric born=DateTime ok=Boolean photo=Binary
+ADD ATTRIBUTE tags=[Text] friend=refs:EF5ERATRBBN_ewEo9buQbznirhBmvrSSC0O2GIR4Gbfs homes=[[refn:address]]";
+
+    #[test]
+    fn round_trips_the_sdk_types() {
+        let registry = OverlayLocalRegistry::from_string(include_str!("../../example/test/assets/semantic.overlayfile").to_string()).unwrap();
+        let ast = oca::file::parse_from_string(OCAFILE.to_string(), &registry).unwrap();
+        let mut model = oca::bundle::from_ast(None, &ast).unwrap().oca_bundle;
+        model.fill_attributes();
+
+        let attributes = model.attributes.unwrap();
+        assert_eq!(attributes.len(), 8);
+        for attribute in attributes.values() {
+            let nested = attribute.attribute_type.as_ref().unwrap();
+            let tree = nested_attr_type_to_tree(nested);
+            assert_eq!(&nested_attr_type_from_tree(&tree).unwrap(), nested, "{}", attribute.name);
+        }
+    }
+
+    #[test]
+    fn rejects_references_to_invalid_saids() {
+        let err = nested_attr_type_from_tree(&AttrType::Array(Box::new(AttrType::RefSaid("not-a-said".to_string()))));
+        assert!(err.unwrap_err().contains("'not-a-said'"));
+    }
+}
@@ -31,7 +31,7 @@ print("\n2. Getting bundle attributes...")
 attrs = oca_sdk.bundle_attributes(bundle_json, OVERLAY_REGISTRY_DIR)
 print(f"   Attributes: {len(attrs)}")
 for attr in attrs:
-    print(f"   - {attr['name']}: {attr['type']}")
+    print(f"   - {attr['name']}: {oca_sdk.attribute_type_from_tree(attr['type'])}")
 
 # Validate semantics
 print("\n3. Validating bundle semantics...")
@@ -48,8 +48,8 @@ data_validation = oca_sdk.validate_bundle_data(
 )
 print(f"   Data: {test_data}")
 print(f"   Valid: {data_validation['valid']}")
-if data_validation['errors']:
-    print(f"   Errors: {data_validation['errors']}")
+for error in data_validation['errors']:
+    print(f"   - {error['path']} [{error['rule']}]: {error['message']}")
 
 # Convert back to OCAfile
 print("\n5. Converting bundle to OCAfile...")
@@ -159,7 +159,7 @@ for i, overlay in enumerate(list(bundle.get('overlays', []))[:3]):
 print("\n2. Capture Base Attributes:")
 attrs = oca_sdk.bundle_attributes(bundle_json, OVERLAY_REGISTRY_DIR)
 for attr in attrs:
-    print(f"   - {attr['name']}: {attr['type']}")
+    print(f"   - {attr['name']}: {oca_sdk.attribute_type_from_tree(attr['type'])}")
 
 # Display overlays by type
 print("\n3. Overlays Summary:")
@@ -205,7 +205,7 @@ if data_validation['valid']:
 else:
     print("   ✗ Data validation errors:")
     for error in data_validation['errors']:
-        print(f"     - {error}")
+        print(f"     - {error['path']} [{error['rule']}]: {error['message']}")
 
 # Validate with invalid data
 print("\n6. Testing with Invalid Data:")
@@ -225,7 +225,7 @@ if invalid_validation['valid']:
 else:
     print("   ✗ Data validation errors (expected):")
     for error in invalid_validation['errors']:
-        print(f"     - {error}")
+        print(f"     - {error['path']} [{error['rule']}]: {error['message']}")
 
 # Generate OCAfile from bundle
 print("\n7. Converting Bundle back to OCAfile...")
@@ -0,0 +1,170 @@
+use oca_bindings_common::{builder, resolve};
+use oca_bindings_common::types::AttrType;
+use oca_sdk_rs::oca::file::{self as ocafile, OCAAst};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::bundle::Bundle;
+use crate::errors::{self, Error};
+use crate::registry::{self, OverlayRegistry, RegistryArg};
+use crate::resolver::{self, BundleResolver};
+use crate::py_to_json_string;
+
+/// Builds a bundle step by step. Every method returns the builder, so calls
+/// chain; `build` produces the same bundle, with the same SAID, as the
+/// equivalent OCAfile.
+#[pyclass(module = "oca_sdk")]
+pub struct BundleBuilder {
+    builder: builder::BundleBuilder,
+}
+
+impl BundleBuilder {
+    /// The AST of the rendered OCAfile, with the text the OCAfile could not
+    /// carry put back.
+    fn ast(&self, registry: &OverlayRegistry, resolver: Option<&dyn resolve::BundleResolver>) -> errors::Result<OCAAst> {
+        let rendered = self.builder.render().map_err(Error::Other)?;
+        Ok(rendered.read_back(resolver, |text| ocafile::parse_from_string(text, &registry.registry))?)
+    }
+}
+
+fn fields(py: Python<'_>, fields: &PyDict) -> PyResult<serde_json::Map<String, serde_json::Value>> {
+    Ok(serde_json::from_str(&py_to_json_string(py, fields)?).map_err(Error::data_format("overlay fields"))?)
+}
+
+#[pymethods]
+impl BundleBuilder {
+    #[new]
+    #[pyo3(signature = (name=None))]
+    fn new(name: Option<&str>) -> PyResult<Self> {
+        let mut builder = builder::BundleBuilder::new();
+        if let Some(name) = name {
+            builder.name(name).map_err(Error::Other)?;
+        }
+        Ok(BundleBuilder { builder })
+    }
+
+    /// `attribute_type` is the OCAfile spelling, such as `Array[Text]` or
+    /// `refn:address`, or a type tree as taken by `attribute_type_from_tree`.
+    fn add_attribute<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        py: Python<'py>,
+        name: &str,
+        attribute_type: &PyAny,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let attr_type = match attribute_type.downcast::<PyString>() {
+            Ok(spelling) => {
+                let spelling = spelling.to_str()?;
+                AttrType::parse(spelling)
+                    .ok_or_else(|| Error::Other(format!("Invalid attribute type '{spelling}'")))?
+            }
+            Err(_) => serde_json::from_str(&py_to_json_string(py, attribute_type)?)
+                .map_err(Error::data_format("attribute type"))?,
+        };
+        slf.builder.add_attribute(name, attr_type).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    fn label<'py>(mut slf: PyRefMut<'py, Self>, language: &str, attribute: &str, label: &str) -> PyResult<PyRefMut<'py, Self>> {
+        slf.builder.label(language, attribute, label).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    /// Needs a registry that defines the `information` overlay.
+    fn information<'py>(mut slf: PyRefMut<'py, Self>, language: &str, attribute: &str, information: &str) -> PyResult<PyRefMut<'py, Self>> {
+        slf.builder.information(language, attribute, information).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    /// `entries` maps entry codes to their labels in `language`.
+    fn entries<'py>(mut slf: PyRefMut<'py, Self>, language: &str, attribute: &str, entries: &PyDict) -> PyResult<PyRefMut<'py, Self>> {
+        let entries = entries
+            .iter()
+            .map(|(code, label)| Ok((code.extract()?, label.extract()?)))
+            .collect::<PyResult<Vec<(String, String)>>>()?;
+        slf.builder.entries(language, attribute, entries).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    fn entry_codes<'py>(mut slf: PyRefMut<'py, Self>, attribute: &str, codes: Vec<String>) -> PyResult<PyRefMut<'py, Self>> {
+        slf.builder.entry_codes(attribute, codes).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    fn conformance<'py>(mut slf: PyRefMut<'py, Self>, attribute: &str, conformance: &str) -> PyResult<PyRefMut<'py, Self>> {
+        slf.builder.conformance(attribute, conformance).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    fn format<'py>(mut slf: PyRefMut<'py, Self>, attribute: &str, format: &str) -> PyResult<PyRefMut<'py, Self>> {
+        slf.builder.format(attribute, format).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    fn cardinality<'py>(mut slf: PyRefMut<'py, Self>, attribute: &str, cardinality: &str) -> PyResult<PyRefMut<'py, Self>> {
+        slf.builder.cardinality(attribute, cardinality).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    fn unit<'py>(mut slf: PyRefMut<'py, Self>, attribute: &str, unit: &str) -> PyResult<PyRefMut<'py, Self>> {
+        slf.builder.unit(attribute, unit).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    fn character_encoding<'py>(mut slf: PyRefMut<'py, Self>, attribute: &str, encoding: &str) -> PyResult<PyRefMut<'py, Self>> {
+        slf.builder.character_encoding(attribute, encoding).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    fn standard<'py>(mut slf: PyRefMut<'py, Self>, attribute: &str, standard: &str) -> PyResult<PyRefMut<'py, Self>> {
+        slf.builder.standard(attribute, standard).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    fn sensitive<'py>(mut slf: PyRefMut<'py, Self>, attribute: &str) -> PyResult<PyRefMut<'py, Self>> {
+        slf.builder.sensitive(attribute).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    fn meta<'py>(mut slf: PyRefMut<'py, Self>, py: Python<'py>, language: &str, meta: &PyDict) -> PyResult<PyRefMut<'py, Self>> {
+        let meta = fields(py, meta)?;
+        slf.builder.meta(language, meta).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    /// Adds fields to any overlay the registry defines, such as
+    /// `overlay("unit", {"metric_system": "SI"})`. Dicts merge into what
+    /// earlier calls added to the same overlay and language.
+    #[pyo3(signature = (name, overlay_fields, language=None))]
+    fn overlay<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        py: Python<'py>,
+        name: &str,
+        overlay_fields: &PyDict,
+        language: Option<&str>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let overlay_fields = fields(py, overlay_fields)?;
+        slf.builder.overlay(name, language, overlay_fields).map_err(Error::Other)?;
+        Ok(slf)
+    }
+
+    /// With a resolver, `refn:` types are replaced by the SAID of the bundle
+    /// each name resolves to.
+    #[pyo3(signature = (overlay_dir=None, resolver=None))]
+    fn build(
+        &self,
+        py: Python<'_>,
+        overlay_dir: Option<RegistryArg>,
+        resolver: Option<PyRef<BundleResolver>>,
+    ) -> PyResult<Bundle> {
+        let registry = registry::resolve(overlay_dir)?;
+        let ast = self.ast(&registry, resolver::as_dyn(&resolver))?;
+        let model = oca_sdk_rs::oca::bundle::from_ast(None, &ast).map_err(Error::bundle_build)?.oca_bundle;
+        Ok(Bundle::new(model, registry.into_handle(py)?)?)
+    }
+
+    #[pyo3(signature = (overlay_dir=None))]
+    fn to_ocafile(&self, overlay_dir: Option<RegistryArg>) -> PyResult<String> {
+        let registry = registry::resolve(overlay_dir)?;
+        Ok(ocafile::generate_from_ast(&self.ast(&registry, None)?))
+    }
+}
@@ -0,0 +1,262 @@
+use oca_bindings_common::edit::{self, OverlayFilter};
+use oca_bindings_common::languages::{self, Translation};
+use oca_sdk_rs::oca::bundle::{OCABundle, OCABundleModel};
+use oca_sdk_rs::oca::file as ocafile;
+use pyo3::prelude::*;
+
+use crate::errors::{self, Error};
+use crate::registry::{self, OverlayRegistry, Registry, RegistryArg};
+use crate::resolver::{self, BundleResolver};
+use crate::{
+    attributes_list, bundle_model_from_json, bundle_model_from_ocafile, bundle_model_from_value,
+    bundle_model_to_json, bundle_value_from_json, data_result, json_to_py, py_to_json_string, semantics_result,
+};
+
+/// A loaded OCA bundle. Parsed once, so repeated validation does not pay
+/// for JSON decoding on every call.
+#[pyclass(module = "oca_sdk")]
+pub struct Bundle {
+    pub(crate) model: OCABundleModel,
+    /// The serialized bundle, kept for the checks that work on JSON.
+    pub(crate) value: serde_json::Value,
+    /// The registry the bundle was loaded or built with. Edits are loaded
+    /// with it unless they are given another.
+    pub(crate) registry: Py<OverlayRegistry>,
+}
+
+/// A bundle argument: a `Bundle` or bundle JSON text.
+#[derive(FromPyObject)]
+pub enum BundleArg<'py> {
+    Bundle(PyRef<'py, Bundle>),
+    Json(String),
+}
+
+impl BundleArg<'_> {
+    pub(crate) fn model(&self, registry: &OverlayRegistry) -> errors::Result<OCABundleModel> {
+        match self {
+            BundleArg::Bundle(bundle) => Ok(bundle.model.clone()),
+            BundleArg::Json(bundle_json) => bundle_model_from_json(bundle_json, registry),
+        }
+    }
+
+    /// The bundle loaded through the SDK and serialized again, so that two
+    /// bundles compare in the same serialization.
+    pub(crate) fn loaded_value(&self, registry: &OverlayRegistry) -> errors::Result<serde_json::Value> {
+        match self {
+            BundleArg::Bundle(bundle) => Ok(bundle.value.clone()),
+            BundleArg::Json(bundle_json) => filled_value(&mut bundle_model_from_json(bundle_json, registry)?),
+        }
+    }
+
+    /// The registry to load edits with: `overlay_dir` when given, else the
+    /// one a `Bundle` was loaded with.
+    pub(crate) fn registry<'a>(
+        &'a self,
+        py: Python<'a>,
+        overlay_dir: Option<RegistryArg<'a>>,
+    ) -> errors::Result<Registry<'a>> {
+        match self {
+            BundleArg::Bundle(bundle) => registry::resolve_or(py, overlay_dir, &bundle.registry),
+            BundleArg::Json(_) => registry::resolve(overlay_dir),
+        }
+    }
+
+    pub(crate) fn value(&self) -> errors::Result<serde_json::Value> {
+        match self {
+            BundleArg::Bundle(bundle) => Ok(bundle.value.clone()),
+            BundleArg::Json(bundle_json) => bundle_value_from_json(bundle_json),
+        }
+    }
+}
+
+impl Bundle {
+    pub(crate) fn new(mut model: OCABundleModel, registry: Py<OverlayRegistry>) -> errors::Result<Self> {
+        let value = filled_value(&mut model)?;
+        Ok(Bundle { model, value, registry })
+    }
+
+    /// Applies `change` to the bundle JSON and loads the result with
+    /// `overlay_dir`, or the bundle's own registry when it is not given.
+    fn edited(
+        &self,
+        py: Python<'_>,
+        overlay_dir: Option<RegistryArg>,
+        change: impl FnOnce(&serde_json::Value) -> Result<serde_json::Value, String>,
+    ) -> PyResult<Self> {
+        let registry = registry::resolve_or(py, overlay_dir, &self.registry)?;
+        let model = edited_model(&self.value, &registry, change)?;
+        Ok(Bundle::new(model, registry.into_handle(py)?)?)
+    }
+}
+
+/// Fills in the model's attributes and serializes it.
+fn filled_value(model: &mut OCABundleModel) -> errors::Result<serde_json::Value> {
+    model.fill_attributes();
+    serde_json::to_value(OCABundle::from(model.clone()))
+        .map_err(|e| Error::Other(format!("Failed to serialize bundle: {e}")))
+}
+
+/// Applies `change` to the bundle JSON and loads the result, which checks
+/// its overlays against the registry.
+pub(crate) fn edited_model(
+    value: &serde_json::Value,
+    registry: &OverlayRegistry,
+    change: impl FnOnce(&serde_json::Value) -> Result<serde_json::Value, String>,
+) -> errors::Result<OCABundleModel> {
+    let edited = change(value).map_err(Error::Other)?;
+    bundle_model_from_value(&edited, registry)
+}
+
+pub(crate) fn translation_value(py: Python<'_>, translation: &PyAny) -> PyResult<Translation> {
+    Ok(serde_json::from_str(&py_to_json_string(py, translation)?).map_err(Error::data_format("translation"))?)
+}
+
+pub(crate) fn translation_to_py(py: Python<'_>, translation: &Translation) -> PyResult<PyObject> {
+    let translation = serde_json::to_value(translation)
+        .map_err(|e| Error::Other(format!("Failed to serialize translation: {e}")))?;
+    json_to_py(py, &translation)
+}
+
+/// An overlay given as a dict with its `type`, its `language` if it has one,
+/// and its fields.
+pub(crate) fn overlay_value(py: Python<'_>, overlay: &PyAny) -> PyResult<serde_json::Value> {
+    Ok(serde_json::from_str(&py_to_json_string(py, overlay)?).map_err(Error::data_format("overlay"))?)
+}
+
+#[pymethods]
+impl Bundle {
+    /// With a `base` bundle, the OCAfile's commands are applied on top of it.
+    #[staticmethod]
+    #[pyo3(signature = (ocafile_text, overlay_dir=None, resolver=None, base=None))]
+    fn from_ocafile(
+        py: Python<'_>,
+        ocafile_text: String,
+        overlay_dir: Option<RegistryArg>,
+        resolver: Option<PyRef<BundleResolver>>,
+        base: Option<BundleArg>,
+    ) -> PyResult<Self> {
+        let registry = registry::resolve(overlay_dir)?;
+        let base = base.map(|base| base.model(&registry)).transpose()?;
+        let ocafile_text = resolver::substitute_names(ocafile_text, resolver::as_dyn(&resolver))?;
+        let model = bundle_model_from_ocafile(ocafile_text, &registry, base)?;
+        Ok(Bundle::new(model, registry.into_handle(py)?)?)
+    }
+
+    #[staticmethod]
+    #[pyo3(signature = (bundle_json, overlay_dir=None))]
+    fn from_json(py: Python<'_>, bundle_json: String, overlay_dir: Option<RegistryArg>) -> PyResult<Self> {
+        let registry = registry::resolve(overlay_dir)?;
+        let model = bundle_model_from_json(&bundle_json, &registry)?;
+        Ok(Bundle::new(model, registry.into_handle(py)?)?)
+    }
+
+    fn to_json(&self) -> PyResult<String> {
+        Ok(bundle_model_to_json(&self.model)?)
+    }
+
+    fn to_ocafile(&self) -> String {
+        ocafile::generate_from_ast(&self.model.to_ast())
+    }
+
+    fn digest(&self) -> Option<String> {
+        self.model.digest.as_ref().map(|said| said.to_string())
+    }
+
+    /// With a resolver, referenced bundles are described under `nested`.
+    #[pyo3(signature = (resolver=None))]
+    fn attributes(&self, py: Python<'_>, resolver: Option<PyRef<BundleResolver>>) -> PyResult<PyObject> {
+        attributes_list(py, &self.model, &self.value, resolver::as_dyn(&resolver))
+    }
+
+    fn overlays(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let overlays = self
+            .value
+            .get("overlays")
+            .cloned()
+            .unwrap_or_else(|| serde_json::Value::Array(vec![]));
+        json_to_py(py, &overlays)
+    }
+
+    fn validate_semantics(&self, py: Python<'_>) -> PyResult<PyObject> {
+        semantics_result(py, &self.model, &self.value)
+    }
+
+    /// With a resolver, values of reference attributes are validated against
+    /// the bundles they reference.
+    #[pyo3(signature = (data_json, resolver=None))]
+    fn validate_data(
+        &mut self,
+        py: Python<'_>,
+        data_json: String,
+        resolver: Option<PyRef<BundleResolver>>,
+    ) -> PyResult<PyObject> {
+        data_result(py, &mut self.model, &self.value, &data_json, resolver::as_dyn(&resolver))
+    }
+
+    /// Returns a new bundle with `overlay` added and the SAIDs recomputed.
+    /// The type may be a name such as `label` when the bundle already has an
+    /// overlay of that name.
+    #[pyo3(signature = (overlay, overlay_dir=None))]
+    fn add_overlay(&self, py: Python<'_>, overlay: &PyAny, overlay_dir: Option<RegistryArg>) -> PyResult<Self> {
+        let overlay = overlay_value(py, overlay)?;
+        self.edited(py, overlay_dir, |bundle| edit::add_overlay(bundle, &overlay))
+    }
+
+    /// Returns a new bundle with the overlay of the same type and language
+    /// as `overlay` replaced by it.
+    #[pyo3(signature = (overlay, overlay_dir=None))]
+    fn replace_overlay(&self, py: Python<'_>, overlay: &PyAny, overlay_dir: Option<RegistryArg>) -> PyResult<Self> {
+        let overlay = overlay_value(py, overlay)?;
+        self.edited(py, overlay_dir, |bundle| edit::replace_overlay(bundle, &overlay))
+    }
+
+    /// Returns a new bundle without the overlays of the given `type` (full
+    /// type or name) and `language`; an omitted argument matches any.
+    #[pyo3(signature = (r#type=None, language=None, overlay_dir=None))]
+    fn remove_overlays(
+        &self,
+        py: Python<'_>,
+        r#type: Option<String>,
+        language: Option<String>,
+        overlay_dir: Option<RegistryArg>,
+    ) -> PyResult<Self> {
+        let filter = OverlayFilter {
+            overlay_type: r#type,
+            language,
+        };
+        self.edited(py, overlay_dir, |bundle| {
+            edit::remove_overlays(bundle, &filter).map(|(edited, _)| edited)
+        })
+    }
+
+    /// The languages of the bundle's overlays.
+    fn languages(&self) -> Vec<String> {
+        languages::languages(&self.value)
+    }
+
+    /// Returns a new bundle without the overlays in other languages.
+    /// Overlays without a language are kept.
+    #[pyo3(signature = (keep, overlay_dir=None))]
+    fn keep_languages(&self, py: Python<'_>, keep: Vec<String>, overlay_dir: Option<RegistryArg>) -> PyResult<Self> {
+        self.edited(py, overlay_dir, |bundle| languages::keep_languages(bundle, &keep))
+    }
+
+    /// The overlays in `language` as a translation package: a dict with the
+    /// `language`, the `capture_base` SAID and the `overlays` without SAIDs.
+    fn extract_translation(&self, py: Python<'_>, language: &str) -> PyResult<PyObject> {
+        let translation = languages::extract_translation(&self.value, language).map_err(Error::Other)?;
+        translation_to_py(py, &translation)
+    }
+
+    /// Returns a new bundle with the overlays of a translation package,
+    /// replacing those of the same type in its language.
+    #[pyo3(signature = (translation, overlay_dir=None))]
+    fn merge_translation(&self, py: Python<'_>, translation: &PyAny, overlay_dir: Option<RegistryArg>) -> PyResult<Self> {
+        let translation = translation_value(py, translation)?;
+        self.edited(py, overlay_dir, |bundle| languages::merge_translation(bundle, &translation))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Bundle(digest={:?})", self.digest().unwrap_or_default())
+    }
+}
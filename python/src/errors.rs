@@ -0,0 +1,204 @@
+use oca_bindings_common::build::BuildSetError;
+use oca_bindings_common::builder::ReadBackError;
+use oca_sdk_rs::oca::file::error::ParseError;
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde::Deserialize;
+
+// `OcaError` derives from `ValueError` so code written against the earlier
+// releases, which raised plain `ValueError`, keeps catching everything.
+create_exception!(oca_sdk, OcaError, PyValueError, "Base class for all OCA SDK errors.");
+create_exception!(oca_sdk, OcaFileParseError, OcaError, "The OCAfile could not be parsed.");
+create_exception!(oca_sdk, OverlayRegistryError, OcaError, "The overlay registry could not be loaded.");
+create_exception!(oca_sdk, BundleBuildError, OcaError, "The OCAfile parsed but the bundle could not be built.");
+create_exception!(oca_sdk, BundleLoadError, OcaError, "The bundle JSON could not be loaded.");
+create_exception!(oca_sdk, UnsupportedVersionError, BundleLoadError, "The bundle declares a version the SDK cannot read.");
+create_exception!(oca_sdk, DataFormatError, OcaError, "Input that should be JSON is malformed.");
+create_exception!(oca_sdk, InvalidAttributeTypeError, OcaError, "A type tree has no SDK attribute type.");
+create_exception!(oca_sdk, UnresolvedReferenceError, OcaError, "A referenced bundle could not be resolved.");
+create_exception!(oca_sdk, BundleSetError, OcaError, "A set of OCAfiles could not be built.");
+
+pub enum Error {
+    OcaFileParse(ParseError),
+    OverlayRegistry { overlay_dir: Option<String>, message: String },
+    BundleBuild(Vec<BuildError>),
+    BundleLoad { registry: String, message: String },
+    UnsupportedVersion { version: String, message: String },
+    DataFormat { input: &'static str, error: serde_json::Error },
+    AttributeType(String),
+    Reference(Vec<String>),
+    BuildSet(Vec<BuildSetError>),
+    Other(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// An error `from_ast` reports. The SDK does not export its error type, so
+/// the fields are read from its serialized form.
+#[derive(Deserialize)]
+pub struct BuildError {
+    #[serde(rename = "ln")]
+    line_number: usize,
+    #[serde(rename = "c")]
+    raw_line: String,
+    #[serde(rename = "e")]
+    message: String,
+}
+
+impl Error {
+    pub fn data_format(input: &'static str) -> impl FnOnce(serde_json::Error) -> Error {
+        move |error| Error::DataFormat { input, error }
+    }
+
+    pub fn bundle_build<E: serde::Serialize + std::fmt::Display>(errors: Vec<E>) -> Error {
+        let errors = errors
+            .iter()
+            .map(|error| {
+                serde_json::to_value(error)
+                    .and_then(serde_json::from_value)
+                    .unwrap_or_else(|_| BuildError {
+                        line_number: 0,
+                        raw_line: String::new(),
+                        message: error.to_string(),
+                    })
+            })
+            .collect();
+        Error::BundleBuild(errors)
+    }
+
+    fn into_pyerr(self, py: Python<'_>) -> PyResult<PyErr> {
+        let (err, fields): (PyErr, Vec<(&str, PyObject)>) = match self {
+            Error::OcaFileParse(error) => {
+                let (line, column, snippet) = match &error {
+                    ParseError::GrammarError {
+                        line_number,
+                        column_number,
+                        raw_line,
+                        ..
+                    } => (Some(*line_number), Some(*column_number), Some(raw_line.clone())),
+                    _ => (None, None, None),
+                };
+                let message = match &error {
+                    ParseError::GrammarError { message, .. } => message.clone(),
+                    other => other.to_string(),
+                };
+                (
+                    OcaFileParseError::new_err(format!("Failed to parse OCAfile: {error}")),
+                    vec![
+                        ("line", line.into_py(py)),
+                        ("column", column.into_py(py)),
+                        ("snippet", snippet.into_py(py)),
+                        ("message", message.into_py(py)),
+                    ],
+                )
+            }
+            Error::OverlayRegistry { overlay_dir, message } => (
+                OverlayRegistryError::new_err(format!("Failed to load overlay registry: {message}")),
+                vec![
+                    ("overlay_dir", overlay_dir.into_py(py)),
+                    ("message", message.into_py(py)),
+                ],
+            ),
+            Error::BundleBuild(errors) => {
+                let summary = errors
+                    .iter()
+                    .map(|e| format!("line {}: {}", e.line_number, e.message))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                let details = errors
+                    .iter()
+                    .map(|e| {
+                        let dict = PyDict::new(py);
+                        dict.set_item("line", e.line_number)?;
+                        dict.set_item("snippet", e.raw_line.clone())?;
+                        dict.set_item("message", e.message.clone())?;
+                        Ok(dict.into_py(py))
+                    })
+                    .collect::<PyResult<Vec<PyObject>>>()?;
+                (
+                    BundleBuildError::new_err(format!("Failed to build bundle: {summary}")),
+                    vec![("errors", details.into_py(py))],
+                )
+            }
+            Error::BundleLoad { registry, message } => (
+                BundleLoadError::new_err(format!("Failed to load bundle with {registry}: {message}")),
+                vec![
+                    ("registry", registry.into_py(py)),
+                    ("message", message.into_py(py)),
+                ],
+            ),
+            Error::UnsupportedVersion { version, message } => (
+                UnsupportedVersionError::new_err(message.clone()),
+                vec![
+                    ("version", version.into_py(py)),
+                    ("message", message.into_py(py)),
+                ],
+            ),
+            Error::DataFormat { input, error } => (
+                DataFormatError::new_err(format!("Invalid {input} JSON: {error}")),
+                vec![
+                    ("input", input.into_py(py)),
+                    ("line", error.line().into_py(py)),
+                    ("column", error.column().into_py(py)),
+                    ("message", error.to_string().into_py(py)),
+                ],
+            ),
+            Error::AttributeType(message) => (
+                InvalidAttributeTypeError::new_err(format!("Invalid attribute type: {message}")),
+                vec![("message", message.into_py(py))],
+            ),
+            Error::Reference(errors) => (
+                UnresolvedReferenceError::new_err(format!("Failed to resolve references: {}", errors.join("; "))),
+                vec![("errors", errors.into_py(py))],
+            ),
+            Error::BuildSet(errors) => {
+                let summary = errors.iter().map(|e| e.message.as_str()).collect::<Vec<_>>().join("; ");
+                let details = serde_json::to_value(&errors)
+                    .map_err(|e| OcaError::new_err(e.to_string()))?;
+                (
+                    BundleSetError::new_err(format!("Failed to build OCAfiles: {summary}")),
+                    vec![("errors", crate::json_to_py(py, &details)?)],
+                )
+            }
+            Error::Other(message) => (OcaError::new_err(message), vec![]),
+        };
+
+        let value = err.value(py);
+        for (name, field) in fields {
+            value.setattr(name, field)?;
+        }
+        Ok(err)
+    }
+}
+
+impl From<ReadBackError<ParseError>> for Error {
+    fn from(error: ReadBackError<ParseError>) -> Error {
+        match error {
+            ReadBackError::References(errors) => Error::Reference(errors),
+            ReadBackError::Parse(error) => Error::OcaFileParse(error),
+            ReadBackError::Ast(message) => Error::Other(message),
+        }
+    }
+}
+
+impl From<Error> for PyErr {
+    fn from(error: Error) -> PyErr {
+        Python::with_gil(|py| error.into_pyerr(py).unwrap_or_else(|e| e))
+    }
+}
+
+pub fn register(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add("OcaError", py.get_type::<OcaError>())?;
+    m.add("OcaFileParseError", py.get_type::<OcaFileParseError>())?;
+    m.add("OverlayRegistryError", py.get_type::<OverlayRegistryError>())?;
+    m.add("BundleBuildError", py.get_type::<BundleBuildError>())?;
+    m.add("BundleLoadError", py.get_type::<BundleLoadError>())?;
+    m.add("UnsupportedVersionError", py.get_type::<UnsupportedVersionError>())?;
+    m.add("DataFormatError", py.get_type::<DataFormatError>())?;
+    m.add("InvalidAttributeTypeError", py.get_type::<InvalidAttributeTypeError>())?;
+    m.add("UnresolvedReferenceError", py.get_type::<UnresolvedReferenceError>())?;
+    m.add("BundleSetError", py.get_type::<BundleSetError>())?;
+    Ok(())
+}
@@ -1,143 +1,542 @@
-use oca_sdk_rs::data_validator::{self, DataValidationStatus};
-use oca_sdk_rs::ocafile;
-use oca_sdk_rs::overlay_registry::OverlayLocalRegistry;
-use oca_sdk_rs::{validate_semantics, AttributeType, NestedAttrType, NestedAttrTypeFrame, OCABundle, OCABundleModel, RefValue};
+mod builder;
+mod bundle;
+mod errors;
+mod registry;
+mod resolver;
+
+use builder::BundleBuilder;
+use bundle::{edited_model, overlay_value, translation_to_py, translation_value, Bundle, BundleArg};
+use errors::Error;
+use oca_bindings_common::types::AttrType;
+use oca_bindings_common::edit::{self, OverlayFilter};
+use oca_bindings_common::languages;
+use oca_bindings_common::{additions, attributes, build, compat, data, diff, resolve, said, semantics, version};
+use oca_sdk_rs::oca::bundle::{validate_semantics, OCABundle, OCABundleModel, SemanticValidationStatus};
+use oca_sdk_rs::oca::file::{self as ocafile, OCAAst};
+use oca_sdk_rs::oca::overlay_file::{AttributeType, NestedAttrType, NestedAttrTypeFrame, RefValue};
+use oca_sdk_rs::oca::validator::{self as data_validator, DataValidationStatus};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::PyDict;
 use recursion::CollapsibleExt;
+use registry::{OverlayRegistry, RegistryArg};
+use resolver::BundleResolver;
 
-fn overlay_registry(overlay_dir: Option<String>) -> Result<OverlayLocalRegistry, String> {
-    match overlay_dir {
-        Some(dir) if !dir.trim().is_empty() => {
-            OverlayLocalRegistry::from_dir(&dir).map_err(|e| e.to_string())
-        }
-        _ => Ok(OverlayLocalRegistry::default()),
-    }
+fn bundle_value_from_json(bundle_json: &str) -> errors::Result<serde_json::Value> {
+    serde_json::from_str(bundle_json).map_err(Error::data_format("bundle"))
 }
 
-fn bundle_model_from_json(bundle_json: &str, _overlay_dir: Option<String>) -> Result<OCABundleModel, String> {
-    let bundle: OCABundle = serde_json::from_str(bundle_json)
-        .map_err(|e| format!("Invalid bundle JSON: {e}"))?;
-    Ok(oca_sdk_rs::bundle_model_from_bundle(bundle))
+fn bundle_model_from_json(bundle_json: &str, registry: &OverlayRegistry) -> errors::Result<OCABundleModel> {
+    bundle_model_from_value(&bundle_value_from_json(bundle_json)?, registry)
+}
+
+/// Loads an already parsed bundle, so callers that also need its JSON form
+/// do not parse the text twice.
+fn bundle_model_from_value(bundle: &serde_json::Value, registry: &OverlayRegistry) -> errors::Result<OCABundleModel> {
+    version::require_supported(bundle).map_err(|message| Error::UnsupportedVersion {
+        version: bundle["v"].as_str().unwrap_or_default().to_string(),
+        message,
+    })?;
+    let bytes = serde_json::to_vec(bundle)
+        .map_err(|e| Error::Other(format!("Failed to serialize bundle: {e}")))?;
+    oca_sdk_rs::oca::bundle::load(&mut bytes.as_slice(), &registry.registry).map_err(|e| Error::BundleLoad {
+        registry: registry.description.clone(),
+        message: e.to_string(),
+    })
 }
 
-fn nested_attr_type_to_string(nested: &NestedAttrType) -> String {
+/// Builds on top of `base` when given, keeping its capture base and
+/// overlays.
+fn bundle_model_from_ocafile(
+    ocafile_text: String,
+    registry: &OverlayRegistry,
+    base: Option<OCABundleModel>,
+) -> errors::Result<OCABundleModel> {
+    let ast = ocafile::parse_from_string(ocafile_text, &registry.registry).map_err(Error::OcaFileParse)?;
+    Ok(oca_sdk_rs::oca::bundle::from_ast(base, &ast)
+        .map_err(Error::bundle_build)?
+        .oca_bundle)
+}
+
+fn bundle_model_to_json(bundle_model: &OCABundleModel) -> errors::Result<String> {
+    let bundle = OCABundle::from(bundle_model.clone());
+    serde_json::to_string_pretty(&bundle)
+        .map_err(|e| Error::Other(format!("Failed to serialize bundle: {e}")))
+}
+
+fn nested_attr_type_to_tree(nested: &NestedAttrType) -> AttrType {
     nested.clone().collapse_frames(|frame| match frame {
         NestedAttrTypeFrame::Reference(ref_value) => match ref_value {
-            RefValue::Said(said) => format!("refs:{said}"),
-            RefValue::Name(name) => format!("refn:{name}"),
+            RefValue::Said(said) => AttrType::RefSaid(said.to_string()),
+            RefValue::Name(name) => AttrType::RefName(name.to_string()),
         },
         NestedAttrTypeFrame::Value(value) => match value {
-            AttributeType::Text => "Text".to_string(),
-            AttributeType::Numeric => "Numeric".to_string(),
-            AttributeType::DateTime => "DateTime".to_string(),
-            AttributeType::Boolean => "Boolean".to_string(),
-            AttributeType::Binary => "Binary".to_string(),
+            AttributeType::Text => AttrType::Text,
+            AttributeType::Numeric => AttrType::Numeric,
+            AttributeType::DateTime => AttrType::DateTime,
+            AttributeType::Boolean => AttrType::Boolean,
+            AttributeType::Binary => AttrType::Binary,
         },
-        NestedAttrTypeFrame::Array(array) => format!("[{array}]"),
-        NestedAttrTypeFrame::Null => "".to_string(),
+        NestedAttrTypeFrame::Array(items) => AttrType::Array(Box::new(items)),
+        NestedAttrTypeFrame::Null => AttrType::Null,
+    })
+}
+
+fn nested_attr_type_from_tree(attr_type: &AttrType) -> errors::Result<NestedAttrType> {
+    Ok(match attr_type {
+        AttrType::Text => NestedAttrType::Value(AttributeType::Text),
+        AttrType::Numeric => NestedAttrType::Value(AttributeType::Numeric),
+        AttrType::DateTime => NestedAttrType::Value(AttributeType::DateTime),
+        AttrType::Boolean => NestedAttrType::Value(AttributeType::Boolean),
+        AttrType::Binary => NestedAttrType::Value(AttributeType::Binary),
+        AttrType::RefSaid(said) => NestedAttrType::Reference(RefValue::Said(
+            said.parse()
+                .map_err(|_| Error::AttributeType(format!("Invalid SAID in attribute type: '{said}'")))?,
+        )),
+        AttrType::RefName(name) => NestedAttrType::Reference(RefValue::Name(name.clone())),
+        AttrType::Array(items) => NestedAttrType::Array(Box::new(nested_attr_type_from_tree(items)?)),
+        AttrType::Null => NestedAttrType::Null,
     })
 }
 
+/// Runs the structural checks and the SDK validator, so each error has a
+/// category and names the overlay and attribute it concerns when known.
+fn semantics_result(
+    py: Python<'_>,
+    bundle_model: &OCABundleModel,
+    bundle_value: &serde_json::Value,
+) -> PyResult<PyObject> {
+    let sdk_errors = match validate_semantics(bundle_model)
+        .map_err(|e| Error::Other(format!("Failed to validate semantics: {e}")))? {
+        SemanticValidationStatus::Valid => vec![],
+        SemanticValidationStatus::Invalid(errors) => {
+            errors.into_iter().map(|e| e.to_string()).collect()
+        }
+    };
+    let issues = semantics::with_sdk_errors(bundle_value, semantics::check_bundle(bundle_value), sdk_errors);
+    let valid = issues.is_empty();
+    let issues = serde_json::to_value(&issues)
+        .map_err(|e| Error::Other(format!("Failed to serialize semantic issues: {e}")))?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("valid", valid)?;
+    dict.set_item("errors", json_to_py(py, &issues)?)?;
+    Ok(dict.into())
+}
+
+/// Validates the record with the structured checks, adding what the SDK
+/// validator finds that they do not, so each error names the attribute,
+/// JSON pointer and rule.
+fn data_result(
+    py: Python<'_>,
+    bundle_model: &mut OCABundleModel,
+    bundle_value: &serde_json::Value,
+    data_json: &str,
+    resolver: Option<&dyn resolve::BundleResolver>,
+) -> PyResult<PyObject> {
+    let data: serde_json::Value = serde_json::from_str(data_json)
+        .map_err(Error::data_format("data"))?;
+
+    let sdk = |record: &serde_json::Value| match data_validator::validate_data(bundle_model, record) {
+        Ok(DataValidationStatus::Valid) => Ok(vec![]),
+        Ok(DataValidationStatus::Invalid(errors)) => Ok(errors),
+        Err(e) => Err(Error::Other(format!("Failed to validate data: {e}"))),
+    };
+    let errors = data::validate_record_with(bundle_value, &data, sdk, resolver)?;
+    let valid = errors.is_empty();
+    let errors = serde_json::to_value(&errors)
+        .map_err(|e| Error::Other(format!("Failed to serialize validation errors: {e}")))?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("valid", valid)?;
+    dict.set_item("errors", json_to_py(py, &errors)?)?;
+    Ok(dict.into())
+}
+
+/// One descriptor per attribute, joining the capture base with its overlays.
+/// Expects `fill_attributes` to have been called on the model.
+fn attributes_list(
+    py: Python<'_>,
+    bundle_model: &OCABundleModel,
+    bundle_value: &serde_json::Value,
+    resolver: Option<&dyn resolve::BundleResolver>,
+) -> PyResult<PyObject> {
+    let filled = bundle_model
+        .attributes
+        .iter()
+        .flat_map(|attr_map| attr_map.values())
+        .map(|attr| (attr.name.as_str(), attr.attribute_type.as_ref().map(nested_attr_type_to_tree)));
+    let descriptors = attributes::describe_filled(bundle_value, filled, resolver);
+    let descriptors = serde_json::to_value(&descriptors)
+        .map_err(|e| Error::Other(format!("Failed to serialize attributes: {e}")))?;
+
+    json_to_py(py, &descriptors)
+}
+
+fn json_to_py(py: Python<'_>, value: &serde_json::Value) -> PyResult<PyObject> {
+    let json = py.import("json")?;
+    Ok(json.call_method1("loads", (value.to_string(),))?.into())
+}
+
+fn py_to_json_string(py: Python<'_>, value: &PyAny) -> PyResult<String> {
+    let json = py.import("json")?;
+    json.call_method1("dumps", (value,))?.extract()
+}
+
+/// With a resolver, `refn:` types are replaced by the SAID of the bundle
+/// each name resolves to before building. With a `base` bundle (a `Bundle`
+/// or bundle JSON), the OCAfile's commands are applied on top of it;
+/// `bundle_additions` reports what they added.
 #[pyfunction]
-fn build_from_ocafile(ocafile_text: String, overlay_dir: Option<String>) -> PyResult<String> {
-    let registry = overlay_registry(overlay_dir).map_err(pyo3::exceptions::PyValueError::new_err)?;
-    let ast = ocafile::parse_from_string(ocafile_text, &registry)
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Failed to parse OCAfile: {e}")))?;
-    let bundle = oca_sdk_rs::from_ast(None, &ast)
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Failed to build bundle: {e:?}")))?
-        .oca_bundle;
-    let bundle = OCABundle::from(bundle);
-    serde_json::to_string_pretty(&bundle)
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Failed to serialize bundle: {e}")))
+#[pyo3(signature = (ocafile_text, overlay_dir=None, resolver=None, base=None))]
+fn build_from_ocafile(
+    ocafile_text: String,
+    overlay_dir: Option<RegistryArg>,
+    resolver: Option<PyRef<BundleResolver>>,
+    base: Option<BundleArg>,
+) -> PyResult<String> {
+    let registry = registry::resolve(overlay_dir)?;
+    let base = base.map(|base| base.model(&registry)).transpose()?;
+    let ocafile_text = resolver::substitute_names(ocafile_text, resolver::as_dyn(&resolver))?;
+    let bundle_model = bundle_model_from_ocafile(ocafile_text, &registry, base)?;
+    Ok(bundle_model_to_json(&bundle_model)?)
 }
 
+/// Returns the OCAfile AST as plain dicts and lists. `meta` and `commands`
+/// are what most callers want; `version` and `commands_meta` are kept so the
+/// result can be fed back to `ocafile_from_ast`.
 #[pyfunction]
-fn bundle_to_ocafile(bundle_json: String, overlay_dir: Option<String>) -> PyResult<String> {
-    let bundle_model = bundle_model_from_json(&bundle_json, overlay_dir)
-        .map_err(pyo3::exceptions::PyValueError::new_err)?;
-    let ast = bundle_model.to_ast();
+#[pyo3(signature = (ocafile_text, overlay_dir=None))]
+fn parse_ocafile(py: Python<'_>, ocafile_text: String, overlay_dir: Option<RegistryArg>) -> PyResult<PyObject> {
+    let registry = registry::resolve(overlay_dir)?;
+    let ast = ocafile::parse_from_string(ocafile_text, &registry.registry).map_err(Error::OcaFileParse)?;
+    let ast = serde_json::to_value(&ast)
+        .map_err(|e| Error::Other(format!("Failed to serialize OCAfile AST: {e}")))?;
+    json_to_py(py, &ast)
+}
+
+#[pyfunction]
+fn ocafile_from_ast(py: Python<'_>, ast: &PyAny) -> PyResult<String> {
+    let ast_json = py_to_json_string(py, ast)?;
+    let ast: OCAAst = serde_json::from_str(&ast_json).map_err(Error::data_format("AST"))?;
     Ok(ocafile::generate_from_ast(&ast))
 }
 
+/// Builds OCAfiles that reference each other with `refn:<name>`, given as a
+/// dict of name to OCAfile text (an OCAfile's own `--name=` wins). They are
+/// built in dependency order with each name replaced by the SAID built for
+/// it. Returns `saids` (name to SAID), `bundles` (name to bundle JSON) and
+/// the build `order`.
+#[pyfunction]
+#[pyo3(signature = (ocafiles, overlay_dir=None, resolver=None))]
+fn build_ocafiles(
+    py: Python<'_>,
+    ocafiles: &PyDict,
+    overlay_dir: Option<RegistryArg>,
+    resolver: Option<PyRef<BundleResolver>>,
+) -> PyResult<PyObject> {
+    let files = ocafiles
+        .iter()
+        .map(|(name, text)| Ok((name.extract::<String>()?, text.extract::<String>()?)))
+        .collect::<PyResult<Vec<_>>>()?;
+    let registry = registry::resolve(overlay_dir)?;
+
+    let built = build::build_set(files, resolver::as_dyn(&resolver), |text| {
+        bundle_model_from_ocafile(text.to_string(), &registry, None)
+            .and_then(|model| bundle_model_to_json(&model))
+            .map_err(|e| PyErr::from(e).to_string())
+    })
+    .map_err(Error::BuildSet)?;
+    let built = serde_json::to_value(&built)
+        .map_err(|e| Error::Other(format!("Failed to serialize bundles: {e}")))?;
+
+    json_to_py(py, &built)
+}
+
+/// Reports what `bundle` adds to the `base` it was built from: new
+/// `attributes`, `overlays` of a new type or language and `changed_overlays`.
+/// Both arguments are a `Bundle` or bundle JSON.
 #[pyfunction]
-fn validate_bundle_semantics(py: Python<'_>, bundle_json: String) -> PyResult<PyObject> {
-    let bundle_model = bundle_model_from_json(&bundle_json, None)
-        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+fn bundle_additions(py: Python<'_>, base: BundleArg, bundle: BundleArg) -> PyResult<PyObject> {
+    let additions = additions::additions(&base.value()?, &bundle.value()?);
+    let additions = serde_json::to_value(&additions)
+        .map_err(|e| Error::Other(format!("Failed to serialize additions: {e}")))?;
 
-    let (valid, errors) = match validate_semantics(&bundle_model)
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Failed to validate semantics: {e}")))? {
-        oca_sdk_rs::SemanticValidationStatus::Valid => (true, vec![]),
-        oca_sdk_rs::SemanticValidationStatus::Invalid(errors) => {
-            let errors = errors.into_iter().map(|e| e.to_string()).collect();
-            (false, errors)
-        }
+    json_to_py(py, &additions)
+}
+
+/// Compares two versions of a bundle (each a `Bundle` or bundle JSON):
+/// added, removed and retyped attributes, added, removed and changed
+/// overlays, and label and entry changes per language.
+#[pyfunction]
+#[pyo3(signature = (old, new, overlay_dir=None))]
+fn diff_bundles(py: Python<'_>, old: BundleArg, new: BundleArg, overlay_dir: Option<RegistryArg>) -> PyResult<PyObject> {
+    let (old, new) = bundle_pair(old, new, overlay_dir)?;
+    let bundle_diff = serde_json::to_value(diff::diff(&old, &new))
+        .map_err(|e| Error::Other(format!("Failed to serialize diff: {e}")))?;
+
+    json_to_py(py, &bundle_diff)
+}
+
+/// `diff_bundles` as a text report, one change per line.
+#[pyfunction]
+#[pyo3(signature = (old, new, overlay_dir=None))]
+fn diff_bundles_report(old: BundleArg, new: BundleArg, overlay_dir: Option<RegistryArg>) -> PyResult<String> {
+    let (old, new) = bundle_pair(old, new, overlay_dir)?;
+    Ok(diff::diff(&old, &new).to_string())
+}
+
+/// Adds an overlay, a dict with its `type`, its `language` if it has one,
+/// and its fields, to a bundle (a `Bundle` or bundle JSON). The SAIDs are
+/// recomputed and the result is checked against the overlay registry.
+/// Returns the new bundle JSON.
+#[pyfunction]
+#[pyo3(signature = (bundle, overlay, overlay_dir=None))]
+fn add_overlay(py: Python<'_>, bundle: BundleArg, overlay: &PyAny, overlay_dir: Option<RegistryArg>) -> PyResult<String> {
+    let overlay = overlay_value(py, overlay)?;
+    let registry = bundle.registry(py, overlay_dir)?;
+    let edited = edited_model(&bundle.loaded_value(&registry)?, &registry, |bundle| edit::add_overlay(bundle, &overlay))?;
+    Ok(bundle_model_to_json(&edited)?)
+}
+
+/// Replaces the overlay of the same type and language as `overlay`.
+#[pyfunction]
+#[pyo3(signature = (bundle, overlay, overlay_dir=None))]
+fn replace_overlay(py: Python<'_>, bundle: BundleArg, overlay: &PyAny, overlay_dir: Option<RegistryArg>) -> PyResult<String> {
+    let overlay = overlay_value(py, overlay)?;
+    let registry = bundle.registry(py, overlay_dir)?;
+    let edited = edited_model(&bundle.loaded_value(&registry)?, &registry, |bundle| edit::replace_overlay(bundle, &overlay))?;
+    Ok(bundle_model_to_json(&edited)?)
+}
+
+/// Removes the overlays of the given `type` (full type or name, e.g.
+/// `label`) and `language`; an omitted argument matches any.
+#[pyfunction]
+#[pyo3(signature = (bundle, r#type=None, language=None, overlay_dir=None))]
+fn remove_overlays(
+    py: Python<'_>,
+    bundle: BundleArg,
+    r#type: Option<String>,
+    language: Option<String>,
+    overlay_dir: Option<RegistryArg>,
+) -> PyResult<String> {
+    let filter = OverlayFilter {
+        overlay_type: r#type,
+        language,
     };
+    let registry = bundle.registry(py, overlay_dir)?;
+    let edited = edited_model(&bundle.loaded_value(&registry)?, &registry, |bundle| {
+        edit::remove_overlays(bundle, &filter).map(|(edited, _)| edited)
+    })?;
+    Ok(bundle_model_to_json(&edited)?)
+}
 
-    let dict = PyDict::new(py);
-    dict.set_item("valid", valid)?;
-    dict.set_item("errors", errors)?;
-    Ok(dict.into())
+/// Keeps only the overlays in the `keep` languages, and those without a
+/// language, and recomputes the SAIDs. Returns the new bundle JSON.
+#[pyfunction]
+#[pyo3(signature = (bundle, keep, overlay_dir=None))]
+fn keep_languages(py: Python<'_>, bundle: BundleArg, keep: Vec<String>, overlay_dir: Option<RegistryArg>) -> PyResult<String> {
+    let registry = bundle.registry(py, overlay_dir)?;
+    let edited = edited_model(&bundle.loaded_value(&registry)?, &registry, |bundle| {
+        languages::keep_languages(bundle, &keep)
+    })?;
+    Ok(bundle_model_to_json(&edited)?)
 }
 
+/// Extracts the overlays in `language` into a translation package, a dict
+/// with the `language`, the `capture_base` SAID and the `overlays` without
+/// their SAIDs. Edit it, or change its `language` to start a new
+/// translation, and apply it with `merge_translation`.
 #[pyfunction]
+#[pyo3(signature = (bundle, language, overlay_dir=None))]
+fn extract_translation(
+    py: Python<'_>,
+    bundle: BundleArg,
+    language: &str,
+    overlay_dir: Option<RegistryArg>,
+) -> PyResult<PyObject> {
+    let registry = bundle.registry(py, overlay_dir)?;
+    let translation = languages::extract_translation(&bundle.loaded_value(&registry)?, language).map_err(Error::Other)?;
+    translation_to_py(py, &translation)
+}
+
+/// Writes the overlays of a translation package into the bundle, replacing
+/// those of the same type in its language. Returns the new bundle JSON.
+#[pyfunction]
+#[pyo3(signature = (bundle, translation, overlay_dir=None))]
+fn merge_translation(
+    py: Python<'_>,
+    bundle: BundleArg,
+    translation: &PyAny,
+    overlay_dir: Option<RegistryArg>,
+) -> PyResult<String> {
+    let translation = translation_value(py, translation)?;
+    let registry = bundle.registry(py, overlay_dir)?;
+    let edited = edited_model(&bundle.loaded_value(&registry)?, &registry, |bundle| {
+        languages::merge_translation(bundle, &translation)
+    })?;
+    Ok(bundle_model_to_json(&edited)?)
+}
+
+/// Loads two versions of a bundle for comparison.
+fn bundle_pair(
+    old: BundleArg,
+    new: BundleArg,
+    overlay_dir: Option<RegistryArg>,
+) -> errors::Result<(serde_json::Value, serde_json::Value)> {
+    let registry = registry::resolve(overlay_dir)?;
+    Ok((old.loaded_value(&registry)?, new.loaded_value(&registry)?))
+}
+
+/// Gives a verdict on whether data valid under `old` stays valid under
+/// `new`: `compatible`, `backward_compatible` or `breaking`, with the
+/// `findings` it is based on, each with its own `verdict`, `kind`,
+/// `attribute` and `message`.
+#[pyfunction]
+#[pyo3(signature = (old, new, overlay_dir=None))]
+fn check_compatibility(py: Python<'_>, old: BundleArg, new: BundleArg, overlay_dir: Option<RegistryArg>) -> PyResult<PyObject> {
+    let (old, new) = bundle_pair(old, new, overlay_dir)?;
+    let compatibility = serde_json::to_value(compat::check(&old, &new))
+        .map_err(|e| Error::Other(format!("Failed to serialize compatibility: {e}")))?;
+
+    json_to_py(py, &compatibility)
+}
+
+/// `check_compatibility` as a text report: the verdict, then one finding
+/// per line, most severe first.
+#[pyfunction]
+#[pyo3(signature = (old, new, overlay_dir=None))]
+fn check_compatibility_report(old: BundleArg, new: BundleArg, overlay_dir: Option<RegistryArg>) -> PyResult<String> {
+    let (old, new) = bundle_pair(old, new, overlay_dir)?;
+    Ok(compat::check(&old, &new).to_string())
+}
+
+/// Recomputes the SAIDs of the capture base, each overlay and the bundle and
+/// compares them with the embedded ones. Returns `valid` and, per object,
+/// its `path`, `embedded` and `computed` SAID and a `message` on mismatch.
+#[pyfunction]
+fn verify_bundle(py: Python<'_>, bundle_json: String) -> PyResult<PyObject> {
+    let bundle = bundle_value_from_json(&bundle_json)?;
+    let verification = serde_json::to_value(said::verify(&bundle))
+        .map_err(|e| Error::Other(format!("Failed to serialize verification: {e}")))?;
+
+    json_to_py(py, &verification)
+}
+
+/// Splits a version string such as `OCAS02JSON000106_` into `protocol`,
+/// `major`, `minor`, `format` and the declared `size`.
+#[pyfunction]
+fn parse_bundle_version(py: Python<'_>, version: &str) -> PyResult<PyObject> {
+    let version: version::Version = version.parse().map_err(Error::Other)?;
+    let version = serde_json::to_value(&version)
+        .map_err(|e| Error::Other(format!("Failed to serialize version: {e}")))?;
+
+    json_to_py(py, &version)
+}
+
+/// Parses the bundle's `v` and returns it as `version` with the
+/// `actual_size` of the serialized bundle, whether the sizes match and
+/// whether the SDK reads this version (`supported`).
+#[pyfunction]
+fn check_bundle_version(py: Python<'_>, bundle_json: String) -> PyResult<PyObject> {
+    let bundle = bundle_value_from_json(&bundle_json)?;
+    let check = version::check(&bundle).map_err(Error::Other)?;
+    let check = serde_json::to_value(&check)
+        .map_err(|e| Error::Other(format!("Failed to serialize version check: {e}")))?;
+
+    json_to_py(py, &check)
+}
+
+/// Checks a type tree such as
+/// `{"kind": "array", "items": {"kind": "value", "type": "Text"}}` and returns
+/// its OCAfile spelling, for building OCAfiles programmatically.
+#[pyfunction]
+fn attribute_type_from_tree(py: Python<'_>, tree: &PyAny) -> PyResult<String> {
+    let tree = py_to_json_string(py, tree)?;
+    let attr_type: AttrType = serde_json::from_str(&tree).map_err(|e| Error::AttributeType(e.to_string()))?;
+    let nested = nested_attr_type_from_tree(&attr_type)?;
+    Ok(nested_attr_type_to_tree(&nested).to_string())
+}
+
+#[pyfunction]
+fn bundle_to_ocafile(bundle_json: String, overlay_dir: Option<RegistryArg>) -> PyResult<String> {
+    let registry = registry::resolve(overlay_dir)?;
+    let bundle_model = bundle_model_from_json(&bundle_json, &registry)?;
+    let ast = bundle_model.to_ast();
+    Ok(ocafile::generate_from_ast(&ast))
+}
+
+#[pyfunction]
+#[pyo3(signature = (bundle_json, overlay_dir=None))]
+fn validate_bundle_semantics(
+    py: Python<'_>,
+    bundle_json: String,
+    overlay_dir: Option<RegistryArg>,
+) -> PyResult<PyObject> {
+    let registry = registry::resolve(overlay_dir)?;
+    let bundle_value = bundle_value_from_json(&bundle_json)?;
+    let bundle_model = bundle_model_from_value(&bundle_value, &registry)?;
+    semantics_result(py, &bundle_model, &bundle_value)
+}
+
+#[pyfunction]
+#[pyo3(signature = (bundle_json, data_json, overlay_dir=None, resolver=None))]
 fn validate_bundle_data(
     py: Python<'_>,
     bundle_json: String,
     data_json: String,
-    overlay_dir: Option<String>,
+    overlay_dir: Option<RegistryArg>,
+    resolver: Option<PyRef<BundleResolver>>,
 ) -> PyResult<PyObject> {
-    let mut bundle_model = bundle_model_from_json(&bundle_json, overlay_dir)
-        .map_err(pyo3::exceptions::PyValueError::new_err)?;
-    let data: serde_json::Value = serde_json::from_str(&data_json)
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid data JSON: {e}")))?;
-
-    let (valid, errors) = match data_validator::validate_data(&mut bundle_model, &data)
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Failed to validate data: {e}")))? {
-        DataValidationStatus::Valid => (true, vec![]),
-        DataValidationStatus::Invalid(errors) => (false, errors),
-    };
-
-    let dict = PyDict::new(py);
-    dict.set_item("valid", valid)?;
-    dict.set_item("errors", errors)?;
-    Ok(dict.into())
+    let registry = registry::resolve(overlay_dir)?;
+    let bundle_value = bundle_value_from_json(&bundle_json)?;
+    let mut bundle_model = bundle_model_from_value(&bundle_value, &registry)?;
+    data_result(py, &mut bundle_model, &bundle_value, &data_json, resolver::as_dyn(&resolver))
 }
 
 #[pyfunction]
+#[pyo3(signature = (bundle_json, overlay_dir=None, resolver=None))]
 fn bundle_attributes(
     py: Python<'_>,
     bundle_json: String,
-    overlay_dir: Option<String>,
+    overlay_dir: Option<RegistryArg>,
+    resolver: Option<PyRef<BundleResolver>>,
 ) -> PyResult<PyObject> {
-    let mut bundle_model = bundle_model_from_json(&bundle_json, overlay_dir)
-        .map_err(pyo3::exceptions::PyValueError::new_err)?;
+    let registry = registry::resolve(overlay_dir)?;
+    let bundle_value = bundle_value_from_json(&bundle_json)?;
+    let mut bundle_model = bundle_model_from_value(&bundle_value, &registry)?;
     bundle_model.fill_attributes();
-
-    let list = PyList::empty(py);
-    if let Some(attr_map) = bundle_model.attributes.as_ref() {
-        for attr in attr_map.values() {
-            let attr_type = attr
-                .attribute_type
-                .as_ref()
-                .map(nested_attr_type_to_string)
-                .unwrap_or_default();
-            let dict = PyDict::new(py);
-            dict.set_item("name", attr.name.clone())?;
-            dict.set_item("type", attr_type)?;
-            list.append(dict)?;
-        }
-    }
-
-    Ok(list.into())
+    attributes_list(py, &bundle_model, &bundle_value, resolver::as_dyn(&resolver))
 }
 
 #[pymodule]
-fn oca_sdk(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+fn oca_sdk(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    errors::register(py, m)?;
+    m.add_class::<Bundle>()?;
+    m.add_class::<BundleBuilder>()?;
+    m.add_class::<OverlayRegistry>()?;
+    m.add_class::<BundleResolver>()?;
     m.add_function(wrap_pyfunction!(build_from_ocafile, m)?)?;
+    m.add_function(wrap_pyfunction!(build_ocafiles, m)?)?;
     m.add_function(wrap_pyfunction!(bundle_to_ocafile, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_ocafile, m)?)?;
+    m.add_function(wrap_pyfunction!(ocafile_from_ast, m)?)?;
     m.add_function(wrap_pyfunction!(validate_bundle_semantics, m)?)?;
     m.add_function(wrap_pyfunction!(validate_bundle_data, m)?)?;
     m.add_function(wrap_pyfunction!(bundle_attributes, m)?)?;
+    m.add_function(wrap_pyfunction!(attribute_type_from_tree, m)?)?;
+    m.add_function(wrap_pyfunction!(bundle_additions, m)?)?;
+    m.add_function(wrap_pyfunction!(add_overlay, m)?)?;
+    m.add_function(wrap_pyfunction!(replace_overlay, m)?)?;
+    m.add_function(wrap_pyfunction!(remove_overlays, m)?)?;
+    m.add_function(wrap_pyfunction!(keep_languages, m)?)?;
+    m.add_function(wrap_pyfunction!(extract_translation, m)?)?;
+    m.add_function(wrap_pyfunction!(merge_translation, m)?)?;
+    m.add_function(wrap_pyfunction!(diff_bundles, m)?)?;
+    m.add_function(wrap_pyfunction!(diff_bundles_report, m)?)?;
+    m.add_function(wrap_pyfunction!(check_compatibility, m)?)?;
+    m.add_function(wrap_pyfunction!(check_compatibility_report, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_bundle, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_bundle_version, m)?)?;
+    m.add_function(wrap_pyfunction!(check_bundle_version, m)?)?;
     Ok(())
 }
@@ -0,0 +1,154 @@
+use std::ops::Deref;
+
+use oca_sdk_rs::oca::overlay_file::OverlayLocalRegistry;
+use pyo3::prelude::*;
+
+use crate::errors::{self, Error};
+
+/// An overlay registry loaded once and passed to any function that takes
+/// `overlay_dir`, instead of re-reading the directory on every call.
+#[pyclass(module = "oca_sdk")]
+pub struct OverlayRegistry {
+    pub(crate) registry: OverlayLocalRegistry,
+    /// Used in error messages, e.g. `overlay registry '/path'`.
+    pub(crate) description: String,
+    /// Overlay file contents, kept so registries can be merged.
+    sources: Vec<String>,
+}
+
+impl OverlayRegistry {
+    fn builtin() -> OverlayRegistry {
+        OverlayRegistry {
+            registry: OverlayLocalRegistry::default(),
+            description: "built-in overlay registry".to_string(),
+            sources: vec![],
+        }
+    }
+
+    fn load_dir(dir: String) -> errors::Result<OverlayRegistry> {
+        let registry_error = |message: String| Error::OverlayRegistry {
+            overlay_dir: Some(dir.clone()),
+            message,
+        };
+        let registry = OverlayLocalRegistry::from_dir(&dir).map_err(|e| registry_error(e.to_string()))?;
+
+        let mut paths = std::fs::read_dir(&dir)
+            .map_err(|e| registry_error(e.to_string()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "overlayfile"))
+            .collect::<Vec<_>>();
+        paths.sort();
+        let sources = paths
+            .iter()
+            .map(std::fs::read_to_string)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| registry_error(e.to_string()))?;
+
+        Ok(OverlayRegistry {
+            registry,
+            description: format!("overlay registry '{dir}'"),
+            sources,
+        })
+    }
+
+    fn from_sources(sources: Vec<String>, description: String) -> errors::Result<OverlayRegistry> {
+        let registry = OverlayLocalRegistry::from_string(sources.join("\n")).map_err(|e| Error::OverlayRegistry {
+            overlay_dir: None,
+            message: e.to_string(),
+        })?;
+        Ok(OverlayRegistry {
+            registry,
+            description,
+            sources,
+        })
+    }
+}
+
+#[pymethods]
+impl OverlayRegistry {
+    /// The built-in registry, without any overlay definitions of your own.
+    #[new]
+    fn new() -> Self {
+        OverlayRegistry::builtin()
+    }
+
+    #[staticmethod]
+    fn from_dir(overlay_dir: String) -> PyResult<Self> {
+        Ok(OverlayRegistry::load_dir(overlay_dir)?)
+    }
+
+    #[staticmethod]
+    fn from_string(overlay_file: String) -> PyResult<Self> {
+        Ok(OverlayRegistry::from_sources(
+            vec![overlay_file],
+            "overlay registry from string".to_string(),
+        )?)
+    }
+
+    /// Returns a new registry holding the overlay definitions of both.
+    fn merge(&self, other: &OverlayRegistry) -> PyResult<Self> {
+        Ok(OverlayRegistry::from_sources(
+            [self.sources.clone(), other.sources.clone()].concat(),
+            format!("{} merged with {}", self.description, other.description),
+        )?)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("OverlayRegistry({})", self.description)
+    }
+}
+
+/// What callers may pass as `overlay_dir`: a directory path or a
+/// previously loaded `OverlayRegistry`.
+#[derive(FromPyObject)]
+pub enum RegistryArg<'py> {
+    Registry(PyRef<'py, OverlayRegistry>),
+    Dir(String),
+}
+
+pub enum Registry<'py> {
+    Shared(PyRef<'py, OverlayRegistry>),
+    Owned(OverlayRegistry),
+}
+
+impl Registry<'_> {
+    /// A handle that outlives the call, e.g. to keep on a `Bundle`.
+    pub fn into_handle(self, py: Python<'_>) -> PyResult<Py<OverlayRegistry>> {
+        match self {
+            Registry::Shared(registry) => Ok(registry.into()),
+            Registry::Owned(registry) => Py::new(py, registry),
+        }
+    }
+}
+
+impl Deref for Registry<'_> {
+    type Target = OverlayRegistry;
+
+    fn deref(&self) -> &OverlayRegistry {
+        match self {
+            Registry::Shared(registry) => registry,
+            Registry::Owned(registry) => registry,
+        }
+    }
+}
+
+pub fn resolve(arg: Option<RegistryArg<'_>>) -> errors::Result<Registry<'_>> {
+    match arg {
+        Some(RegistryArg::Registry(registry)) => Ok(Registry::Shared(registry)),
+        Some(RegistryArg::Dir(dir)) if !dir.trim().is_empty() => Ok(Registry::Owned(OverlayRegistry::load_dir(dir)?)),
+        _ => Ok(Registry::Owned(OverlayRegistry::builtin())),
+    }
+}
+
+/// Like [`resolve`], but without an `arg` it falls back to `default` rather
+/// than the built-in registry.
+pub fn resolve_or<'py>(
+    py: Python<'py>,
+    arg: Option<RegistryArg<'py>>,
+    default: &'py Py<OverlayRegistry>,
+) -> errors::Result<Registry<'py>> {
+    match arg {
+        None => Ok(Registry::Shared(default.borrow(py))),
+        arg => resolve(arg),
+    }
+}
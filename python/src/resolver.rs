@@ -0,0 +1,94 @@
+use oca_bindings_common::resolve::{self, DirResolver, Reference};
+use oca_bindings_common::types::AttrType;
+use pyo3::prelude::*;
+
+use crate::errors::{self, Error};
+use crate::{json_to_py, py_to_json_string};
+
+/// Finds the bundles that `refs:` and `refn:` attribute types point at, so
+/// that validation, attribute listing and building can follow them.
+#[pyclass(module = "oca_sdk")]
+pub struct BundleResolver {
+    source: Source,
+}
+
+enum Source {
+    Dir(String, DirResolver),
+    Callback(PyObject),
+}
+
+#[pymethods]
+impl BundleResolver {
+    /// Looks bundles up in a directory of `<said>.json` files; names are
+    /// looked up as `<name>.json`.
+    #[staticmethod]
+    fn from_dir(store_dir: String) -> PyResult<Self> {
+        let resolver = DirResolver::new(&store_dir).map_err(|e| Error::Reference(vec![e]))?;
+        Ok(BundleResolver {
+            source: Source::Dir(store_dir, resolver),
+        })
+    }
+
+    /// Calls `callback(reference)` with a reference type tree, e.g.
+    /// `{'kind': 'reference', 'said': 'E...'}`. It returns the bundle as a
+    /// JSON string or dict, or `None` when it does not know the reference.
+    #[staticmethod]
+    fn from_callback(py: Python<'_>, callback: PyObject) -> PyResult<Self> {
+        if !callback.as_ref(py).is_callable() {
+            return Err(Error::Other("BundleResolver.from_callback expects a callable".to_string()).into());
+        }
+        Ok(BundleResolver {
+            source: Source::Callback(callback),
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        match &self.source {
+            Source::Dir(dir, _) => format!("BundleResolver(dir='{dir}')"),
+            Source::Callback(_) => "BundleResolver(callback)".to_string(),
+        }
+    }
+}
+
+impl resolve::BundleResolver for BundleResolver {
+    fn resolve(&self, reference: &Reference) -> Result<Option<serde_json::Value>, String> {
+        let callback = match &self.source {
+            Source::Dir(_, resolver) => return resolver.resolve(reference),
+            Source::Callback(callback) => callback,
+        };
+        Python::with_gil(|py| {
+            let call = || -> PyResult<Option<String>> {
+                let tree = serde_json::to_value(AttrType::from(reference.clone()))
+                    .map_err(|e| Error::Other(e.to_string()))?;
+                let bundle = callback.call1(py, (json_to_py(py, &tree)?,))?;
+                let bundle = bundle.as_ref(py);
+                if bundle.is_none() {
+                    return Ok(None);
+                }
+                match bundle.extract::<String>() {
+                    Ok(text) => Ok(Some(text)),
+                    Err(_) => py_to_json_string(py, bundle).map(Some),
+                }
+            };
+            match call().map_err(|e| format!("Resolver failed for {reference}: {e}"))? {
+                Some(text) => serde_json::from_str(&text)
+                    .map(Some)
+                    .map_err(|e| format!("Resolver returned invalid JSON for {reference}: {e}")),
+                None => Ok(None),
+            }
+        })
+    }
+}
+
+/// The resolver argument as the common crate expects it.
+pub fn as_dyn<'a>(resolver: &'a Option<PyRef<'_, BundleResolver>>) -> Option<&'a dyn resolve::BundleResolver> {
+    resolver.as_deref().map(|r| r as &dyn resolve::BundleResolver)
+}
+
+/// Rewrites `refn:` types of an OCAfile to `refs:` when a resolver is given.
+pub fn substitute_names(ocafile_text: String, resolver: Option<&dyn resolve::BundleResolver>) -> errors::Result<String> {
+    match resolver {
+        Some(resolver) => resolve::substitute_names(&ocafile_text, resolver).map_err(Error::Reference),
+        None => Ok(ocafile_text),
+    }
+}
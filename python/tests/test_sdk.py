@@ -1,4 +1,8 @@
+import json
+from pathlib import Path
+
 import oca_sdk
+import pytest
 
 
 def test_sdk_roundtrip():
@@ -7,7 +11,7 @@ def test_sdk_roundtrip():
     assert len(attrs) == 2
 
     semantics = oca_sdk.validate_bundle_semantics(bundle_json)
-    assert semantics['valid'] is True
+    assert semantics == {'valid': True, 'errors': []}
 
     data_ok = oca_sdk.validate_bundle_data(
         bundle_json, '{"name":"Alice","age":42}', None
@@ -16,3 +20,554 @@ def test_sdk_roundtrip():
 
     ocafile_out = oca_sdk.bundle_to_ocafile(bundle_json, None)
     assert 'ADD ATTRIBUTE' in ocafile_out
+
+
+REGISTRY_DIR = str(Path(__file__).parent / "registry")
+
+CUSTOM_OCAFILE = """--name=reviewed
+ADD ATTRIBUTE passport_number=Text
+ADD OVERLAY REVIEW_NOTE
+  attribute_notes
+    passport_number="checked by hand"
+"""
+
+
+def test_custom_overlays_survive_roundtrip():
+    bundle_json = oca_sdk.build_from_ocafile(CUSTOM_OCAFILE, REGISTRY_DIR)
+
+    ocafile_out = oca_sdk.bundle_to_ocafile(bundle_json, REGISTRY_DIR)
+    assert 'checked by hand' in ocafile_out
+
+    data_ok = oca_sdk.validate_bundle_data(
+        bundle_json, '{"passport_number":"P123"}', REGISTRY_DIR
+    )
+    assert data_ok['valid'] is True
+
+
+def test_unknown_overlay_is_reported():
+    bundle_json = oca_sdk.build_from_ocafile(CUSTOM_OCAFILE, REGISTRY_DIR)
+
+    with pytest.raises(oca_sdk.BundleLoadError, match="built-in overlay registry"):
+        oca_sdk.bundle_to_ocafile(bundle_json, None)
+
+
+def test_bundle_class():
+    bundle = oca_sdk.Bundle.from_ocafile('ADD ATTRIBUTE name=Text age=Numeric')
+    assert bundle.digest()
+    assert [attr['name'] for attr in bundle.attributes()] == ['name', 'age']
+    assert isinstance(bundle.overlays(), list)
+    assert bundle.validate_semantics()['valid'] is True
+    assert bundle.validate_data('{"name":"Alice","age":42}')['valid'] is True
+    assert 'ADD ATTRIBUTE' in bundle.to_ocafile()
+
+    reloaded = oca_sdk.Bundle.from_json(bundle.to_json())
+    assert reloaded.digest() == bundle.digest()
+
+
+def test_typed_errors():
+    with pytest.raises(oca_sdk.OcaFileParseError) as parse_error:
+        oca_sdk.build_from_ocafile('ADD ATTRIBUTE name=Text\nADD NONSENSE', None)
+    assert parse_error.value.line == 2
+    assert parse_error.value.column is not None
+    assert 'NONSENSE' in parse_error.value.snippet
+
+    with pytest.raises(oca_sdk.DataFormatError) as data_error:
+        oca_sdk.Bundle.from_json('{"capture_base": ', None)
+    assert data_error.value.input == 'bundle'
+    assert data_error.value.line == 1
+
+    with pytest.raises(oca_sdk.OverlayRegistryError):
+        oca_sdk.build_from_ocafile('ADD ATTRIBUTE name=Text', '/nonexistent/registry')
+
+    assert issubclass(oca_sdk.BundleLoadError, oca_sdk.OcaError)
+    assert issubclass(oca_sdk.OcaError, ValueError)
+
+
+def test_ocafile_ast_roundtrip():
+    ast = oca_sdk.parse_ocafile('--name=person\nADD ATTRIBUTE name=Text age=Numeric')
+    assert ast['meta']['name'] == 'person'
+    assert len(ast['commands']) == 1
+
+    ast['meta']['name'] = 'renamed'
+    ocafile_out = oca_sdk.ocafile_from_ast(ast)
+    assert 'renamed' in ocafile_out
+    assert 'ADD ATTRIBUTE' in ocafile_out
+
+
+def test_structured_data_errors():
+    bundle_json = oca_sdk.build_from_ocafile('ADD ATTRIBUTE name=Text age=Numeric', None)
+
+    result = oca_sdk.validate_bundle_data(bundle_json, '{"name":"Alice","age":"old"}', None)
+    assert result['valid'] is False
+    [error] = [e for e in result['errors'] if e['attribute'] == 'age']
+    assert error['path'] == '/age'
+    assert error['rule'] == 'type'
+    assert error['expected'] == 'Numeric'
+    assert error['actual'] == 'old'
+    assert error['message']
+
+
+def test_overlay_registry_handle():
+    registry = oca_sdk.OverlayRegistry.from_dir(REGISTRY_DIR)
+    bundle_json = oca_sdk.build_from_ocafile(CUSTOM_OCAFILE, registry)
+    assert 'checked by hand' in oca_sdk.bundle_to_ocafile(bundle_json, registry)
+    assert oca_sdk.Bundle.from_json(bundle_json, registry).digest()
+
+    overlay_file = (Path(REGISTRY_DIR) / "custom.overlayfile").read_text()
+    merged = oca_sdk.OverlayRegistry().merge(
+        oca_sdk.OverlayRegistry.from_string(overlay_file)
+    )
+    data_ok = oca_sdk.validate_bundle_data(
+        bundle_json, '{"passport_number":"P123"}', merged
+    )
+    assert data_ok['valid'] is True
+
+    with pytest.raises(oca_sdk.BundleLoadError):
+        oca_sdk.bundle_to_ocafile(bundle_json, oca_sdk.OverlayRegistry())
+
+
+def test_edits_keep_the_bundle_registry():
+    bundle = oca_sdk.Bundle.from_ocafile(CUSTOM_OCAFILE, REGISTRY_DIR)
+    french = {'type': 'label', 'language': 'fra', 'attribute_labels': {'passport_number': 'Numéro'}}
+
+    translated = bundle.add_overlay(french)
+    assert translated.languages() == ['fra']
+    assert translated.remove_overlays(type='label').digest() == bundle.digest()
+    assert 'checked by hand' in oca_sdk.bundle_to_ocafile(oca_sdk.add_overlay(bundle, french), REGISTRY_DIR)
+
+    with pytest.raises(oca_sdk.BundleLoadError):
+        bundle.add_overlay(french, oca_sdk.OverlayRegistry())
+
+
+def test_attribute_descriptors():
+    bundle = oca_sdk.Bundle.from_ocafile("""--name=person
+ADD ATTRIBUTE name=Text sex=Text
+
+ADD OVERLAY LABEL
+  language="eng"
+  attribute_labels
+    name="Name"
+    sex="Sex"
+
+ADD OVERLAY CONFORMANCE
+  attribute_conformances
+    name="M"
+
+ADD OVERLAY ENTRY_CODE
+  attribute_entry_codes
+    sex=["M", "F"]
+
+ADD OVERLAY ENTRY
+  language="eng"
+  attribute_entries
+    sex
+      "M"="Male"
+      "F"="Female"
+""")
+    name, sex = bundle.attributes()
+
+    assert name['name'] == 'name'
+    assert name['type'] == {'kind': 'value', 'type': 'Text'}
+    assert name['labels'] == {'eng': 'Name'}
+    assert name['conformance'] == 'M'
+    assert name['format'] is None
+    assert name['sensitive'] is False
+
+    assert sex['entry_codes'] == ['M', 'F']
+    assert sex['entries'] == {'eng': {'M': 'Male', 'F': 'Female'}}
+    assert oca_sdk.bundle_attributes(bundle.to_json(), None)[1]['labels'] == {'eng': 'Sex'}
+
+
+def test_attribute_type_trees():
+    bundle = oca_sdk.Bundle.from_ocafile(
+        'ADD ATTRIBUTE tags=Array[Text] friend=refs:EF5ERATRBBN_ewEo9buQbznirhBmvrSSC0O2GIR4Gbfs'
+    )
+    tags, friend = bundle.attributes()
+    assert tags['type'] == {'kind': 'array', 'items': {'kind': 'value', 'type': 'Text'}}
+    assert friend['type'] == {
+        'kind': 'reference',
+        'said': 'EF5ERATRBBN_ewEo9buQbznirhBmvrSSC0O2GIR4Gbfs',
+    }
+
+    assert oca_sdk.attribute_type_from_tree(tags['type']) == 'Array[Text]'
+    assert oca_sdk.attribute_type_from_tree({'kind': 'null'}) == 'Null'
+    with pytest.raises(oca_sdk.InvalidAttributeTypeError):
+        oca_sdk.attribute_type_from_tree({'kind': 'reference'})
+    with pytest.raises(oca_sdk.InvalidAttributeTypeError, match='not-a-said'):
+        oca_sdk.attribute_type_from_tree({'kind': 'reference', 'said': 'not-a-said'})
+
+
+def test_attribute_type_trees_round_trip_through_the_sdk():
+    ocafile = (
+        'ADD ATTRIBUTE name=Text age=Numeric born=DateTime ok=Boolean photo=Binary\n'
+        'ADD ATTRIBUTE tags=Array[Text] friend=refs:EF5ERATRBBN_ewEo9buQbznirhBmvrSSC0O2GIR4Gbfs\n'
+    )
+    attributes = oca_sdk.Bundle.from_ocafile(ocafile).attributes()
+    spellings = {attr['name']: oca_sdk.attribute_type_from_tree(attr['type']) for attr in attributes}
+    rebuilt = oca_sdk.Bundle.from_ocafile(
+        'ADD ATTRIBUTE ' + ' '.join(f'{name}={spelling}' for name, spelling in spellings.items())
+    )
+    assert [attr['type'] for attr in rebuilt.attributes()] == [attr['type'] for attr in attributes]
+
+
+def test_reference_resolver(tmp_path):
+    address_json = oca_sdk.build_from_ocafile('ADD ATTRIBUTE street=Text number=Numeric')
+    address = oca_sdk.Bundle.from_json(address_json)
+    (tmp_path / f"{address.digest()}.json").write_text(address_json)
+    (tmp_path / "address.json").write_text(address_json)
+    store = oca_sdk.BundleResolver.from_dir(str(tmp_path))
+
+    person = oca_sdk.Bundle.from_ocafile(
+        'ADD ATTRIBUTE name=Text home=refn:address', resolver=store
+    )
+    home = next(a for a in person.attributes(store) if a['name'] == 'home')
+    assert home['type'] == {'kind': 'reference', 'said': address.digest()}
+    assert sorted(a['name'] for a in home['nested']) == ['number', 'street']
+
+    result = person.validate_data(
+        '{"name":"Alice","home":{"street":"Main","number":"ten"}}', store
+    )
+    [error] = result['errors']
+    assert error['path'] == '/home/number'
+    assert error['rule'] == 'type'
+
+    seen = []
+
+    def lookup(reference):
+        seen.append(reference)
+        return address_json if reference.get('said') == address.digest() else None
+
+    callback = oca_sdk.BundleResolver.from_callback(lookup)
+    ok = person.validate_data('{"name":"Alice","home":{"street":"Main","number":10}}', callback)
+    assert ok['valid'] is True
+    assert seen == [{'kind': 'reference', 'said': address.digest()}]
+
+    with pytest.raises(oca_sdk.UnresolvedReferenceError) as missing:
+        oca_sdk.build_from_ocafile('ADD ATTRIBUTE home=refn:nowhere', None, store)
+    assert missing.value.errors == ['Referenced bundle refn:nowhere not found']
+
+
+def test_build_ocafiles():
+    built = oca_sdk.build_ocafiles({
+        'person': 'ADD ATTRIBUTE name=Text home=refn:address pets=Array[refn:pet]',
+        'pet': 'ADD ATTRIBUTE name=Text vet=refn:address',
+        'address': 'ADD ATTRIBUTE street=Text',
+    })
+
+    assert built['order'] == ['address', 'pet', 'person']
+    assert set(built['saids']) == {'person', 'pet', 'address'}
+    person = oca_sdk.Bundle.from_json(built['bundles']['person'])
+    assert person.digest() == built['saids']['person']
+    home = next(a for a in person.attributes() if a['name'] == 'home')
+    assert home['type'] == {'kind': 'reference', 'said': built['saids']['address']}
+
+    with pytest.raises(oca_sdk.BundleSetError) as failed:
+        oca_sdk.build_ocafiles({
+            'a': 'ADD ATTRIBUTE x=refn:b',
+            'b': 'ADD ATTRIBUTE x=refn:a',
+            'c': 'ADD ATTRIBUTE x=refn:nowhere',
+        })
+    kinds = {e['kind']: e for e in failed.value.errors}
+    assert kinds['missing_name']['names'] == ['nowhere']
+    assert kinds['cycle']['names'] == ['a', 'b', 'a']
+
+
+def test_build_from_base_bundle():
+    base = oca_sdk.build_from_ocafile("""ADD ATTRIBUTE name=Text
+
+ADD OVERLAY LABEL
+  language="eng"
+  attribute_labels
+    name="Name"
+""")
+    extended = oca_sdk.build_from_ocafile("""ADD ATTRIBUTE email=Text
+
+ADD OVERLAY LABEL
+  language="eng"
+  attribute_labels
+    email="E-mail"
+
+ADD OVERLAY CONFORMANCE
+  attribute_conformances
+    email="M"
+""", base=base)
+
+    names = [a['name'] for a in oca_sdk.bundle_attributes(extended)]
+    assert set(names) == {'name', 'email'}
+    added = oca_sdk.bundle_additions(base, extended)
+    assert added['attributes'] == ['email']
+    assert [o['type'].split('/')[1] for o in added['overlays']] == ['conformance']
+    assert [o['language'] for o in added['changed_overlays']] == ['eng']
+
+    bundle = oca_sdk.Bundle.from_ocafile('ADD ATTRIBUTE age=Numeric', base=oca_sdk.Bundle.from_json(base))
+    assert oca_sdk.bundle_additions(base, bundle)['attributes'] == ['age']
+    assert oca_sdk.bundle_additions(base, base) == {'attributes': [], 'overlays': [], 'changed_overlays': []}
+
+
+def test_verify_bundle():
+    bundle_json = oca_sdk.build_from_ocafile("""ADD ATTRIBUTE name=Text
+
+ADD OVERLAY LABEL
+  language="eng"
+  attribute_labels
+    name="Name"
+""")
+
+    report = oca_sdk.verify_bundle(bundle_json)
+    assert report['valid']
+    assert [o['path'] for o in report['objects']] == ['capture_base', 'overlays[0]', 'bundle']
+    assert report['objects'][-1]['computed'] == oca_sdk.Bundle.from_json(bundle_json).digest()
+
+    tampered = json.loads(bundle_json)
+    overlay = tampered['overlays'][0]
+    overlay.get('properties', overlay)['attribute_labels']['name'] = 'Full name'
+    report = oca_sdk.verify_bundle(json.dumps(tampered))
+    assert not report['valid']
+    assert [o['path'] for o in report['objects'] if not o['valid']] == ['overlays[0]', 'bundle']
+
+
+def test_bundle_versions():
+    assert oca_sdk.parse_bundle_version('OCAS02JSON000106_') == {
+        'protocol': 'OCAS', 'major': 0, 'minor': 2, 'format': 'JSON', 'size': 0x106,
+    }
+    with pytest.raises(oca_sdk.OcaError):
+        oca_sdk.parse_bundle_version('OCAS02JSON')
+
+    bundle_json = oca_sdk.build_from_ocafile('ADD ATTRIBUTE name=Text')
+    check = oca_sdk.check_bundle_version(bundle_json)
+    assert check['supported']
+    assert check['size_matches']
+    assert check['actual_size'] == check['version']['size']
+
+    bundle = json.loads(bundle_json)
+    bundle['v'] = 'OCAS10' + bundle['v'][6:]
+    with pytest.raises(oca_sdk.UnsupportedVersionError) as unsupported:
+        oca_sdk.Bundle.from_json(json.dumps(bundle))
+    assert unsupported.value.version == bundle['v']
+    assert isinstance(unsupported.value, oca_sdk.BundleLoadError)
+
+
+def test_diff_bundles():
+    old = oca_sdk.build_from_ocafile("""ADD ATTRIBUTE name=Text age=Text fax=Text
+
+ADD OVERLAY LABEL
+  language="eng"
+  attribute_labels
+    name="Name"
+    age="Age"
+""")
+    new = oca_sdk.build_from_ocafile("""ADD ATTRIBUTE name=Text age=Numeric email=Text
+
+ADD OVERLAY LABEL
+  language="eng"
+  attribute_labels
+    name="Full name"
+    age="Age"
+
+ADD OVERLAY CONFORMANCE
+  attribute_conformances
+    name="M"
+""")
+
+    diff = oca_sdk.diff_bundles(old, new)
+    assert [a['name'] for a in diff['added_attributes']] == ['email']
+    assert [a['name'] for a in diff['removed_attributes']] == ['fax']
+    assert diff['retyped_attributes'] == [{
+        'name': 'age',
+        'old_type': {'kind': 'value', 'type': 'Text'},
+        'new_type': {'kind': 'value', 'type': 'Numeric'},
+    }]
+    assert [o['type'].split('/')[1] for o in diff['added_overlays']] == ['conformance']
+    assert diff['label_changes'] == [
+        {'language': 'eng', 'attribute': 'name', 'old': 'Name', 'new': 'Full name'},
+    ]
+
+    report = oca_sdk.diff_bundles_report(old, oca_sdk.Bundle.from_json(new))
+    assert '  ~ age: Text -> Numeric' in report
+    assert '  [eng] name: "Name" -> "Full name"' in report
+    assert 'No changes' in oca_sdk.diff_bundles_report(old, old)
+
+
+def test_check_compatibility():
+    old = oca_sdk.build_from_ocafile("""ADD ATTRIBUTE name=Text sex=Text
+
+ADD OVERLAY ENTRY_CODE
+  attribute_entry_codes
+    sex=["M", "F", "X"]
+""")
+    relabelled = oca_sdk.build_from_ocafile("""ADD ATTRIBUTE name=Text sex=Text
+
+ADD OVERLAY ENTRY_CODE
+  attribute_entry_codes
+    sex=["M", "F", "X"]
+
+ADD OVERLAY LABEL
+  language="eng"
+  attribute_labels
+    name="Name"
+""")
+    extended = oca_sdk.build_from_ocafile("""ADD ATTRIBUTE name=Text sex=Text email=Text
+
+ADD OVERLAY ENTRY_CODE
+  attribute_entry_codes
+    sex=["M", "F", "X", "O"]
+""")
+    narrowed = oca_sdk.build_from_ocafile("""ADD ATTRIBUTE name=Text sex=Text id=Text
+
+ADD OVERLAY ENTRY_CODE
+  attribute_entry_codes
+    sex=["M", "F"]
+
+ADD OVERLAY CONFORMANCE
+  attribute_conformances
+    id="M"
+""")
+
+    assert oca_sdk.check_compatibility(old, old) == {'verdict': 'compatible', 'findings': []}
+    assert oca_sdk.check_compatibility(old, relabelled)['verdict'] == 'compatible'
+    assert oca_sdk.check_compatibility(old, extended)['verdict'] == 'backward_compatible'
+
+    result = oca_sdk.check_compatibility(old, narrowed)
+    assert result['verdict'] == 'breaking'
+    breaking = {(f['kind'], f['attribute']) for f in result['findings'] if f['verdict'] == 'breaking'}
+    assert breaking == {('attribute_added', 'id'), ('entry_codes_changed', 'sex')}
+
+    report = oca_sdk.check_compatibility_report(old, narrowed)
+    assert report.startswith('Verdict: breaking\n')
+    assert "Entry codes X of 'sex' were removed" in report
+
+
+def test_bundle_builder():
+    builder = (
+        oca_sdk.BundleBuilder()
+        .add_attribute('name', 'Text')
+        .add_attribute('sex', {'kind': 'value', 'type': 'Text'})
+        .label('eng', 'name', 'Name')
+        .label('eng', 'sex', 'Sex')
+        .entry_codes('sex', ['M', 'F'])
+        .entries('eng', 'sex', {'M': 'Male', 'F': 'Female'})
+        .conformance('name', 'M')
+        .meta('eng', {'name': 'Person'})
+    )
+    ocafile = """ADD ATTRIBUTE name=Text sex=Text
+
+ADD OVERLAY LABEL
+  language="eng"
+  attribute_labels
+    name="Name"
+    sex="Sex"
+
+ADD OVERLAY ENTRY_CODE
+  attribute_entry_codes
+    sex=["M", "F"]
+
+ADD OVERLAY ENTRY
+  language="eng"
+  attribute_entries
+    sex
+      "M"="Male"
+      "F"="Female"
+
+ADD OVERLAY CONFORMANCE
+  attribute_conformances
+    name="M"
+
+ADD OVERLAY META
+  language="eng"
+  name="Person"
+"""
+    bundle = builder.build()
+    assert bundle.digest() == oca_sdk.Bundle.from_ocafile(ocafile).digest()
+    assert 'attribute_entries' in builder.to_ocafile()
+
+    quoted = oca_sdk.BundleBuilder().add_attribute('note', 'Text').label('eng', 'note', 'A "quoted"\nlabel').build()
+    labels = [o for o in quoted.overlays() if o['type'].split('/')[1] == 'label']
+    assert labels[0]['attribute_labels']['note'] == 'A "quoted"\nlabel'
+
+    with pytest.raises(oca_sdk.OcaError):
+        oca_sdk.BundleBuilder().add_attribute('name', 'Text').label('eng', 'missing', 'Missing')
+    with pytest.raises(oca_sdk.OcaError):
+        oca_sdk.BundleBuilder().build()
+
+
+def test_edit_overlays():
+    bundle = oca_sdk.Bundle.from_ocafile("""ADD ATTRIBUTE name=Text
+
+ADD OVERLAY LABEL
+  language="eng"
+  attribute_labels
+    name="Name"
+""")
+    french = {'type': 'label', 'language': 'fra', 'attribute_labels': {'name': 'Nom'}}
+
+    translated = bundle.add_overlay(french)
+    assert translated.digest() != bundle.digest()
+    assert oca_sdk.verify_bundle(translated.to_json())['valid']
+    assert sorted(o['language'] for o in translated.overlays()) == ['eng', 'fra']
+    with pytest.raises(oca_sdk.OcaError):
+        translated.add_overlay(french)
+
+    relabelled = translated.replace_overlay({**french, 'attribute_labels': {'name': 'Nom complet'}})
+    labels = {o['language']: o['attribute_labels']['name'] for o in relabelled.overlays()}
+    assert labels == {'eng': 'Name', 'fra': 'Nom complet'}
+
+    assert relabelled.remove_overlays(type='label', language='fra').digest() == bundle.digest()
+    with pytest.raises(oca_sdk.OcaError):
+        bundle.remove_overlays(language='deu')
+
+    bundle_json = oca_sdk.add_overlay(bundle.to_json(), french)
+    assert oca_sdk.Bundle.from_json(bundle_json).digest() == translated.digest()
+    removed = oca_sdk.remove_overlays(bundle_json, type='overlay/label/2.0.0', language='fra')
+    assert oca_sdk.Bundle.from_json(removed).digest() == bundle.digest()
+
+
+def test_languages_and_translations():
+    bundle = oca_sdk.Bundle.from_ocafile("""ADD ATTRIBUTE name=Text
+
+ADD OVERLAY LABEL
+  language="eng"
+  attribute_labels
+    name="Name"
+
+ADD OVERLAY LABEL
+  language="pol"
+  attribute_labels
+    name="Imię"
+
+ADD OVERLAY LABEL
+  language="deu"
+  attribute_labels
+    name="Name"
+
+ADD OVERLAY CONFORMANCE
+  attribute_conformances
+    name="M"
+""")
+    assert sorted(bundle.languages()) == ['deu', 'eng', 'pol']
+
+    pruned = bundle.keep_languages(['eng', 'pol'])
+    assert sorted(pruned.languages()) == ['eng', 'pol']
+    assert [o['type'].split('/')[1] for o in pruned.overlays()].count('conformance') == 1
+    assert oca_sdk.verify_bundle(pruned.to_json())['valid']
+
+    package = pruned.extract_translation('eng')
+    assert package['language'] == 'eng'
+    assert package['overlays'] == [{'type': 'overlay/label/2.0.0', 'attribute_labels': {'name': 'Name'}}]
+    assert pruned.merge_translation(package).digest() == pruned.digest()
+
+    package['language'] = 'fra'
+    package['overlays'][0]['attribute_labels']['name'] = 'Nom'
+    translated = pruned.merge_translation(package)
+    assert sorted(translated.languages()) == ['eng', 'fra', 'pol']
+    assert translated.keep_languages(['eng', 'pol']).digest() == pruned.digest()
+
+    with pytest.raises(oca_sdk.OcaError):
+        pruned.extract_translation('deu')
+    other = oca_sdk.Bundle.from_ocafile('ADD ATTRIBUTE age=Numeric')
+    with pytest.raises(oca_sdk.OcaError):
+        other.merge_translation(package)
+
+    bundle_json = oca_sdk.keep_languages(bundle.to_json(), ['eng', 'pol'])
+    assert oca_sdk.Bundle.from_json(bundle_json).digest() == pruned.digest()
+    package = oca_sdk.extract_translation(bundle_json, 'pol')
+    assert oca_sdk.Bundle.from_json(oca_sdk.merge_translation(bundle_json, package)).digest() == pruned.digest()